@@ -0,0 +1,142 @@
+//! Implementation of calendar-aware offsets, which shift dates by a number of years, months, and
+//! days rather than by a fixed number of days.
+
+use core::ops::{Add, AddAssign};
+
+use crate::{Date, Days, HistoricDate, Month};
+
+/// Calendar-aware offset between dates
+///
+/// Unlike `Days`, a `CalendarOffset` does not correspond with a fixed number of days: one month
+/// after 31 January is 29 February in a leap year, but one month after 31 March is 30 April. When
+/// added to a `Date`, the offset is applied in the historic calendar in the following order:
+/// 1. The years are added, retaining the month and day.
+/// 2. The months are added, retaining the day. If the resulting month is too short for that day,
+///    the day is clamped to the last day of the month.
+/// 3. The days are added.
+///
+/// Because of the clamping in the first two steps, the order matters: 29 February 2020 plus one
+/// year is 28 February 2021, and adding one month and one day to 30 January 2021 results in
+/// 1 March 2021, whereas adding the day before the month would result in 28 February 2021. Dates
+/// that would fall within the days skipped by the Gregorian calendar reform resolve to
+/// 15 October 1582.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CalendarOffset {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+}
+
+impl CalendarOffset {
+    /// Constructs a new offset from the given number of years, months, and days.
+    #[must_use]
+    pub const fn new(years: i32, months: i32, days: i32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+}
+
+impl Add<CalendarOffset> for Date {
+    type Output = Self;
+
+    /// Applies a calendar offset to this date, in the order documented for `CalendarOffset`.
+    ///
+    /// # Panics
+    /// Panics if the resulting date is not representable.
+    fn add(self, rhs: CalendarOffset) -> Self {
+        const GREGORIAN_REFORM: HistoricDate = match HistoricDate::new(1582, Month::October, 15) {
+            Ok(date) => date,
+            Err(_) => unreachable!(),
+        };
+        let date = HistoricDate::from_date(self);
+        let months_since_year_zero = i64::from(date.year()) * 12
+            + i64::from(date.month() as u8 - 1)
+            + i64::from(rhs.years) * 12
+            + i64::from(rhs.months);
+        let Ok(year) = i32::try_from(months_since_year_zero.div_euclid(12)) else {
+            panic!("year of calendar offset result is not representable")
+        };
+        let Ok(month) = u8::try_from(months_since_year_zero.rem_euclid(12) + 1) else {
+            unreachable!()
+        };
+        let Ok(month) = Month::try_from(month) else {
+            unreachable!()
+        };
+        let day = date.day().min(HistoricDate::days_in_month(year, month));
+        let date = HistoricDate::new(year, month, day).unwrap_or(GREGORIAN_REFORM);
+        date.into_date()
+            .step_forward(Days::new(rhs.days))
+            .expect("result of calendar offset is not representable")
+    }
+}
+
+impl AddAssign<CalendarOffset> for Date {
+    fn add_assign(&mut self, rhs: CalendarOffset) {
+        *self = *self + rhs;
+    }
+}
+
+/// Verifies calendar offsets, including day clamping and the order in which they are applied.
+#[test]
+fn calendar_offsets() {
+    let date = |year, month, day| Date::from_historic_date(year, month, day).unwrap();
+    let leap_day = date(2020, Month::February, 29);
+    assert_eq!(
+        leap_day + CalendarOffset::new(1, 0, 0),
+        date(2021, Month::February, 28)
+    );
+    assert_eq!(
+        leap_day + CalendarOffset::new(4, 0, 0),
+        date(2024, Month::February, 29)
+    );
+    assert_eq!(
+        leap_day + CalendarOffset::new(0, 1, 1),
+        date(2020, Month::March, 30)
+    );
+    assert_eq!(
+        leap_day + CalendarOffset::new(0, 0, 1),
+        date(2020, Month::March, 1)
+    );
+
+    // Clamping happens before days are added, so order matters.
+    let end_of_january = date(2021, Month::January, 30);
+    assert_eq!(
+        end_of_january + CalendarOffset::new(0, 1, 1),
+        date(2021, Month::March, 1)
+    );
+    assert_eq!(
+        end_of_january + CalendarOffset::new(0, 1, 0) + CalendarOffset::new(0, 0, 1),
+        date(2021, Month::March, 1)
+    );
+    assert_eq!(
+        end_of_january + CalendarOffset::new(0, 0, 1) + CalendarOffset::new(0, 1, 0),
+        date(2021, Month::February, 28)
+    );
+
+    // Negative components and year boundaries.
+    assert_eq!(
+        date(2021, Month::January, 15) + CalendarOffset::new(0, -1, 0),
+        date(2020, Month::December, 15)
+    );
+    assert_eq!(
+        date(2021, Month::March, 31) + CalendarOffset::new(-1, -1, -1),
+        date(2020, Month::February, 28)
+    );
+    assert_eq!(
+        date(2021, Month::March, 31) + CalendarOffset::new(0, 25, 0),
+        date(2023, Month::April, 30)
+    );
+
+    // Skipped days of the Gregorian calendar reform resolve to its first day.
+    assert_eq!(
+        date(1582, Month::September, 10) + CalendarOffset::new(0, 1, 0),
+        date(1582, Month::October, 15)
+    );
+
+    let mut date_mut = leap_day;
+    date_mut += CalendarOffset::default();
+    assert_eq!(date_mut, leap_day);
+}
@@ -7,7 +7,7 @@ use core::ops::{Add, AddAssign, Sub, SubAssign};
 use crate::{
     GregorianDate, HistoricDate, JulianDate, Month, WeekDay,
     calendar::Days,
-    errors::{InvalidGregorianDate, InvalidHistoricDate, InvalidJulianDate},
+    errors::{InvalidGregorianDate, InvalidHistoricDate, InvalidIsoWeek, InvalidJulianDate},
 };
 
 /// Generic date representation
@@ -101,6 +101,103 @@ impl Date {
         }
     }
 
+    /// Returns the date that lies `days` after this one, or `None` if the result is not
+    /// representable. Stepping is done directly on the underlying day count, so this is a
+    /// constant-time operation.
+    #[must_use]
+    pub const fn step_forward(self, days: Days) -> Option<Self> {
+        match self.days.checked_add(days) {
+            Some(days) => Some(Self { days }),
+            None => None,
+        }
+    }
+
+    /// Returns the date that lies `days` before this one, or `None` if the result is not
+    /// representable. Stepping is done directly on the underlying day count, so this is a
+    /// constant-time operation.
+    #[must_use]
+    pub const fn step_backward(self, days: Days) -> Option<Self> {
+        match self.days.checked_sub(days) {
+            Some(days) => Some(Self { days }),
+            None => None,
+        }
+    }
+
+    /// Returns an iterator over all dates starting at this date, up to but excluding `end`. This
+    /// is the stable equivalent of the range `self..end`.
+    #[must_use]
+    pub const fn dates_until(self, end: Self) -> DateIterator {
+        DateIterator { start: self, end }
+    }
+
+    /// Returns an iterator over every date in the given month of the historic calendar, in
+    /// chronological order. For October 1582, the days skipped by the Gregorian calendar reform
+    /// are not included.
+    ///
+    /// # Panics
+    /// Panics if the month following the requested month is not representable.
+    #[must_use]
+    pub fn weekdays_in_month(year: i32, month: Month) -> DateIterator {
+        let next_year = match month {
+            Month::December => year.checked_add(1),
+            _ => Some(year),
+        };
+        let Ok(next_month) = Month::try_from(month as u8 % 12 + 1) else {
+            unreachable!()
+        };
+        let Ok(start) = Self::from_historic_date(year, month, 1) else {
+            unreachable!()
+        };
+        let Some(Ok(end)) =
+            next_year.map(|next_year| Self::from_historic_date(next_year, next_month, 1))
+        else {
+            panic!("month following {month} {year} is not representable")
+        };
+        start.dates_until(end)
+    }
+
+    /// Returns the `n`-th occurrence of `week_day` within the given month of the historic
+    /// calendar, counting from 1: for example, the third Monday of January. Returns `None` if `n`
+    /// is zero or the month contains fewer than `n` such days, as for a fifth Friday that does not
+    /// exist.
+    #[must_use]
+    pub fn nth_weekday_of_month(year: i32, month: Month, week_day: WeekDay, n: u8) -> Option<Self> {
+        let index = usize::from(n.checked_sub(1)?);
+        Self::weekdays_in_month(year, month)
+            .filter(|date| date.week_day() == week_day)
+            .nth(index)
+    }
+
+    /// Returns the number of days by which the proleptic Gregorian calendar runs ahead of the
+    /// historic calendar on this date, or `None` if both calendars coincide.
+    ///
+    /// This crate places the Gregorian calendar reform at its original date: 4 October 1582 in
+    /// the Julian calendar is directly followed by 15 October 1582 in the Gregorian calendar. Only
+    /// before the reform, where the historic calendar follows the Julian calendar, do the two
+    /// representations of a date differ: for example, historic 4 October 1582 is Gregorian 14
+    /// October 1582, a shift of 10 days. Further in the past, the shift decreases by three days
+    /// every four centuries, becoming zero in the third century and negative before. `None` is
+    /// also returned in the far past, once the shift no longer fits in an `i8`.
+    #[must_use]
+    pub fn gregorian_reform_shift(&self) -> Option<i8> {
+        if HistoricDate::from_date(*self).is_gregorian() {
+            return None;
+        }
+        // Interpret the Julian representation of this date as Gregorian. Julian leap days that do
+        // not exist in the Gregorian calendar are counted from the preceding day.
+        let julian = JulianDate::from_date(*self);
+        let (relabelled, leap_day) =
+            match GregorianDate::new(julian.year(), julian.month(), julian.day()) {
+                Ok(date) => (date, 0),
+                Err(_) => (
+                    GregorianDate::new(julian.year(), julian.month(), 28).ok()?,
+                    1,
+                ),
+            };
+        let shift = self.elapsed_calendar_days_since(relabelled.into_date()) - Days::new(leap_day);
+        i8::try_from(shift.count()).ok()
+    }
+
     /// Returns the day-of-the-week of this date.
     #[must_use]
     pub const fn week_day(&self) -> WeekDay {
@@ -117,6 +214,64 @@ impl Date {
             _ => unreachable!(),
         }
     }
+
+    /// Creates a `Date` from an ISO 8601 week date, given its week-numbering year, its week
+    /// number, and its day-of-the-week ranging from 1 (Monday) to 7 (Sunday). This is the inverse
+    /// of `iso_week`.
+    ///
+    /// # Errors
+    /// Will raise an error if the week number does not exist within the given year, or if the
+    /// day-of-the-week is not within 1 to 7. Week 53 only exists in years that contain 53 weeks.
+    pub fn from_iso_week(year: i32, week: u8, week_day: u8) -> Result<Self, InvalidIsoWeek> {
+        let error = InvalidIsoWeek {
+            year,
+            week,
+            week_day,
+        };
+        if !(1..=53).contains(&week) || !(1..=7).contains(&week_day) {
+            return Err(error);
+        }
+
+        // 4 January always falls in week 1, from which the Monday of that week follows.
+        let january_4 = Self::from_gregorian_date(year, Month::January, 4).map_err(|_| error)?;
+        let days_since_monday = (january_4.week_day() as i32 + 6) % 7;
+        let days_since_week_1 = 7 * (i32::from(week) - 1) + i32::from(week_day) - 1;
+        let date = january_4
+            .step_forward(Days::new(days_since_week_1 - days_since_monday))
+            .ok_or(error)?;
+
+        // Only in years with 53 weeks does the 53rd week not roll over into the next year.
+        if date.iso_week() == (year, week) {
+            Ok(date)
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Returns the ISO 8601 week date of this date, as its week-numbering year and week number.
+    /// Weeks start on Monday, and week 1 is the week containing the first Thursday of the year.
+    /// Hence, the first days of January may belong to the last week of the preceding year, and
+    /// the last days of December to week 1 of the next year. As prescribed by ISO 8601, the
+    /// proleptic Gregorian calendar is used to determine the year, also for dates before 1582.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "Week numbers range from 1 to 53"
+    )]
+    pub fn iso_week(&self) -> (i32, u8) {
+        // The week-numbering year is the year in which the Thursday of the same week falls.
+        let days_since_monday = (self.week_day() as i32 + 6) % 7;
+        let thursday = *self + Days::new(3 - days_since_monday);
+        let year = GregorianDate::from_date(thursday).year();
+        let Ok(new_year) = GregorianDate::new(year, Month::January, 1) else {
+            unreachable!()
+        };
+        let ordinal = thursday
+            .elapsed_calendar_days_since(new_year.into_date())
+            .count();
+        (year, (ordinal / 7 + 1) as u8)
+    }
 }
 
 impl Add<Days> for Date {
@@ -157,6 +312,55 @@ where
     }
 }
 
+/// Iterator over a half-open range of consecutive dates, as returned by `Date::dates_until()`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateIterator {
+    start: Date,
+    end: Date,
+}
+
+impl DateIterator {
+    /// Returns the number of dates that remain in this iterator.
+    fn remaining(&self) -> usize {
+        let remaining = i64::from(self.end.days.count()) - i64::from(self.start.days.count());
+        usize::try_from(remaining).unwrap_or(0)
+    }
+}
+
+impl Iterator for DateIterator {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            let date = self.start;
+            self.start += Days::new(1);
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for DateIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= Days::new(1);
+            Some(self.end)
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for DateIterator {}
+
+impl core::iter::FusedIterator for DateIterator {}
+
 /// Verifies that the epoch of `Date` is found at 1970-01-01 (historic calendar).
 #[test]
 fn epoch_at_1970_01_01() {
@@ -201,6 +405,115 @@ fn week_days() {
     check_week_day(1998, Month::December, 17, WeekDay::Thursday);
 }
 
+/// Verifies ISO 8601 week dates, including dates that belong to a week of the adjacent year.
+#[test]
+fn iso_weeks() {
+    let iso_week = |year, month, day| {
+        Date::from_gregorian_date(year, month, day)
+            .unwrap()
+            .iso_week()
+    };
+    assert_eq!(iso_week(2005, Month::January, 1), (2004, 53));
+    assert_eq!(iso_week(2005, Month::January, 3), (2005, 1));
+    assert_eq!(iso_week(2007, Month::January, 1), (2007, 1));
+    assert_eq!(iso_week(2008, Month::December, 29), (2009, 1));
+    assert_eq!(iso_week(2010, Month::January, 3), (2009, 53));
+    assert_eq!(iso_week(2021, Month::January, 3), (2020, 53));
+    assert_eq!(iso_week(2025, Month::July, 14), (2025, 29));
+    assert_eq!(iso_week(1969, Month::December, 31), (1970, 1));
+}
+
+/// Verifies iteration over the dates of a month and lookup of the n-th week day within it.
+#[test]
+fn weekdays_in_month() {
+    let dates = Date::weekdays_in_month(2024, Month::February);
+    assert_eq!(dates.len(), 29);
+    assert_eq!(Date::weekdays_in_month(2023, Month::December).len(), 31);
+    assert_eq!(
+        Date::weekdays_in_month(2023, Month::December).next_back(),
+        Date::from_historic_date(2023, Month::December, 31).ok()
+    );
+    assert_eq!(Date::weekdays_in_month(1582, Month::October).len(), 21);
+
+    assert_eq!(
+        Date::nth_weekday_of_month(2024, Month::January, WeekDay::Monday, 3),
+        Date::from_historic_date(2024, Month::January, 15).ok()
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2024, Month::March, WeekDay::Friday, 5),
+        Date::from_historic_date(2024, Month::March, 29).ok()
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2024, Month::February, WeekDay::Sunday, 5),
+        None
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2024, Month::February, WeekDay::Sunday, 0),
+        None
+    );
+}
+
+/// Verifies the shift between the historic and proleptic Gregorian calendars across the reform.
+#[test]
+fn gregorian_reform_shift() {
+    let last_julian_day = Date::from_historic_date(1582, Month::October, 4).unwrap();
+    assert_eq!(last_julian_day.gregorian_reform_shift(), Some(10));
+    assert_eq!(
+        GregorianDate::from_date(last_julian_day),
+        GregorianDate::new(1582, Month::October, 14).unwrap()
+    );
+    let first_gregorian_day = last_julian_day + Days::new(1);
+    assert_eq!(
+        HistoricDate::from_date(first_gregorian_day),
+        HistoricDate::new(1582, Month::October, 15).unwrap()
+    );
+    assert_eq!(first_gregorian_day.gregorian_reform_shift(), None);
+    assert_eq!(
+        Date::from_historic_date(2000, Month::January, 1)
+            .unwrap()
+            .gregorian_reform_shift(),
+        None
+    );
+
+    let shift = |year, month, day| {
+        Date::from_historic_date(year, month, day)
+            .unwrap()
+            .gregorian_reform_shift()
+    };
+    assert_eq!(shift(1000, Month::January, 1), Some(5));
+    assert_eq!(shift(1000, Month::March, 1), Some(6));
+    assert_eq!(shift(250, Month::June, 1), Some(0));
+    assert_eq!(shift(100, Month::March, 1), Some(-1));
+    assert_eq!(shift(100, Month::February, 29), Some(-2));
+    assert_eq!(shift(-4712, Month::January, 1), Some(-38));
+    assert_eq!(shift(-100_000, Month::January, 1), None);
+}
+
+/// Verifies that stepping through a week of dates advances the week day by one each time.
+#[test]
+fn step_through_week() {
+    let start = Date::from_historic_date(2025, Month::July, 14).unwrap();
+    let end = start.step_forward(Days::weeks(1)).unwrap();
+    assert_eq!(end.step_backward(Days::weeks(1)), Some(start));
+
+    let dates = start.dates_until(end);
+    assert_eq!(dates.len(), 7);
+    let mut previous: Option<Date> = None;
+    for date in dates {
+        if let Some(previous) = previous {
+            assert_eq!(previous.step_forward(Days::new(1)), Some(date));
+            assert_eq!((previous.week_day() as u8 + 1) % 7, date.week_day() as u8);
+        }
+        previous = Some(date);
+    }
+    assert_eq!(previous, end.step_backward(Days::new(1)));
+    assert_eq!(start.dates_until(end).next_back(), previous);
+    assert_eq!(end.dates_until(start).next(), None);
+
+    let last = Date::from_time_since_epoch(Days::new(i32::MAX));
+    assert_eq!(last.step_forward(Days::new(1)), None);
+}
+
 #[cfg(kani)]
 mod infallibility {
     use super::*;
@@ -45,6 +45,43 @@ impl Days {
         Self { count: count * 7 }
     }
 
+    /// Constructs a new `Days` from a given number of weeks, returning `None` if the resulting
+    /// number of days does not fit in an `i32`.
+    #[must_use]
+    pub const fn try_weeks(count: i32) -> Option<Self> {
+        match count.checked_mul(7) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Adds two `Days`, returning `None` if overflow occurred.
+    #[must_use]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.count.checked_add(other.count) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Subtracts two `Days`, returning `None` if overflow occurred.
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.count.checked_sub(other.count) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Multiplies a `Days` with a unitless factor, returning `None` if overflow occurred.
+    #[must_use]
+    pub const fn checked_mul(self, factor: i32) -> Option<Self> {
+        match self.count.checked_mul(factor) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
     /// Returns the raw number of time units contained in this `Days`. It is advised not to
     /// use this function unless absolutely necessary, as it effectively throws away all time unit
     /// information and safety.
@@ -149,3 +186,22 @@ impl Days {
         self.count.is_negative()
     }
 }
+
+/// Verifies that the checked arithmetic functions detect overflow at the `i32` boundaries.
+#[test]
+fn checked_arithmetic() {
+    let max = Days::new(i32::MAX);
+    let min = Days::new(i32::MIN);
+    let one = Days::new(1);
+    assert_eq!(max.checked_add(one), None);
+    assert_eq!(min.checked_sub(one), None);
+    assert_eq!(max.checked_mul(2), None);
+    assert_eq!(min.checked_mul(-1), None);
+    assert_eq!(Days::try_weeks(i32::MAX), None);
+    assert_eq!(Days::try_weeks(i32::MAX / 7 + 1), None);
+
+    assert_eq!(max.checked_sub(one), Some(Days::new(i32::MAX - 1)));
+    assert_eq!(min.checked_add(one), Some(Days::new(i32::MIN + 1)));
+    assert_eq!(Days::new(3).checked_mul(-2), Some(Days::new(-6)));
+    assert_eq!(Days::try_weeks(2), Some(Days::weeks(2)));
+}
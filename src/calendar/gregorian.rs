@@ -2,7 +2,7 @@
 //! calendar.
 
 use crate::{
-    Date, Month,
+    Date, HistoricDate, JulianDate, Month,
     calendar::Days,
     calendar::historic::month_day_from_ordinal_date,
     errors::{InvalidDayOfYear, InvalidGregorianDate},
@@ -133,6 +133,16 @@ impl GregorianDate {
         self.day
     }
 
+    /// Returns the number of calendar days from this date until `other`, which is negative if
+    /// `other` lies before this date. As for `Date::elapsed_calendar_days_since`, this counts
+    /// calendar days only: leap seconds are not taken into account.
+    #[must_use]
+    pub fn days_until(self, other: Self) -> Days {
+        other
+            .into_date()
+            .elapsed_calendar_days_since(self.into_date())
+    }
+
     /// Returns the number of days in a given month of a year.
     const fn days_in_month(year: i32, month: Month) -> u8 {
         use crate::Month::{
@@ -176,6 +186,42 @@ impl From<Date> for GregorianDate {
     }
 }
 
+impl From<JulianDate> for GregorianDate {
+    fn from(value: JulianDate) -> Self {
+        Self::from_date(value.into_date())
+    }
+}
+
+impl From<HistoricDate> for GregorianDate {
+    fn from(value: HistoricDate) -> Self {
+        Self::from_date(value.into_date())
+    }
+}
+
+/// Verifies direct conversion between the calendars around the Gregorian reform, where the first
+/// Gregorian date 1582-10-15 directly followed the last Julian date 1582-10-04.
+#[test]
+fn calendar_conversions() {
+    use crate::Month::October;
+    let gregorian = GregorianDate::new(1582, October, 15).unwrap();
+    let julian = JulianDate::new(1582, October, 5).unwrap();
+    let historic = HistoricDate::new(1582, October, 15).unwrap();
+    assert_eq!(JulianDate::from(gregorian), julian);
+    assert_eq!(GregorianDate::from(julian), gregorian);
+    assert_eq!(HistoricDate::from(gregorian), historic);
+    assert_eq!(HistoricDate::from(julian), historic);
+    assert_eq!(GregorianDate::from(historic), gregorian);
+    assert_eq!(JulianDate::from(historic), julian);
+
+    // Before the reform, historic dates follow the Julian calendar instead.
+    let julian = JulianDate::new(1582, October, 4).unwrap();
+    let gregorian = GregorianDate::new(1582, October, 14).unwrap();
+    let historic = HistoricDate::new(1582, October, 4).unwrap();
+    assert_eq!(GregorianDate::from(julian), gregorian);
+    assert_eq!(HistoricDate::from(gregorian), historic);
+    assert_eq!(JulianDate::from(historic), julian);
+}
+
 /// Verifies that roundtrip conversion for some random dates conserves the date.
 #[test]
 fn roundtrip() {
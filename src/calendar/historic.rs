@@ -4,7 +4,7 @@
 //! Gregoric calendar reform of 1582. When in doubt, use this calendar.
 
 use crate::{
-    Date, GregorianDate, JulianDate, Month,
+    Date, Days, GregorianDate, JulianDate, Month,
     errors::{InvalidDayOfYear, InvalidDayOfYearCount, InvalidHistoricDate},
 };
 
@@ -132,6 +132,41 @@ impl HistoricDate {
         self.day
     }
 
+    /// Returns the calendar quarter of this date, ranging from 1 (January through March) to 4
+    /// (October through December).
+    #[must_use]
+    pub const fn quarter(&self) -> u8 {
+        (self.month as u8 - 1) / 3 + 1
+    }
+
+    /// Returns the fiscal year and fiscal quarter of this date, for a fiscal year that starts on
+    /// the first day of `fiscal_year_start`. Fiscal years are labelled by the calendar year in
+    /// which they start: for a fiscal year starting in July, January 2025 falls in the third
+    /// quarter of fiscal year 2024. A fiscal year starting in January coincides with the calendar
+    /// year.
+    #[must_use]
+    pub const fn fiscal_quarter(&self, fiscal_year_start: Month) -> (i32, u8) {
+        let month = self.month as u8;
+        let start = fiscal_year_start as u8;
+        let fiscal_year = if month >= start {
+            self.year
+        } else {
+            self.year - 1
+        };
+        let months_into_fiscal_year = (month + 12 - start) % 12;
+        (fiscal_year, months_into_fiscal_year / 3 + 1)
+    }
+
+    /// Returns the number of calendar days from this date until `other`, which is negative if
+    /// `other` lies before this date. As for `Date::elapsed_calendar_days_since`, this counts
+    /// calendar days only: leap seconds are not taken into account.
+    #[must_use]
+    pub fn days_until(self, other: Self) -> Days {
+        other
+            .into_date()
+            .elapsed_calendar_days_since(self.into_date())
+    }
+
     /// Returns the day-of-year of this specific date, within its calendar year. The day-of-year is
     /// an integer value ranging from 1 on January 1 to 365 (or 365, in leap years) on December 31.
     /// Uses the algorithm given by Meeus in Astronomical Algorithms.
@@ -253,6 +288,18 @@ impl From<Date> for HistoricDate {
     }
 }
 
+impl From<GregorianDate> for HistoricDate {
+    fn from(value: GregorianDate) -> Self {
+        Self::from_date(value.into_date())
+    }
+}
+
+impl From<JulianDate> for HistoricDate {
+    fn from(value: JulianDate) -> Self {
+        Self::from_date(value.into_date())
+    }
+}
+
 impl core::fmt::Display for HistoricDate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}-{:02}-{:02}", self.year, self.month as u8, self.day)
@@ -275,16 +322,84 @@ fn day_of_year() {
     assert_eq!(date4, date2);
 }
 
+/// Verifies calendar quarters and fiscal quarters for several fiscal-year start months.
+#[test]
+fn quarters() {
+    let date = |year, month| HistoricDate::new(year, month, 1).unwrap();
+    assert_eq!(date(2024, Month::January).quarter(), 1);
+    assert_eq!(date(2024, Month::March).quarter(), 1);
+    assert_eq!(date(2024, Month::April).quarter(), 2);
+    assert_eq!(date(2024, Month::December).quarter(), 4);
+
+    // A fiscal year starting in January coincides with the calendar year.
+    assert_eq!(
+        date(2024, Month::February).fiscal_quarter(Month::January),
+        (2024, 1)
+    );
+    assert_eq!(
+        date(2024, Month::November).fiscal_quarter(Month::January),
+        (2024, 4)
+    );
+
+    // For a fiscal year starting in July, January falls in the prior-labelled fiscal year.
+    assert_eq!(
+        date(2024, Month::July).fiscal_quarter(Month::July),
+        (2024, 1)
+    );
+    assert_eq!(
+        date(2024, Month::October).fiscal_quarter(Month::July),
+        (2024, 2)
+    );
+    assert_eq!(
+        date(2025, Month::January).fiscal_quarter(Month::July),
+        (2024, 3)
+    );
+    assert_eq!(
+        date(2025, Month::June).fiscal_quarter(Month::July),
+        (2024, 4)
+    );
+    assert_eq!(date(-1, Month::March).fiscal_quarter(Month::April), (-2, 4));
+}
+
 /// Verifies that the Gregorian calendar reform is properly modelled.
 #[test]
 fn gregorian_reform() {
-    use crate::Days;
     use crate::Month::*;
     let date1 = Date::from_historic_date(1582, October, 4).unwrap();
     let date2 = Date::from_historic_date(1582, October, 15).unwrap();
     assert_eq!(date1 + Days::new(1), date2);
 }
 
+/// Verifies that the number of days between two dates is computed correctly, including across
+/// the Gregorian calendar reform.
+#[test]
+fn days_until() {
+    use crate::Month::*;
+    let start = HistoricDate::new(2000, January, 1).unwrap();
+    let end = HistoricDate::new(2000, March, 1).unwrap();
+    assert_eq!(start.days_until(end), Days::new(60));
+    assert_eq!(end.days_until(start), Days::new(-60));
+    assert_eq!(start.days_until(start), Days::new(0));
+
+    let before_reform = HistoricDate::new(1582, October, 4).unwrap();
+    let after_reform = HistoricDate::new(1582, October, 15).unwrap();
+    assert_eq!(before_reform.days_until(after_reform), Days::new(1));
+
+    let start = GregorianDate::new(2000, January, 1).unwrap();
+    let end = GregorianDate::new(2000, March, 1).unwrap();
+    assert_eq!(start.days_until(end), Days::new(60));
+    let start = GregorianDate::new(1900, January, 1).unwrap();
+    let end = GregorianDate::new(1900, March, 1).unwrap();
+    assert_eq!(start.days_until(end), Days::new(59));
+
+    let start = JulianDate::new(2000, January, 1).unwrap();
+    let end = JulianDate::new(2000, March, 1).unwrap();
+    assert_eq!(start.days_until(end), Days::new(60));
+    let start = JulianDate::new(1900, January, 1).unwrap();
+    let end = JulianDate::new(1900, March, 1).unwrap();
+    assert_eq!(start.days_until(end), Days::new(60));
+}
+
 #[cfg(kani)]
 impl kani::Arbitrary for HistoricDate {
     fn any() -> Self {
@@ -2,7 +2,7 @@
 //! calendar.
 
 use crate::{
-    Date, Days, Month,
+    Date, Days, GregorianDate, HistoricDate, Month,
     calendar::historic::month_day_from_ordinal_date,
     errors::{InvalidDayOfYear, InvalidJulianDate},
 };
@@ -130,6 +130,16 @@ impl JulianDate {
         self.day
     }
 
+    /// Returns the number of calendar days from this date until `other`, which is negative if
+    /// `other` lies before this date. As for `Date::elapsed_calendar_days_since`, this counts
+    /// calendar days only: leap seconds are not taken into account.
+    #[must_use]
+    pub fn days_until(self, other: Self) -> Days {
+        other
+            .into_date()
+            .elapsed_calendar_days_since(self.into_date())
+    }
+
     /// Returns the number of days in a given month of a year.
     const fn days_in_month(year: i32, month: Month) -> u8 {
         use crate::Month::{
@@ -173,6 +183,18 @@ impl From<Date> for JulianDate {
     }
 }
 
+impl From<GregorianDate> for JulianDate {
+    fn from(value: GregorianDate) -> Self {
+        Self::from_date(value.into_date())
+    }
+}
+
+impl From<HistoricDate> for JulianDate {
+    fn from(value: HistoricDate) -> Self {
+        Self::from_date(value.into_date())
+    }
+}
+
 /// Verifies that roundtrip conversion for some random dates conserves the date.
 #[test]
 fn roundtrip() {
@@ -0,0 +1,152 @@
+//! The Julian Day Number (JDN) counts the number of days since 4713 BC (-4712) January 1 in the
+//! historic calendar. Unlike the Modified Julian Day, a Julian day starts at noon: the JDN of a
+//! given calendar date is that of the Julian day starting at noon on that date. Consequently, the
+//! fractional Julian Date (JD) of midnight at the start of a date is half a day less than its JDN.
+//!
+//! The JD is related to the MJD by the constant offset `JD = MJD + 2400000.5`.
+
+use crate::{Date, Days, ModifiedJulianDate};
+
+/// The Julian Day Number (JDN) of any given date.
+///
+/// This is the integer number of days since noon of -4712-01-01 in the historic calendar: the JDN
+/// of the Julian day that starts at noon of the represented calendar date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JulianDayNumber {
+    time_since_epoch: Days,
+}
+
+/// The difference between the Julian day number of a date and its modified Julian date. The MJD
+/// epoch (1858-11-17T00:00) is at JD 2400000.5, so the Julian day starting at noon on that date has
+/// JDN 2400001.
+const JULIAN_DAY_NUMBER_MODIFIED_JULIAN_DATE_EPOCH: Days = Days::new(2_400_001);
+
+impl JulianDayNumber {
+    /// Constructs a new JDN directly from the number of days since the JDN epoch, noon of
+    /// -4712-01-01 in the historic calendar.
+    #[must_use]
+    pub const fn from_time_since_epoch(time_since_epoch: Days) -> Self {
+        Self { time_since_epoch }
+    }
+
+    /// Returns the number of days since the JDN epoch of this day.
+    #[must_use]
+    pub const fn time_since_epoch(&self) -> Days {
+        self.time_since_epoch
+    }
+
+    /// Constructs the Julian day number of some given calendar date. This is the JDN of the Julian
+    /// day starting at noon of that date.
+    #[must_use]
+    pub fn from_date(date: Date) -> Self {
+        Self::from_modified_julian_date(ModifiedJulianDate::from_date(date))
+    }
+
+    /// Converts this Julian day number into the calendar date at whose noon it starts.
+    #[must_use]
+    pub fn into_date(&self) -> Date {
+        self.into_modified_julian_date().into_date()
+    }
+
+    /// Constructs the Julian day number of the date represented by a modified Julian date.
+    #[must_use]
+    pub fn from_modified_julian_date(mjd: ModifiedJulianDate) -> Self {
+        Self {
+            time_since_epoch: mjd.time_since_epoch() + JULIAN_DAY_NUMBER_MODIFIED_JULIAN_DATE_EPOCH,
+        }
+    }
+
+    /// Converts this Julian day number into the modified Julian date of the same calendar date.
+    #[must_use]
+    pub fn into_modified_julian_date(&self) -> ModifiedJulianDate {
+        ModifiedJulianDate::from_time_since_epoch(
+            self.time_since_epoch - JULIAN_DAY_NUMBER_MODIFIED_JULIAN_DATE_EPOCH,
+        )
+    }
+}
+
+impl Date {
+    /// Returns the Julian day number of this date: the JDN of the Julian day starting at noon of
+    /// this date.
+    #[must_use]
+    pub fn julian_day_number(&self) -> JulianDayNumber {
+        JulianDayNumber::from_date(*self)
+    }
+
+    /// Returns the fractional Julian Date (JD) at midnight at the start of this date. Since Julian
+    /// days start at noon, this is always half a day less than the Julian day number of this date:
+    /// equivalently, `JD = MJD + 2400000.5`.
+    #[must_use]
+    pub fn julian_day(&self) -> f64 {
+        f64::from(self.julian_day_number().time_since_epoch().count()) - 0.5
+    }
+}
+
+impl From<Date> for JulianDayNumber {
+    fn from(value: Date) -> Self {
+        Self::from_date(value)
+    }
+}
+
+impl From<JulianDayNumber> for Date {
+    fn from(value: JulianDayNumber) -> Self {
+        value.into_date()
+    }
+}
+
+impl From<ModifiedJulianDate> for JulianDayNumber {
+    fn from(value: ModifiedJulianDate) -> Self {
+        Self::from_modified_julian_date(value)
+    }
+}
+
+impl From<JulianDayNumber> for ModifiedJulianDate {
+    fn from(value: JulianDayNumber) -> Self {
+        value.into_modified_julian_date()
+    }
+}
+
+/// Verifies this implementation by computing the `JulianDayNumber` and fractional Julian date for
+/// some known time stamp, given the Julian date at midnight of that date. If it doesn't match,
+/// panics.
+#[cfg(test)]
+#[allow(clippy::float_cmp, reason = "Exact values expected")]
+fn check_historic_julian_day(year: i32, month: crate::Month, day: u8, julian_day: f64) {
+    let date = Date::from_historic_date(year, month, day).unwrap();
+    assert_eq!(date.julian_day(), julian_day);
+    let jdn = date.julian_day_number();
+    assert_eq!(f64::from(jdn.time_since_epoch().count()) - 0.5, julian_day);
+    assert_eq!(jdn.into_date(), date);
+    let mjd = ModifiedJulianDate::from_date(date);
+    assert_eq!(JulianDayNumber::from(mjd), jdn);
+    assert_eq!(ModifiedJulianDate::from(jdn), mjd);
+}
+
+/// Compares some computed JD values with known values from Meeus' Astronomical Algorithms, the
+/// same ones used to verify the modified Julian date.
+#[test]
+fn historic_dates_from_meeus() {
+    use crate::Month::*;
+    check_historic_julian_day(2000, January, 1, 2_451_544.5);
+    check_historic_julian_day(1999, January, 1, 2_451_179.5);
+    check_historic_julian_day(1987, January, 27, 2_446_822.5);
+    check_historic_julian_day(1987, June, 19, 2_446_965.5);
+    check_historic_julian_day(1988, January, 27, 2_447_187.5);
+    check_historic_julian_day(1988, June, 19, 2_447_331.5);
+    check_historic_julian_day(1900, January, 1, 2_415_020.5);
+    check_historic_julian_day(1600, January, 1, 2_305_447.5);
+    check_historic_julian_day(1600, December, 31, 2_305_812.5);
+    check_historic_julian_day(837, April, 10, 2_026_871.5);
+    check_historic_julian_day(-123, December, 31, 1_676_496.5);
+    check_historic_julian_day(-122, January, 1, 1_676_497.5);
+    check_historic_julian_day(-1000, July, 12, 1_356_000.5);
+    check_historic_julian_day(-1000, February, 29, 1_355_866.5);
+    check_historic_julian_day(-1001, August, 17, 1_355_670.5);
+    check_historic_julian_day(-4712, January, 1, -0.5);
+    assert_eq!(
+        Date::from_historic_date(-4712, January, 1)
+            .unwrap()
+            .julian_day_number(),
+        JulianDayNumber::from_time_since_epoch(Days::new(0))
+    );
+}
@@ -1,8 +1,10 @@
 //! Representation of specific calendrical types, used to represent individual dates according to a
 //! variety of historical calendars.
 
+mod calendar_offset;
+pub use calendar_offset::CalendarOffset;
 mod date;
-pub use date::Date;
+pub use date::{Date, DateIterator};
 mod days;
 pub use days::Days;
 mod gregorian;
@@ -11,9 +13,13 @@ mod historic;
 pub use historic::HistoricDate;
 mod julian;
 pub use julian::JulianDate;
+mod julian_day_number;
+pub use julian_day_number::JulianDayNumber;
 mod modified_julian_date;
 pub use modified_julian_date::ModifiedJulianDate;
 mod month;
 pub use month::Month;
 mod week_day;
 pub use week_day::WeekDay;
+mod weeks;
+pub use weeks::Weeks;
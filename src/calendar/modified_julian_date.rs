@@ -2,6 +2,8 @@
 //! at 0h UT. Effectively, this makes it a constant offset from the Julian Day (JD); however, the
 //! MJD is useful because it is not fractional for time points at midnight.
 
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
 use crate::{
     Date, Days, Month,
     errors::{InvalidGregorianDate, InvalidHistoricDate, InvalidJulianDate},
@@ -88,6 +90,54 @@ impl ModifiedJulianDate {
     }
 }
 
+impl core::fmt::Display for ModifiedJulianDate {
+    /// Renders this modified Julian date as its day count, prefixed with `MJD`: `MJD 51544`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "MJD {}", self.time_since_epoch.count())
+    }
+}
+
+impl Add<Days> for ModifiedJulianDate {
+    type Output = Self;
+
+    fn add(self, rhs: Days) -> Self {
+        Self {
+            time_since_epoch: self.time_since_epoch + rhs,
+        }
+    }
+}
+
+impl AddAssign<Days> for ModifiedJulianDate {
+    fn add_assign(&mut self, rhs: Days) {
+        self.time_since_epoch += rhs;
+    }
+}
+
+impl Sub<Days> for ModifiedJulianDate {
+    type Output = Self;
+
+    fn sub(self, rhs: Days) -> Self {
+        Self {
+            time_since_epoch: self.time_since_epoch - rhs,
+        }
+    }
+}
+
+impl SubAssign<Days> for ModifiedJulianDate {
+    fn sub_assign(&mut self, rhs: Days) {
+        self.time_since_epoch -= rhs;
+    }
+}
+
+impl Sub for ModifiedJulianDate {
+    type Output = Days;
+
+    /// Returns the number of days elapsed between two modified Julian dates.
+    fn sub(self, rhs: Self) -> Days {
+        self.time_since_epoch - rhs.time_since_epoch
+    }
+}
+
 impl From<Date> for ModifiedJulianDate {
     fn from(value: Date) -> Self {
         Self::from_date(value)
@@ -137,6 +187,32 @@ fn historic_dates_from_meeus() {
     check_historic_modified_julian_date(-4712, January, 1, Days::new(-2_400_001));
 }
 
+/// Verifies that modified Julian dates may be offset and differenced directly in terms of days.
+#[test]
+fn day_arithmetic() {
+    use crate::Month::*;
+    let start = ModifiedJulianDate::from_historic_date(1987, January, 27).unwrap();
+    let end = ModifiedJulianDate::from_historic_date(1987, June, 19).unwrap();
+    assert_eq!(end - start, Days::new(143));
+    assert_eq!(start - end, Days::new(-143));
+    assert_eq!(start + (end - start), end);
+    assert_eq!(end - (end - start), start);
+
+    let week_later = start + Days::new(7);
+    assert_eq!(
+        week_later,
+        ModifiedJulianDate::from_historic_date(1987, February, 3).unwrap()
+    );
+    assert_eq!(week_later.into_date(), start.into_date() + Days::new(7));
+    assert!(week_later > start);
+
+    let mut date = start;
+    date += Days::new(7);
+    assert_eq!(date, week_later);
+    date -= Days::new(7);
+    assert_eq!(date, start);
+}
+
 #[cfg(kani)]
 mod proof_harness {
     use super::*;
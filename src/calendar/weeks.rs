@@ -0,0 +1,121 @@
+//! Definition of a `Weeks`, a calendrical duration expressed as a number of whole weeks. Useful
+//! for week-based arithmetic on dates, where the week structure should remain visible.
+
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::{Date, Days};
+
+/// Representation of a duration to an accuracy of `Weeks`. Converts losslessly into `Days`, but
+/// keeps week-based calendrical computations free of the factor seven.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    derive_more::Add,
+    derive_more::AddAssign,
+    derive_more::Sub,
+    derive_more::SubAssign,
+    derive_more::Neg,
+)]
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+pub struct Weeks {
+    count: i32,
+}
+
+impl Weeks {
+    /// Constructs a new `Weeks` from a given number of weeks.
+    #[must_use]
+    pub const fn new(count: i32) -> Self {
+        Self { count }
+    }
+
+    /// Returns the raw number of weeks contained in this `Weeks`.
+    #[must_use]
+    pub const fn count(&self) -> i32 {
+        self.count
+    }
+
+    /// Returns the number of days in this number of weeks, or `None` if it does not fit in a
+    /// `Days`.
+    #[must_use]
+    pub const fn try_into_days(self) -> Option<Days> {
+        Days::try_weeks(self.count)
+    }
+}
+
+impl From<Weeks> for Days {
+    /// Converts a number of weeks into the equivalent number of days.
+    ///
+    /// # Panics
+    /// Panics if the resulting number of days does not fit in a `Days`.
+    fn from(weeks: Weeks) -> Self {
+        weeks
+            .try_into_days()
+            .expect("number of days does not fit in a `Days`")
+    }
+}
+
+impl Add<Weeks> for Date {
+    type Output = Self;
+
+    fn add(self, rhs: Weeks) -> Self {
+        self + Days::from(rhs)
+    }
+}
+
+impl AddAssign<Weeks> for Date {
+    fn add_assign(&mut self, rhs: Weeks) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Weeks> for Date {
+    type Output = Self;
+
+    fn sub(self, rhs: Weeks) -> Self {
+        self - Days::from(rhs)
+    }
+}
+
+impl SubAssign<Weeks> for Date {
+    fn sub_assign(&mut self, rhs: Weeks) {
+        *self = *self - rhs;
+    }
+}
+
+impl Date {
+    /// Returns the number of elapsed calendar days since `other`, broken down into whole weeks and
+    /// leftover days. Both are truncated towards zero, so they share the sign of the difference.
+    #[must_use]
+    pub fn week_difference(self, other: Self) -> (i32, i32) {
+        let days = self.elapsed_calendar_days_since(other).count();
+        (days / 7, days % 7)
+    }
+}
+
+/// Verifies week arithmetic on dates, and the breakdown of date differences into weeks and days.
+#[test]
+fn week_arithmetic() {
+    use crate::Month;
+    let date = Date::from_historic_date(2024, Month::February, 20).unwrap();
+    let later = Date::from_historic_date(2024, Month::March, 5).unwrap();
+    assert_eq!(date + Weeks::new(2), later);
+    assert_eq!(later - Weeks::new(2), date);
+    assert_eq!(Days::from(Weeks::new(2)), Days::new(14));
+    assert_eq!(Weeks::new(i32::MAX).try_into_days(), None);
+
+    let mut shifted = date;
+    shifted += Weeks::new(3);
+    shifted -= Weeks::new(1);
+    assert_eq!(shifted, later);
+
+    let end = Date::from_historic_date(2024, Month::March, 8).unwrap();
+    assert_eq!(end.week_difference(date), (2, 3));
+    assert_eq!(date.week_difference(end), (-2, -3));
+    assert_eq!(date.week_difference(date), (0, 0));
+}
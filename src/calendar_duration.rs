@@ -0,0 +1,183 @@
+//! Implementation of durations that retain the calendar components they were expressed in, as
+//! opposed to `Duration`, which collapses all components into a count of attoseconds.
+
+use core::fmt::Display;
+
+use num_traits::Zero;
+
+use crate::{Duration, Second};
+
+/// Duration expressed in separate calendar components
+///
+/// Where a `Duration` is no more than a count of attoseconds, a `CalendarDuration` retains the
+/// individual components of an ISO 8601 duration expression. Hence, `P1Y2M` and `P14M` are
+/// distinct calendar durations, even though both collapse to the same `Duration`. This is useful
+/// when the original expression must be reproduced, for example when re-emitting it.
+///
+/// Components are signed: a negative duration is represented by negating all its components. The
+/// `subseconds` component stores the fractional part of the seconds, and has the same sign as
+/// `seconds`. When collapsed using `to_duration()`, months and years are interpreted as 1/12 of an
+/// average Gregorian year and an average Gregorian year, respectively, in line with `Duration`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CalendarDuration {
+    pub years: i128,
+    pub months: i128,
+    pub days: i128,
+    pub hours: i128,
+    pub minutes: i128,
+    pub seconds: i128,
+    pub subseconds: Duration,
+}
+
+impl CalendarDuration {
+    /// Collapses all components into a single `Duration`.
+    ///
+    /// # Panics
+    /// Panics if the resulting duration is not representable. Calendar durations obtained through
+    /// parsing are always representable.
+    #[must_use]
+    pub fn to_duration(&self) -> Duration {
+        self.checked_to_duration()
+            .expect("calendar duration is not representable as `Duration`")
+    }
+
+    /// Collapses all components into a single `Duration`, returning `None` if the result is not
+    /// representable.
+    #[must_use]
+    pub fn checked_to_duration(&self) -> Option<Duration> {
+        [
+            Duration::years(1).checked_mul(self.years)?,
+            Duration::months(1).checked_mul(self.months)?,
+            Duration::days(1).checked_mul(self.days)?,
+            Duration::hours(1).checked_mul(self.hours)?,
+            Duration::minutes(1).checked_mul(self.minutes)?,
+            Duration::seconds(1).checked_mul(self.seconds)?,
+            self.subseconds,
+        ]
+        .into_iter()
+        .try_fold(Duration::ZERO, Duration::checked_add)
+    }
+
+    /// Returns whether this duration is negative, i.e., whether any of its components is negative
+    /// and none of them is positive.
+    fn is_negative(&self) -> bool {
+        let components = [
+            self.years,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.subseconds.count(),
+        ];
+        components.iter().any(|&component| component < 0)
+            && components.iter().all(|&component| component <= 0)
+    }
+
+    /// Combines the seconds and subseconds into a sign, a magnitude of whole seconds, and a
+    /// non-negative fraction of a second. Since the magnitude is unsigned, this is possible for
+    /// any combination of components, even if their sum is not representable as `Duration`.
+    fn seconds_and_subseconds(&self) -> (bool, u128, Duration) {
+        let (carry, fraction) = self.subseconds.factor_out::<Second>();
+        // Overflow may only occur if both terms share the same sign, in which case their
+        // magnitudes may simply be added.
+        let (is_negative, seconds) = self.seconds.checked_add(carry).map_or_else(
+            || {
+                (
+                    self.seconds < 0,
+                    self.seconds.unsigned_abs() + carry.unsigned_abs(),
+                )
+            },
+            |seconds| (seconds < 0, seconds.unsigned_abs()),
+        );
+        if fraction.is_zero() {
+            (is_negative, seconds, fraction)
+        } else if seconds == 0 {
+            (fraction.is_negative(), 0, fraction.abs())
+        } else if is_negative == fraction.is_negative() {
+            (is_negative, seconds, fraction.abs())
+        } else {
+            (
+                is_negative,
+                seconds - 1,
+                Duration::seconds(1) - fraction.abs(),
+            )
+        }
+    }
+}
+
+impl Default for CalendarDuration {
+    fn default() -> Self {
+        Self {
+            years: 0,
+            months: 0,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            subseconds: Duration::ZERO,
+        }
+    }
+}
+
+impl From<CalendarDuration> for Duration {
+    fn from(value: CalendarDuration) -> Self {
+        value.to_duration()
+    }
+}
+
+impl Display for CalendarDuration {
+    /// Formats this duration as ISO 8601 duration expression. Zero-valued components are omitted,
+    /// except that a zero duration is formatted as `PT0S`. If the components of a duration do not
+    /// all share the same sign, each component is printed with its own sign.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let is_negative = self.is_negative();
+        if is_negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        write_component(f, self.years, is_negative, 'Y')?;
+        write_component(f, self.months, is_negative, 'M')?;
+        write_component(f, self.days, is_negative, 'D')?;
+
+        let (seconds_are_negative, seconds, subseconds) = self.seconds_and_subseconds();
+        let has_seconds = seconds != 0 || !subseconds.is_zero();
+        if self.hours != 0 || self.minutes != 0 || has_seconds {
+            write!(f, "T")?;
+        } else if self.years == 0 && self.months == 0 && self.days == 0 {
+            return write!(f, "T0S");
+        }
+        write_component(f, self.hours, is_negative, 'H')?;
+        write_component(f, self.minutes, is_negative, 'M')?;
+
+        if has_seconds {
+            if seconds_are_negative && !is_negative {
+                write!(f, "-")?;
+            }
+            write!(f, "{seconds}")?;
+            if !subseconds.is_zero() {
+                write!(f, ".")?;
+                for digit in subseconds.decimal_digits(f.precision()) {
+                    write!(f, "{digit}")?;
+                }
+            }
+            write!(f, "S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a single non-zero component of a calendar duration, followed by its designator. For
+/// negative durations, only the magnitude is written, since the sign precedes the whole duration.
+fn write_component(
+    f: &mut core::fmt::Formatter<'_>,
+    value: i128,
+    is_negative: bool,
+    designator: char,
+) -> core::fmt::Result {
+    match (value, is_negative) {
+        (0, _) => Ok(()),
+        (_, true) => write!(f, "{}{designator}", value.unsigned_abs()),
+        (_, false) => write!(f, "{value}{designator}"),
+    }
+}
@@ -4,14 +4,15 @@
 
 use core::{
     fmt::Debug,
-    ops::{Div, Mul},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
 };
 
-use num_traits::{Bounded, ConstZero, Signed, Zero};
+use num_traits::{Bounded, ConstZero, Zero};
 
 use crate::{
     Days, Femto, FractionalDigitsIterator, Micro, Milli, Nano, Pico, Second, SecondsPerDay,
     SecondsPerHour, SecondsPerMinute, SecondsPerMonth, SecondsPerWeek, SecondsPerYear, UnitRatio,
+    errors::{DaysOverflow, FloatDurationError},
 };
 
 /// Representation of time durations
@@ -25,27 +26,54 @@ use crate::{
 /// 10 trillion years, or about 700 times the age of the universe; should be sufficient for most
 /// purposes. Note that this type is explicitly intended for calculations only: when storing large
 /// numbers of durations, it might be more efficient to use a more tailor-made representation.
-#[derive(
-    Copy,
-    Clone,
-    Debug,
-    PartialEq,
-    Eq,
-    PartialOrd,
-    Ord,
-    Hash,
-    derive_more::Add,
-    derive_more::AddAssign,
-    derive_more::Sub,
-    derive_more::SubAssign,
-    derive_more::Neg,
-)]
+///
+/// Addition, subtraction, and negation of `Duration`s panic with the message "attotime: Duration
+/// overflow" whenever the result is not representable, regardless of whether overflow checks are
+/// enabled in the build profile. Use `checked_add`, `saturating_add`, and similar functions to
+/// handle overflow explicitly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(kani, derive(kani::Arbitrary))]
 pub struct Duration {
     count: i128,
 }
 
 impl Duration {
+    /// The `Duration` that represents no time passed.
+    pub const ZERO: Self = Self { count: 0 };
+
+    /// The largest representable `Duration`, nearest to positive infinity.
+    pub const MAX: Self = Self { count: i128::MAX };
+
+    /// The smallest representable `Duration`, nearest to negative infinity.
+    pub const MIN: Self = Self { count: i128::MIN };
+
+    /// A `Duration` of one attosecond, the smallest representable positive duration.
+    pub const ATTOSECOND: Self = Self::attoseconds(1);
+
+    /// A `Duration` of one nanosecond.
+    pub const NANOSECOND: Self = Self::nanoseconds(1);
+
+    /// A `Duration` of one microsecond.
+    pub const MICROSECOND: Self = Self::microseconds(1);
+
+    /// A `Duration` of one millisecond.
+    pub const MILLISECOND: Self = Self::milliseconds(1);
+
+    /// A `Duration` of one second.
+    pub const SECOND: Self = Self::seconds(1);
+
+    /// A `Duration` of one minute.
+    pub const MINUTE: Self = Self::minutes(1);
+
+    /// A `Duration` of one hour.
+    pub const HOUR: Self = Self::hours(1);
+
+    /// A `Duration` of one day, consisting of 86400 seconds.
+    pub const DAY: Self = Self::days(1);
+
+    /// A `Duration` of one week.
+    pub const WEEK: Self = Self::weeks(1);
+
     /// Constructs a new `Duration` from a given number of attoseconds.
     #[must_use]
     pub const fn attoseconds(count: i128) -> Self {
@@ -150,6 +178,80 @@ impl Duration {
         }
     }
 
+    /// Constructs a new `Duration` from a given number of some generic `Unit`, such that generic
+    /// code may write `Duration::from_units::<Milli>(500)` instead of requiring a distinctly named
+    /// constructor for each unit.
+    ///
+    /// # Panics
+    /// Panics if the resulting duration is not representable.
+    #[must_use]
+    pub const fn from_units<Unit: UnitRatio + ?Sized>(count: i128) -> Self {
+        match Self::checked_from_units::<Unit>(count) {
+            Some(duration) => duration,
+            None => panic!("{}", OVERFLOW_MESSAGE),
+        }
+    }
+
+    /// Constructs a new `Duration` from a given number of some generic `Unit`. Returns `None` if
+    /// the result is not representable.
+    #[must_use]
+    pub const fn checked_from_units<Unit: UnitRatio + ?Sized>(count: i128) -> Option<Self> {
+        match count.checked_mul(Unit::ATTOSECONDS) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Constructs a new `Duration` from a number of hours, minutes, and seconds, summed together.
+    /// Components need not share a sign, so `from_hms(1, -30, 0)` is half an hour.
+    ///
+    /// # Panics
+    /// Panics if the resulting duration is not representable.
+    #[must_use]
+    pub const fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Self {
+        Self::from_hms_nano(hours, minutes, seconds, 0)
+    }
+
+    /// Constructs a new `Duration` from a number of hours, minutes, seconds, and nanoseconds,
+    /// summed together.
+    ///
+    /// # Panics
+    /// Panics if the resulting duration is not representable.
+    #[must_use]
+    pub const fn from_hms_nano(hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        match Self::checked_from_hms_nano(hours, minutes, seconds, nanoseconds) {
+            Some(duration) => duration,
+            None => panic!("{}", OVERFLOW_MESSAGE),
+        }
+    }
+
+    /// Constructs a new `Duration` from a number of hours, minutes, seconds, and nanoseconds,
+    /// summed together. Returns `None` if the result is not representable.
+    #[must_use]
+    pub const fn checked_from_hms_nano(
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        nanoseconds: i64,
+    ) -> Option<Self> {
+        let Some(hours) = (hours as i128).checked_mul(SecondsPerHour::ATTOSECONDS) else {
+            return None;
+        };
+        let Some(minutes) = (minutes as i128).checked_mul(SecondsPerMinute::ATTOSECONDS) else {
+            return None;
+        };
+        let Some(count) = hours.checked_add(minutes) else {
+            return None;
+        };
+        let Some(count) = count.checked_add(seconds as i128 * Second::ATTOSECONDS) else {
+            return None;
+        };
+        let Some(count) = count.checked_add(nanoseconds as i128 * Nano::ATTOSECONDS) else {
+            return None;
+        };
+        Some(Self { count })
+    }
+
     /// Returns the raw number of time units contained in this `Duration`. It is advised not to
     /// use this function unless absolutely necessary, as it effectively throws away all time unit
     /// information and safety.
@@ -180,40 +282,120 @@ impl Duration {
         self.fractional_digits(precision, 10)
     }
 
-    /// Converts towards a different time unit, rounding towards the nearest whole unit.
+    /// Returns a formatter that renders this `Duration` as a mission elapsed time, in the format
+    /// `ddd:hh:mm:ss.fff`. The day count is zero-padded to three digits but does not wrap, so
+    /// 500 days is rendered as `500:`. Negative durations are prefixed with a minus sign.
+    ///
+    /// By default, three fractional digits are printed: a different number may be selected using
+    /// the precision of the formatter, e.g. `{:.6}`. Digits beyond the precision are truncated.
+    #[must_use]
+    pub const fn format_elapsed(&self) -> impl core::fmt::Display {
+        ElapsedTime { duration: *self }
+    }
+
+    /// Converts towards a different time unit, rounding towards the nearest whole unit. Ties are
+    /// rounded towards positive infinity.
+    ///
+    /// Negative durations are rounded to the nearest unit as well, so -1.7 s rounds to -2 s. Note
+    /// that versions up to 0.4.0 instead rounded such durations towards zero, to -1 s.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, which may only happen for durations
+    /// within half a unit of `Duration::MIN` or `Duration::MAX`. Use `checked_round` to handle
+    /// such durations.
     #[must_use]
     pub const fn round<Target>(self) -> Self
+    where
+        Target: UnitRatio + ?Sized,
+    {
+        match self.checked_round::<Target>() {
+            Some(duration) => duration,
+            None => panic!("{}", OVERFLOW_MESSAGE),
+        }
+    }
+
+    /// Converts towards a different time unit, rounding towards the nearest whole unit as for
+    /// `round`. Returns `None` if the rounded duration is not representable.
+    #[must_use]
+    pub const fn checked_round<Target>(self) -> Option<Self>
     where
         Target: UnitRatio + ?Sized,
     {
         let unit_attoseconds = Target::ATTOSECONDS;
-        let count = ((self.count + unit_attoseconds / 2) / unit_attoseconds) * unit_attoseconds;
-        Self { count }
+        let quotient = div_floor(self.count, unit_attoseconds);
+        let remainder = self.count.rem_euclid(unit_attoseconds);
+        let quotient = if remainder >= unit_attoseconds - unit_attoseconds / 2 {
+            quotient + 1
+        } else {
+            quotient
+        };
+        match quotient.checked_mul(unit_attoseconds) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Converts towards a different time unit, rounding towards the nearest whole unit. Unlike
+    /// `round`, which always rounds ties towards positive infinity, ties are rounded towards the
+    /// nearest even multiple of the unit ("banker's rounding"): 0.5 s and 1.5 s round to 0 s and
+    /// 2 s, respectively. This avoids a systematic bias when rounding many durations.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, which may only happen for durations
+    /// within half a unit of `Duration::MIN` or `Duration::MAX`.
+    #[must_use]
+    pub const fn round_half_even<Target>(self) -> Self
+    where
+        Target: UnitRatio + ?Sized,
+    {
+        let unit_attoseconds = Target::ATTOSECONDS;
+        let quotient = div_floor(self.count, unit_attoseconds);
+        let remainder = self.count.rem_euclid(unit_attoseconds);
+        let excess = unit_attoseconds - remainder;
+        let quotient = if remainder > excess || (remainder == excess && quotient % 2 != 0) {
+            quotient + 1
+        } else {
+            quotient
+        };
+        match quotient.checked_mul(unit_attoseconds) {
+            Some(count) => Self { count },
+            None => panic!("{}", OVERFLOW_MESSAGE),
+        }
     }
 
     /// Converts towards a different time unit, rounding towards positive infinity if the unit is
     /// not entirely commensurate with the present unit.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, which may only happen for durations
+    /// within one unit of `Duration::MAX`.
     #[must_use]
-    pub fn ceil<Target>(self) -> Self
+    pub const fn ceil<Target>(self) -> Self
     where
         Target: UnitRatio + ?Sized,
     {
         let unit_attoseconds = Target::ATTOSECONDS;
-        Self {
-            count: (num_integer::div_ceil(self.count, unit_attoseconds)) * unit_attoseconds,
+        match div_ceil(self.count, unit_attoseconds).checked_mul(unit_attoseconds) {
+            Some(count) => Self { count },
+            None => panic!("{}", OVERFLOW_MESSAGE),
         }
     }
 
     /// Converts towards a different time unit, rounding towards negative infinity if the unit is
     /// not entirely commensurate with the present unit.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, which may only happen for durations
+    /// within one unit of `Duration::MIN`.
     #[must_use]
-    pub fn floor<Target>(self) -> Self
+    pub const fn floor<Target>(self) -> Self
     where
         Target: UnitRatio + ?Sized,
     {
         let unit_attoseconds = Target::ATTOSECONDS;
-        Self {
-            count: (num_integer::div_floor(self.count, unit_attoseconds)) * unit_attoseconds,
+        match div_floor(self.count, unit_attoseconds).checked_mul(unit_attoseconds) {
+            Some(count) => Self { count },
+            None => panic!("{}", OVERFLOW_MESSAGE),
         }
     }
 
@@ -230,218 +412,1852 @@ impl Duration {
         }
     }
 
-    /// Segments this `Duration` by factoring out the largest possible number of whole multiples of
-    /// a given unit. Returns this whole number as well as the remainder.
-    ///
-    /// An example would be factoring out the number of whole days from some elapsed time: then,
-    /// `self.factor_out()` would return a tuple of the number of whole days and the fractional
-    /// day part that remains.
+    /// Returns whether this `Duration` is an integer multiple of the given unit, i.e., whether
+    /// rounding towards that unit would leave it unchanged.
     #[must_use]
-    pub fn factor_out<Unit>(self) -> (i128, Self)
+    pub const fn is_whole<Unit>(&self) -> bool
     where
         Unit: UnitRatio + ?Sized,
     {
-        let factored = self.truncate::<Unit>();
-        let remainder = self - factored;
-        let factored = factored.count() / Unit::ATTOSECONDS;
-        (factored, remainder)
+        self.count % Unit::ATTOSECONDS == 0
     }
 
-    /// Divides by an `i128`, rounding to the nearest result.
+    /// Returns whether this `Duration` is a whole number of seconds.
     #[must_use]
-    pub const fn div_round(self, other: i128) -> Self {
-        let count = (self.count + other / 2) / other;
-        Self { count }
+    pub const fn is_whole_seconds(&self) -> bool {
+        self.is_whole::<Second>()
     }
 
-    /// Converts into a float approximation of the stored duration, expressed in the desired units.
-    /// For maximum numerical precision, first reduces the magnitude of the fraction by computing
-    /// the integer quotient: in this manner, only the computation of the fractional part loses
-    /// numerical precision.
-    ///
-    /// # Panics
-    /// May panic if the quotient or remainder of the division by the unit ratio cannot be
-    /// represented by the provided type `T`.
+    /// Returns whether this `Duration` is a whole number of days.
     #[must_use]
-    pub fn as_float<T: num_traits::Float, Unit: UnitRatio>(self) -> T {
-        let numerator = self.count;
-        let denominator = Unit::ATTOSECONDS;
-        let quotient = T::from(numerator / denominator).unwrap();
-        let remainder = T::from(numerator % denominator).unwrap();
-        let fraction = remainder / T::from(denominator).unwrap();
-        quotient + fraction
+    pub const fn is_whole_days(&self) -> bool {
+        self.is_whole::<SecondsPerDay>()
     }
-}
-
-/// Verifies that approximation of equivalent float values results in the correct values. For some
-/// of these values, we look for an exact match, since we know that the value may be represented
-/// exactly as a float.
-#[test]
-#[allow(clippy::float_cmp, reason = "Exact values expected")]
-fn approximate_floats() {
-    let millisecond = Duration::milliseconds(1);
-    let seconds = millisecond.as_float::<f64, Second>();
-    assert_eq!(seconds, 0.001);
-
-    let attosecond = Duration::attoseconds(1);
-    let seconds = attosecond.as_float::<f64, Second>();
-    assert_eq!(seconds, 1e-18);
-
-    let day = Duration::days(1);
-    let seconds = day.as_float::<f32, Second>();
-    assert_eq!(seconds, 86400.);
-    let hours = day.as_float::<f32, SecondsPerHour>();
-    assert_eq!(hours, 24.);
-    let weeks = day.as_float::<f64, SecondsPerWeek>();
-    assert!((weeks - 1. / 7.).abs() < 0.1);
-
-    let year = Duration::years(1);
-    let days = year.as_float::<f64, SecondsPerDay>();
-    assert_eq!(days, 365.2425);
-    let months = year.as_float::<f64, SecondsPerMonth>();
-    assert_eq!(months, 12.);
-}
 
-impl core::fmt::Display for Duration {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.is_negative() {
-            write!(f, "-")?;
+    /// Rounds this `Duration` to the nearest integer multiple of `granularity`, with ties rounded
+    /// towards positive infinity. Only the magnitude of `granularity` is considered. A zero
+    /// `granularity` leaves the `Duration` unchanged.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, which may only happen for durations
+    /// within half a `granularity` of `Duration::MIN` or `Duration::MAX`. Use `checked_round_to`
+    /// to handle such durations.
+    #[must_use]
+    pub const fn round_to(self, granularity: Self) -> Self {
+        match self.checked_round_to(granularity) {
+            Some(duration) => duration,
+            None => panic!("{}", OVERFLOW_MESSAGE),
         }
+    }
 
-        let (days, remainder) = self.factor_out::<SecondsPerDay>();
-        let (hours, remainder) = remainder.factor_out::<SecondsPerHour>();
-        let (minutes, remainder) = remainder.factor_out::<SecondsPerMinute>();
-        let (seconds, remainder) = remainder.factor_out::<Second>();
-        write!(f, "P")?;
-        if days != 0 {
-            write!(f, "{}D", days.abs())?;
-        }
-        write!(f, "T")?;
-        if hours != 0 {
-            write!(f, "{}H", hours.abs())?;
-        }
-        if minutes != 0 {
-            write!(f, "{}M", minutes.abs())?;
+    /// Rounds this `Duration` to the nearest integer multiple of `granularity`, as for
+    /// `round_to`. Returns `None` if the rounded duration is not representable.
+    #[must_use]
+    pub const fn checked_round_to(self, granularity: Self) -> Option<Self> {
+        let Some(granularity) = granularity.count.checked_abs() else {
+            // The magnitude of `Duration::MIN` is not representable itself, so its only multiples
+            // are zero and `Duration::MIN`: half of it is the rounding boundary between them.
+            let half = i128::MIN / 2;
+            return if self.count >= -half {
+                None
+            } else if self.count >= half {
+                Some(Self::ZERO)
+            } else {
+                Some(Self::MIN)
+            };
+        };
+        if granularity == 0 {
+            return Some(self);
         }
-        if seconds != 0 || !remainder.is_zero() {
-            write!(f, "{}", seconds.abs())?;
-            if !remainder.is_zero() {
-                write!(f, ".")?;
-                // Set maximum number of digits after the decimal point printed based on precision
-                // argument given to the formatter.
-                let max_digits_printed = f.precision();
-                for digit in remainder.decimal_digits(max_digits_printed) {
-                    write!(f, "{digit}")?;
-                }
-            }
-            write!(f, "S")?;
+        let quotient = div_floor(self.count, granularity);
+        let remainder = self.count.rem_euclid(granularity);
+        let quotient = if remainder >= granularity - granularity / 2 {
+            quotient + 1
+        } else {
+            quotient
+        };
+        match quotient.checked_mul(granularity) {
+            Some(count) => Some(Self { count }),
+            None => None,
         }
-        Ok(())
     }
-}
 
-impl From<Days> for Duration {
-    fn from(value: Days) -> Self {
-        value.into_duration()
+    /// Rounds this `Duration` towards negative infinity, to an integer multiple of `granularity`.
+    /// Only the magnitude of `granularity` is considered. A zero `granularity` leaves the
+    /// `Duration` unchanged.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, which may only happen for durations
+    /// within one `granularity` of `Duration::MIN`.
+    #[must_use]
+    pub const fn floor_to(self, granularity: Self) -> Self {
+        let Some(granularity) = granularity.count.checked_abs() else {
+            // Multiples of the magnitude of `Duration::MIN` are zero and `Duration::MIN` only.
+            return if self.count >= 0 {
+                Self::ZERO
+            } else {
+                Self::MIN
+            };
+        };
+        if granularity == 0 {
+            return self;
+        }
+        match div_floor(self.count, granularity).checked_mul(granularity) {
+            Some(count) => Self { count },
+            None => panic!("{}", OVERFLOW_MESSAGE),
+        }
     }
-}
-
-impl<T> Mul<T> for Duration
-where
-    T: Into<i128>,
-{
-    type Output = Self;
 
-    /// A `Duration` may not be multiplied with another `Duration` (as that is undefined), but it may
-    /// be multiplied with unitless numbers.
-    fn mul(self, rhs: T) -> Self::Output {
-        Self::Output {
-            count: self.count * rhs.into(),
+    /// Rounds this `Duration` towards positive infinity, to an integer multiple of `granularity`.
+    /// Only the magnitude of `granularity` is considered. A zero `granularity` leaves the
+    /// `Duration` unchanged.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, which may only happen for durations
+    /// within one `granularity` of `Duration::MAX`.
+    #[must_use]
+    pub const fn ceil_to(self, granularity: Self) -> Self {
+        let Some(granularity) = granularity.count.checked_abs() else {
+            // Multiples of the magnitude of `Duration::MIN` are zero and `Duration::MIN` only.
+            return if self.count > 0 {
+                panic!("{}", OVERFLOW_MESSAGE)
+            } else if self.count > i128::MIN {
+                Self::ZERO
+            } else {
+                Self::MIN
+            };
+        };
+        if granularity == 0 {
+            return self;
+        }
+        match div_ceil(self.count, granularity).checked_mul(granularity) {
+            Some(count) => Self { count },
+            None => panic!("{}", OVERFLOW_MESSAGE),
         }
     }
-}
-
-impl Div for Duration {
-    type Output = i128;
 
-    fn div(self, rhs: Self) -> Self::Output {
-        self.count / rhs.count
+    /// Rounds this `Duration` to the nearest number of seconds with `places` fractional digits,
+    /// with ties rounded towards positive infinity. For `places` of 18 or more, the `Duration` is
+    /// already exact and is returned unchanged.
+    ///
+    /// # Panics
+    /// Panics if the rounded duration is not representable, as for `round_to`.
+    #[must_use]
+    pub const fn round_to_decimals(self, places: u32) -> Self {
+        match self.checked_round_to_decimals(places) {
+            Some(duration) => duration,
+            None => panic!("{}", OVERFLOW_MESSAGE),
+        }
     }
-}
 
-impl<T> Div<T> for Duration
-where
-    T: Into<i128>,
-{
-    type Output = Self;
-
-    /// A `Duration` may may be divided by unitless numbers to obtain a new `Duration`.
-    fn div(self, rhs: T) -> Self::Output {
-        Self::Output {
-            count: self.count / rhs.into(),
+    /// Rounds this `Duration` to the nearest number of seconds with `places` fractional digits,
+    /// as for `round_to_decimals`. Returns `None` if the rounded duration is not representable.
+    #[must_use]
+    pub const fn checked_round_to_decimals(self, places: u32) -> Option<Self> {
+        if places >= 18 {
+            return Some(self);
         }
+        self.checked_round_to(Self::attoseconds(10i128.pow(18 - places)))
     }
-}
 
-impl Bounded for Duration {
-    /// Returns the `Duration` value that is nearest to negative infinity.
-    fn min_value() -> Self {
-        Self { count: i128::MIN }
+    /// Returns the number of whole days contained in this `Duration`, truncated towards zero.
+    #[must_use]
+    pub const fn whole_days(self) -> i128 {
+        self.count / SecondsPerDay::ATTOSECONDS
     }
 
-    /// Returns the `Duration` value that is nearest to positive infinity.
-    fn max_value() -> Self {
-        Self { count: i128::MAX }
+    /// Returns the number of whole seconds contained in this `Duration`, truncated towards zero.
+    #[must_use]
+    pub const fn whole_seconds(self) -> i128 {
+        self.count / Second::ATTOSECONDS
     }
-}
 
-impl Zero for Duration {
-    /// Returns a `Duration` value that represents no time passed.
-    fn zero() -> Self {
-        Self {
-            count: i128::zero(),
-        }
+    /// Returns the number of whole milliseconds contained in this `Duration`, truncated towards
+    /// zero.
+    #[must_use]
+    pub const fn whole_milliseconds(self) -> i128 {
+        self.count / Milli::ATTOSECONDS
     }
 
-    /// Whether this `Duration` has any duration.
-    fn is_zero(&self) -> bool {
-        self.count.is_zero()
+    /// Returns the number of whole nanoseconds contained in this `Duration`, truncated towards
+    /// zero.
+    #[must_use]
+    pub const fn whole_nanoseconds(self) -> i128 {
+        self.count / Nano::ATTOSECONDS
     }
-}
 
-impl ConstZero for Duration {
-    const ZERO: Self = Self { count: i128::ZERO };
-}
-
-impl Duration {
-    #[must_use]
-    pub const fn abs(&self) -> Self {
-        Self {
-            count: self.count.abs(),
+    /// Converts this `Duration` into a `Days`, rounding to the nearest whole day. Ties are rounded
+    /// towards positive infinity.
+    ///
+    /// # Errors
+    /// Will return an error if the resulting number of days does not fit inside a `Days`.
+    pub fn to_days_round(self) -> Result<Days, DaysOverflow> {
+        let day = SecondsPerDay::ATTOSECONDS;
+        let days = div_floor(self.count, day);
+        if self.count.rem_euclid(day) >= day - day / 2 {
+            Self::narrow_days(days + 1)
+        } else {
+            Self::narrow_days(days)
         }
     }
 
-    #[must_use]
-    pub fn abs_sub(&self, other: &Self) -> Self {
-        Self {
-            count: self.count.abs_sub(&other.count),
-        }
+    /// Converts this `Duration` into a `Days`, rounding towards negative infinity.
+    ///
+    /// # Errors
+    /// Will return an error if the resulting number of days does not fit inside a `Days`.
+    pub fn to_days_floor(self) -> Result<Days, DaysOverflow> {
+        Self::narrow_days(div_floor(self.count, SecondsPerDay::ATTOSECONDS))
     }
 
-    #[must_use]
-    pub const fn signum(&self) -> Self {
-        Self {
-            count: self.count.signum(),
-        }
+    /// Converts this `Duration` into a `Days`, rounding towards positive infinity.
+    ///
+    /// # Errors
+    /// Will return an error if the resulting number of days does not fit inside a `Days`.
+    pub fn to_days_ceil(self) -> Result<Days, DaysOverflow> {
+        Self::narrow_days(div_ceil(self.count, SecondsPerDay::ATTOSECONDS))
     }
 
-    #[must_use]
-    pub const fn is_positive(&self) -> bool {
-        self.count.is_positive()
+    /// Narrows a number of days, as obtained by dividing the attosecond count, into `Days`. Since
+    /// the division itself cannot overflow, this is the only step that may fail.
+    fn narrow_days(days: i128) -> Result<Days, DaysOverflow> {
+        i32::try_from(days)
+            .map(Days::new)
+            .map_err(|_| DaysOverflow { days })
     }
 
-    #[must_use]
-    pub const fn is_negative(&self) -> bool {
+    /// Segments this `Duration` by factoring out the largest possible number of whole multiples of
+    /// a given unit. Returns this whole number as well as the remainder.
+    ///
+    /// An example would be factoring out the number of whole days from some elapsed time: then,
+    /// `self.factor_out()` would return a tuple of the number of whole days and the fractional
+    /// day part that remains.
+    #[must_use]
+    pub fn factor_out<Unit>(self) -> (i128, Self)
+    where
+        Unit: UnitRatio + ?Sized,
+    {
+        let factored = self.truncate::<Unit>();
+        let remainder = self - factored;
+        let factored = factored.count() / Unit::ATTOSECONDS;
+        (factored, remainder)
+    }
+
+    /// Decomposes this `Duration` into whole days, hours, minutes, and seconds, and the remaining
+    /// fractional seconds. A day is taken to consist of exactly 86400 seconds. Each component is
+    /// truncated towards zero, so all components share the sign of the duration: see
+    /// `DurationComponents` for details.
+    #[must_use]
+    pub fn components(&self) -> DurationComponents {
+        let (days, remainder) = self.factor_out::<SecondsPerDay>();
+        let (hours, remainder) = remainder.factor_out::<SecondsPerHour>();
+        let (minutes, remainder) = remainder.factor_out::<SecondsPerMinute>();
+        let (seconds, subseconds) = remainder.factor_out::<Second>();
+        DurationComponents {
+            days,
+            hours,
+            minutes,
+            seconds,
+            subseconds,
+        }
+    }
+
+    /// Divides by an `i128`, rounding to the nearest result.
+    #[must_use]
+    pub const fn div_round(self, other: i128) -> Self {
+        let count = (self.count + other / 2) / other;
+        Self { count }
+    }
+
+    /// Divides by an `i128`, truncating towards zero as for `Div`. Returns `None` if `divisor` is
+    /// zero or if the result is not representable.
+    #[must_use]
+    pub const fn checked_div(self, divisor: i128) -> Option<Self> {
+        match self.count.checked_div(divisor) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Divides by an `i128`, rounding towards negative infinity.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    #[must_use]
+    pub fn div_floor(self, divisor: i128) -> Self {
+        Self {
+            count: num_integer::div_floor(self.count, divisor),
+        }
+    }
+
+    /// Divides by an `i128`, rounding towards positive infinity.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    #[must_use]
+    pub fn div_ceil(self, divisor: i128) -> Self {
+        Self {
+            count: num_integer::div_ceil(self.count, divisor),
+        }
+    }
+
+    /// Performs Euclidean division by an `i128`: the result is rounded such that the remainder is
+    /// never negative. For positive divisors, this is the same as rounding towards negative
+    /// infinity; for negative divisors, as rounding towards positive infinity.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    #[must_use]
+    pub const fn div_euclid(self, divisor: i128) -> Self {
+        Self {
+            count: self.count.div_euclid(divisor),
+        }
+    }
+
+    /// Performs Euclidean division by another `Duration`, returning the number of whole periods
+    /// `rhs` contained in this `Duration`. The quotient is rounded such that the corresponding
+    /// remainder, as returned by `rem_euclid`, is never negative.
+    ///
+    /// This is the `Duration`-valued counterpart of `div_euclid`, which divides by a unitless
+    /// number instead.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero, or if the result overflows, which only happens when dividing
+    /// `Duration::MIN` by minus one attosecond.
+    #[must_use]
+    pub const fn div_duration_euclid(self, rhs: Self) -> i128 {
+        self.count.div_euclid(rhs.count)
+    }
+
+    /// Returns the non-negative remainder of Euclidean division by another `Duration`: the result
+    /// always lies in `[0, |rhs|)`, also for negative durations. This is useful to determine the
+    /// phase within some recurring period, like the time of day.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[must_use]
+    pub const fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            count: self.count.rem_euclid(rhs.count),
+        }
+    }
+
+    /// Returns the greatest common divisor of two durations: the longest duration of which both
+    /// are an integer multiple. Durations are treated as integer multiples of one attosecond, so
+    /// the result is always at least one attosecond, unless both durations are zero. The result is
+    /// never negative.
+    ///
+    /// # Panics
+    /// Panics if the result is not representable, which happens only if each duration is either
+    /// zero or `Duration::MIN`, and at least one of them is `Duration::MIN`.
+    #[must_use]
+    pub fn gcd(self, other: Self) -> Self {
+        let gcd = num_integer::gcd(self.count.unsigned_abs(), other.count.unsigned_abs());
+        Self {
+            count: i128::try_from(gcd).expect("greatest common divisor is not representable"),
+        }
+    }
+
+    /// Returns the least common multiple of two durations: the shortest duration that is an
+    /// integer multiple of both. Durations are treated as integer multiples of one attosecond. The
+    /// result is never negative, and zero if either duration is zero. Returns `None` if the result
+    /// is not representable.
+    #[must_use]
+    pub fn lcm(self, other: Self) -> Option<Self> {
+        if self.count == 0 || other.count == 0 {
+            return Some(Self::ZERO);
+        }
+        let (left, right) = (self.count.unsigned_abs(), other.count.unsigned_abs());
+        let lcm = (left / num_integer::gcd(left, right)).checked_mul(right)?;
+        let count = i128::try_from(lcm).ok()?;
+        Some(Self { count })
+    }
+
+    /// Adds two durations, returning `None` if the result is not representable.
+    #[must_use]
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.count.checked_add(other.count) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Subtracts two durations, returning `None` if the result is not representable.
+    #[must_use]
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.count.checked_sub(other.count) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Adds two durations, saturating at `Duration::min_value()` or `Duration::max_value()` if the
+    /// result is not representable.
+    #[must_use]
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Self {
+            count: self.count.saturating_add(other.count),
+        }
+    }
+
+    /// Subtracts two durations, saturating at `Duration::min_value()` or `Duration::max_value()`
+    /// if the result is not representable.
+    #[must_use]
+    pub const fn saturating_sub(self, other: Self) -> Self {
+        Self {
+            count: self.count.saturating_sub(other.count),
+        }
+    }
+
+    /// Multiplies by an `i128`, returning `None` if the result is not representable.
+    #[must_use]
+    pub const fn checked_mul(self, factor: i128) -> Option<Self> {
+        match self.count.checked_mul(factor) {
+            Some(count) => Some(Self { count }),
+            None => None,
+        }
+    }
+
+    /// Converts into a float approximation of the stored duration, expressed in the desired units.
+    /// For maximum numerical precision, first reduces the magnitude of the fraction by computing
+    /// the integer quotient: in this manner, only the computation of the fractional part loses
+    /// numerical precision.
+    ///
+    /// The integer quotient is represented exactly as long as it fits in the mantissa of `T` (up
+    /// to 2^53 units for `f64`, 2^24 for `f32`). Beyond that, and in any case after adding the
+    /// fractional part, the result is correct to within one unit in the last place: for `f64`,
+    /// this is a relative error of about 2.2e-16. At 10,000 years, for example, this bounds the
+    /// error of a conversion into seconds to about 61 microseconds. Use `as_float_units` or
+    /// `as_compensated_float` if more precision is required.
+    ///
+    /// # Panics
+    /// May panic if the quotient or remainder of the division by the unit ratio cannot be
+    /// represented by the provided type `T`.
+    #[must_use]
+    pub fn as_float<T: num_traits::Float, Unit: UnitRatio>(self) -> T {
+        let (quotient, fraction) = self.as_float_units::<T, Unit>();
+        quotient + fraction
+    }
+
+    /// Converts into a float approximation of the stored duration, expressed in the desired units,
+    /// but returns the integer and fractional parts separately. Both parts carry the sign of the
+    /// duration, and the fractional part is at most one unit in magnitude. Since the
+    /// parts are not summed, the fractional part does not lose precision to the (possibly much
+    /// larger) integer part.
+    ///
+    /// # Panics
+    /// May panic if the quotient or remainder of the division by the unit ratio cannot be
+    /// represented by the provided type `T`.
+    #[must_use]
+    pub fn as_float_units<T: num_traits::Float, Unit: UnitRatio>(self) -> (T, T) {
+        let numerator = self.count;
+        let denominator = Unit::ATTOSECONDS;
+        let quotient = T::from(numerator / denominator).unwrap();
+        let remainder = T::from(numerator % denominator).unwrap();
+        let fraction = remainder / T::from(denominator).unwrap();
+        (quotient, fraction)
+    }
+
+    /// Converts into a float approximation of the stored duration, expressed in the desired units,
+    /// together with a compensation term that captures the rounding error of that approximation.
+    /// The first element is at least as accurate as the result of `as_float`; adding the second
+    /// element to it (in higher precision) recovers the duration to within the precision of the
+    /// fractional part.
+    ///
+    /// The compensation term may be used for Kahan-style summation: when accumulating many
+    /// converted durations, carry the compensation terms along separately and add them to the
+    /// running sum only at the end.
+    ///
+    /// # Panics
+    /// May panic if the quotient or remainder of the division by the unit ratio cannot be
+    /// represented by the provided type `T`.
+    #[must_use]
+    pub fn as_compensated_float<T: num_traits::Float, Unit: UnitRatio>(self) -> (T, T) {
+        let numerator = self.count;
+        let denominator = Unit::ATTOSECONDS;
+        let quotient = numerator / denominator;
+        let approximate_quotient = T::from(quotient).unwrap();
+        let quotient_error = quotient - approximate_quotient.to_i128().unwrap();
+        let quotient_error = T::from(quotient_error).unwrap();
+        let remainder = T::from(numerator % denominator).unwrap();
+        let fraction = remainder / T::from(denominator).unwrap();
+
+        // Fast two-sum: since the magnitude of the approximate quotient is larger than that of
+        // the correction term, the rounding error of the sum is exactly recovered.
+        let correction = quotient_error + fraction;
+        let sum = approximate_quotient + correction;
+        let compensation = correction - (sum - approximate_quotient);
+        (sum, compensation)
+    }
+
+    /// Constructs a `Duration` from a float number of the desired units. The integer and
+    /// fractional parts are converted separately, such that no precision is lost beyond that of
+    /// `T` itself: the result is rounded to the nearest attosecond.
+    ///
+    /// # Panics
+    /// Panics if `count` is not finite, or if the resulting duration is not representable.
+    #[must_use]
+    pub fn from_float<T: num_traits::Float, Unit: UnitRatio>(count: T) -> Self {
+        match Self::try_from_float::<T, Unit>(count) {
+            Ok(duration) => duration,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Constructs a `Duration` from a float number of the desired units, as `from_float`.
+    ///
+    /// # Errors
+    /// Returns an error if `count` is not finite, or if the resulting duration is not
+    /// representable.
+    #[allow(clippy::missing_panics_doc, reason = "Infallible")]
+    pub fn try_from_float<T: num_traits::Float, Unit: UnitRatio>(
+        count: T,
+    ) -> Result<Self, FloatDurationError> {
+        if !count.is_finite() {
+            return Err(FloatDurationError::NonFinite);
+        }
+        let whole = count.trunc();
+        let fraction = (count - whole) * T::from(Unit::ATTOSECONDS).unwrap();
+        let count = whole
+            .to_i128()
+            .and_then(|whole| whole.checked_mul(Unit::ATTOSECONDS))
+            .zip(fraction.round().to_i128())
+            .and_then(|(whole, fraction)| whole.checked_add(fraction))
+            .ok_or(FloatDurationError::OutOfRange)?;
+        Ok(Self { count })
+    }
+
+    /// Constructs a `Duration` from a float number of seconds, rounded to the nearest attosecond.
+    ///
+    /// # Errors
+    /// Returns an error if `seconds` is not finite, or if the resulting duration is not
+    /// representable.
+    pub fn from_seconds_f64(seconds: f64) -> Result<Self, FloatDurationError> {
+        Self::try_from_float::<f64, Second>(seconds)
+    }
+
+    /// Constructs a `Duration` from a float number of minutes, rounded to the nearest attosecond.
+    ///
+    /// # Errors
+    /// Returns an error if `minutes` is not finite, or if the resulting duration is not
+    /// representable.
+    pub fn from_minutes_f64(minutes: f64) -> Result<Self, FloatDurationError> {
+        Self::try_from_float::<f64, SecondsPerMinute>(minutes)
+    }
+
+    /// Constructs a `Duration` from a float number of days, rounded to the nearest attosecond.
+    ///
+    /// # Errors
+    /// Returns an error if `days` is not finite, or if the resulting duration is not
+    /// representable.
+    pub fn from_days_f64(days: f64) -> Result<Self, FloatDurationError> {
+        Self::try_from_float::<f64, SecondsPerDay>(days)
+    }
+}
+
+/// Returns the exact error of some float approximation of a duration, in attoseconds.
+#[cfg(test)]
+#[allow(
+    clippy::cast_possible_truncation,
+    reason = "Values are integral and in range"
+)]
+fn float_error(approximation: f64, duration: Duration) -> i128 {
+    let whole_seconds = approximation.trunc();
+    let fraction = approximation - whole_seconds;
+    let attoseconds = (whole_seconds as i128) * Second::ATTOSECONDS + (fraction * 1e18) as i128;
+    attoseconds - duration.count()
+}
+
+/// Verifies that the precision of float conversions of very large durations stays within the
+/// documented bounds. At 10,000 years, one unit in the last place of an `f64` number of seconds
+/// is about 61 microseconds.
+#[test]
+#[allow(clippy::cast_possible_truncation, reason = "Compensation is small")]
+#[allow(clippy::float_cmp, reason = "Exact values expected")]
+fn float_precision_at_ten_thousand_years() {
+    let unit_in_last_place = Duration::microseconds(62).count();
+    for offset in [
+        Duration::ZERO,
+        Duration::attoseconds(1),
+        Duration::microseconds(123_456),
+        Duration::milliseconds(999),
+        Duration::nanoseconds(987_654_321),
+        Duration::seconds(1) - Duration::attoseconds(1),
+    ] {
+        for duration in [
+            Duration::years(10_000) + offset,
+            -Duration::years(10_000) - offset,
+        ] {
+            let approximation = duration.as_float::<f64, Second>();
+            assert!(float_error(approximation, duration).abs() <= unit_in_last_place);
+
+            let (whole, fraction) = duration.as_float_units::<f64, Second>();
+            assert_eq!(whole.fract(), 0.);
+            assert!(fraction.abs() <= 1.);
+            assert_eq!(whole + fraction, approximation);
+
+            // The compensated sum must be accurate to well below a microsecond.
+            let (sum, compensation) = duration.as_compensated_float::<f64, Second>();
+            assert_eq!(sum, approximation);
+            let residual = float_error(sum, duration) + (compensation * 1e18) as i128;
+            assert!(residual.abs() < Duration::nanoseconds(1).count());
+        }
+    }
+}
+
+/// Verifies that durations constructed from hours, minutes, and seconds equal the sum of their
+/// components, including for negative components.
+#[test]
+fn from_hms() {
+    assert_eq!(
+        Duration::from_hms(1, 2, 3),
+        Duration::hours(1) + Duration::minutes(2) + Duration::seconds(3)
+    );
+    assert_eq!(
+        Duration::from_hms(-2, 30, 0),
+        Duration::hours(-2) + Duration::minutes(30)
+    );
+    assert_eq!(Duration::from_hms(-1, 0, 0), -Duration::hours(1));
+    assert_eq!(
+        Duration::from_hms_nano(0, 0, 1, 500_000_000),
+        Duration::milliseconds(1500)
+    );
+    assert_eq!(Duration::checked_from_hms_nano(i64::MAX, 0, 0, 0), None);
+    assert_eq!(Duration::checked_from_hms_nano(0, i64::MIN, 0, 0), None);
+    assert!(Duration::checked_from_hms_nano(0, 0, i64::MAX, i64::MAX).is_some());
+}
+
+/// Verifies that constructing a non-representable duration from hours, minutes, and seconds panics
+/// with the common overflow message.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn from_hms_overflow() {
+    let _ = Duration::from_hms(i64::MAX, 0, 0);
+}
+
+/// Verifies that approximation of equivalent float values results in the correct values. For some
+/// of these values, we look for an exact match, since we know that the value may be represented
+/// exactly as a float.
+#[test]
+#[allow(clippy::float_cmp, reason = "Exact values expected")]
+fn approximate_floats() {
+    let millisecond = Duration::milliseconds(1);
+    let seconds = millisecond.as_float::<f64, Second>();
+    assert_eq!(seconds, 0.001);
+
+    let attosecond = Duration::attoseconds(1);
+    let seconds = attosecond.as_float::<f64, Second>();
+    assert_eq!(seconds, 1e-18);
+
+    let day = Duration::days(1);
+    let seconds = day.as_float::<f32, Second>();
+    assert_eq!(seconds, 86400.);
+    let hours = day.as_float::<f32, SecondsPerHour>();
+    assert_eq!(hours, 24.);
+    let weeks = day.as_float::<f64, SecondsPerWeek>();
+    assert!((weeks - 1. / 7.).abs() < 0.1);
+
+    let year = Duration::years(1);
+    let days = year.as_float::<f64, SecondsPerDay>();
+    assert_eq!(days, 365.2425);
+    let months = year.as_float::<f64, SecondsPerMonth>();
+    assert_eq!(months, 12.);
+}
+
+/// Verifies that durations are correctly constructed from float values, rounding to the nearest
+/// attosecond.
+#[test]
+fn from_floats() {
+    assert_eq!(
+        Duration::from_float::<f64, Second>(0.001),
+        Duration::milliseconds(1)
+    );
+    assert_eq!(
+        Duration::from_float::<f64, Second>(-1.5),
+        Duration::milliseconds(-1500)
+    );
+    assert_eq!(
+        Duration::from_float::<f64, SecondsPerDay>(1.25),
+        Duration::hours(30)
+    );
+    assert_eq!(
+        Duration::from_float::<f32, SecondsPerHour>(24.),
+        Duration::days(1)
+    );
+    assert_eq!(
+        Duration::from_float::<f64, Second>(1e-18),
+        Duration::attoseconds(1)
+    );
+}
+
+/// Verifies that rounding towards a unit may be done in const contexts, and that negative
+/// durations are rounded in the correct direction.
+#[test]
+fn const_unit_rounding() {
+    const POSITIVE: Duration = Duration::milliseconds(1_700);
+    const NEGATIVE: Duration = Duration::milliseconds(-1_700);
+    const ROUNDED: [Duration; 6] = [
+        POSITIVE.round::<Second>(),
+        POSITIVE.ceil::<Second>(),
+        POSITIVE.floor::<Second>(),
+        NEGATIVE.round::<Second>(),
+        NEGATIVE.ceil::<Second>(),
+        NEGATIVE.floor::<Second>(),
+    ];
+    const TIME_POINT: crate::TaiTime =
+        crate::TaiTime::from_time_since_epoch(Duration::milliseconds(-1_700));
+    const TIME_POINTS: [crate::TaiTime; 3] = [
+        TIME_POINT.round::<Second>(),
+        TIME_POINT.ceil::<Second>(),
+        TIME_POINT.floor::<Second>(),
+    ];
+    assert_eq!(
+        ROUNDED,
+        [
+            Duration::seconds(2),
+            Duration::seconds(2),
+            Duration::seconds(1),
+            Duration::seconds(-2),
+            Duration::seconds(-1),
+            Duration::seconds(-2),
+        ]
+    );
+
+    let negative = Duration::milliseconds(-1_200);
+    assert_eq!(negative.round::<Second>(), Duration::seconds(-1));
+    assert_eq!(negative.ceil::<Second>(), Duration::seconds(-1));
+    assert_eq!(negative.floor::<Second>(), Duration::seconds(-2));
+
+    // Ties are rounded towards positive infinity.
+    assert_eq!(
+        Duration::milliseconds(1_500).round::<Second>(),
+        Duration::seconds(2)
+    );
+    assert_eq!(
+        Duration::milliseconds(-1_500).round::<Second>(),
+        Duration::seconds(-1)
+    );
+
+    // Whole units are left unchanged.
+    let whole = Duration::seconds(-3);
+    assert_eq!(whole.round::<Second>(), whole);
+    assert_eq!(whole.ceil::<Second>(), whole);
+    assert_eq!(whole.floor::<Second>(), whole);
+
+    assert_eq!(
+        TIME_POINTS.map(|time_point| time_point.time_since_epoch()),
+        [
+            Duration::seconds(-2),
+            Duration::seconds(-1),
+            Duration::seconds(-2),
+        ]
+    );
+}
+
+/// Verifies that rounding near the representable extremes does not wrap around: `checked_round`
+/// returns `None` if the rounded duration is not representable.
+#[test]
+fn checked_round_overflow() {
+    assert_eq!(Duration::MAX.checked_round::<Second>(), None);
+    assert_eq!(Duration::MIN.checked_round::<Second>(), None);
+    assert_eq!(
+        Duration::MAX.checked_round::<crate::Atto>(),
+        Some(Duration::MAX)
+    );
+
+    // Durations that round down remain representable, also near the extremes.
+    let last_second = Duration::MAX.floor::<Second>();
+    assert_eq!(last_second.checked_round::<Second>(), Some(last_second));
+    assert_eq!(
+        (last_second + Duration::milliseconds(400)).checked_round::<Second>(),
+        Some(last_second)
+    );
+    let first_second = Duration::MIN.ceil::<Second>();
+    assert_eq!(
+        (first_second - Duration::milliseconds(400)).checked_round::<Second>(),
+        Some(first_second)
+    );
+
+    assert_eq!(
+        Duration::milliseconds(-1_700).checked_round::<Second>(),
+        Some(Duration::seconds(-2))
+    );
+}
+
+/// Verifies that rounding a duration that is not representable after rounding panics.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn round_overflow() {
+    let _ = Duration::MAX.round::<Second>();
+}
+
+/// Verifies that banker's rounding of a duration that is not representable after rounding panics.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn round_half_even_overflow() {
+    let _ = Duration::MAX.round_half_even::<Second>();
+}
+
+/// Verifies that rounding up a duration that is not representable after rounding panics.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn ceil_overflow() {
+    let _ = Duration::MAX.ceil::<Second>();
+}
+
+/// Verifies that rounding down a duration that is not representable after rounding panics.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn floor_overflow() {
+    let _ = Duration::MIN.floor::<Second>();
+}
+
+/// Verifies that ties are rounded towards the nearest even unit by `round_half_even`, and that
+/// other values are rounded to the nearest unit as for `round`.
+#[test]
+fn round_half_even() {
+    for (milliseconds, seconds) in [
+        (500, 0),
+        (1_500, 2),
+        (2_500, 2),
+        (3_500, 4),
+        (-500, 0),
+        (-1_500, -2),
+        (-2_500, -2),
+        (2_499, 2),
+        (2_501, 3),
+        (-2_501, -3),
+        (-2_499, -2),
+        (3_000, 3),
+        (0, 0),
+    ] {
+        assert_eq!(
+            Duration::milliseconds(milliseconds).round_half_even::<Second>(),
+            Duration::seconds(seconds),
+            "{milliseconds} ms"
+        );
+    }
+
+    // Only ties are treated differently from `round`.
+    assert_eq!(
+        Duration::milliseconds(2_500).round::<Second>(),
+        Duration::seconds(3)
+    );
+    assert_eq!(
+        Duration::minutes(90).round_half_even::<SecondsPerHour>(),
+        Duration::hours(2)
+    );
+}
+
+/// Verifies rounding towards arbitrary granularities, including negative durations and the
+/// degenerate zero granularity.
+#[test]
+fn round_to_granularity() {
+    let quarter = Duration::minutes(15);
+    assert_eq!(
+        Duration::minutes(22).round_to(quarter),
+        Duration::minutes(15)
+    );
+    assert_eq!(
+        (Duration::minutes(22) + Duration::seconds(30)).round_to(quarter),
+        Duration::minutes(30)
+    );
+    assert_eq!(
+        Duration::minutes(-8).round_to(quarter),
+        Duration::minutes(-15)
+    );
+    assert_eq!(Duration::minutes(-7).round_to(quarter), Duration::ZERO);
+
+    let deci = Duration::milliseconds(100);
+    assert_eq!(
+        Duration::milliseconds(1_234).floor_to(deci),
+        Duration::milliseconds(1_200)
+    );
+    assert_eq!(
+        Duration::milliseconds(-1_234).floor_to(deci),
+        Duration::milliseconds(-1_300)
+    );
+    assert_eq!(
+        Duration::milliseconds(1_234).ceil_to(deci),
+        Duration::milliseconds(1_300)
+    );
+    assert_eq!(
+        Duration::milliseconds(-1_234).ceil_to(deci),
+        Duration::milliseconds(-1_200)
+    );
+    assert_eq!(
+        Duration::milliseconds(1_200).ceil_to(-deci),
+        Duration::milliseconds(1_200)
+    );
+
+    let duration = Duration::attoseconds(12_345);
+    assert_eq!(duration.round_to(Duration::ZERO), duration);
+    assert_eq!(duration.floor_to(Duration::ZERO), duration);
+    assert_eq!(duration.ceil_to(Duration::ZERO), duration);
+}
+
+/// Verifies that rounding to a granularity near the extremes reports overflow rather than
+/// wrapping, and that `Duration::MIN` may itself be used as granularity.
+#[test]
+fn checked_round_to_extremes() {
+    let second = Duration::seconds(1);
+    assert_eq!(Duration::MAX.checked_round_to(second), None);
+    assert_eq!(Duration::MIN.checked_round_to(second), None);
+    assert_eq!(
+        (Duration::MIN + Duration::milliseconds(500)).checked_round_to(second),
+        Some(Duration::MIN.ceil_to(second))
+    );
+    assert_eq!(
+        Duration::MAX.checked_round_to(Duration::MAX),
+        Some(Duration::MAX)
+    );
+    assert_eq!(Duration::MAX.checked_round_to_decimals(0), None);
+
+    assert_eq!(Duration::MAX.checked_round_to(Duration::MIN), None);
+    assert_eq!(
+        Duration::seconds(1).checked_round_to(Duration::MIN),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(
+        Duration::MIN.checked_round_to(Duration::MIN),
+        Some(Duration::MIN)
+    );
+    assert_eq!(Duration::MAX.floor_to(Duration::MIN), Duration::ZERO);
+    assert_eq!(Duration::seconds(-1).floor_to(Duration::MIN), Duration::MIN);
+    assert_eq!(Duration::seconds(-1).ceil_to(Duration::MIN), Duration::ZERO);
+}
+
+/// Verifies that whole numbers of sub-day units are truncated towards zero, also for negative
+/// durations.
+#[test]
+fn whole_sub_day_units() {
+    let duration = Duration::seconds(90) + Duration::attoseconds(1_500_000_001);
+    assert_eq!(duration.whole_seconds(), 90);
+    assert_eq!(duration.whole_milliseconds(), 90_000);
+    assert_eq!(duration.whole_nanoseconds(), 90_000_000_001);
+    assert_eq!((-duration).whole_seconds(), -90);
+    assert_eq!((-duration).whole_milliseconds(), -90_000);
+    assert_eq!((-duration).whole_nanoseconds(), -90_000_000_001);
+
+    assert_eq!(Duration::milliseconds(-999).whole_seconds(), 0);
+    assert_eq!(Duration::milliseconds(-1_999).whole_seconds(), -1);
+    assert_eq!(Duration::nanoseconds(-1).whole_milliseconds(), 0);
+    assert_eq!(Duration::attoseconds(-1).whole_nanoseconds(), 0);
+    assert_eq!(
+        Duration::MAX.whole_seconds(),
+        i128::MAX / 1_000_000_000_000_000_000
+    );
+}
+
+/// Verifies conversion of `Duration`s into whole `Days`, for each of the rounding modes.
+#[test]
+fn conversion_to_days() {
+    let day = Duration::seconds(86_400);
+    assert_eq!(day.whole_days(), 1);
+    assert_eq!(day.to_days_floor(), Ok(Days::new(1)));
+    assert_eq!(day.to_days_ceil(), Ok(Days::new(1)));
+    assert_eq!(day.to_days_round(), Ok(Days::new(1)));
+
+    let almost_day = Duration::seconds(86_399);
+    assert_eq!(almost_day.whole_days(), 0);
+    assert_eq!(almost_day.to_days_floor(), Ok(Days::new(0)));
+    assert_eq!(almost_day.to_days_ceil(), Ok(Days::new(1)));
+    assert_eq!(almost_day.to_days_round(), Ok(Days::new(1)));
+
+    let negative = Duration::hours(-36);
+    assert_eq!(negative.whole_days(), -1);
+    assert_eq!(negative.to_days_floor(), Ok(Days::new(-2)));
+    assert_eq!(negative.to_days_ceil(), Ok(Days::new(-1)));
+    assert_eq!(negative.to_days_round(), Ok(Days::new(-1)));
+
+    let overflow = Duration::days(i128::from(i32::MAX) + 1);
+    assert_eq!(
+        overflow.to_days_floor(),
+        Err(DaysOverflow {
+            days: i128::from(i32::MAX) + 1
+        })
+    );
+    assert!(Duration::max_value().to_days_floor().is_err());
+
+    // Rounding up near the extremes must be reported as overflow, not panic.
+    let max_days = div_ceil(i128::MAX, SecondsPerDay::ATTOSECONDS);
+    let min_days = div_floor(i128::MIN, SecondsPerDay::ATTOSECONDS);
+    assert_eq!(
+        Duration::MAX.to_days_ceil(),
+        Err(DaysOverflow { days: max_days })
+    );
+    assert_eq!(
+        Duration::MAX.to_days_round(),
+        Err(DaysOverflow { days: max_days })
+    );
+    assert_eq!(
+        Duration::MIN.to_days_floor(),
+        Err(DaysOverflow { days: min_days })
+    );
+    assert_eq!(
+        Duration::MIN.to_days_ceil(),
+        Err(DaysOverflow { days: min_days + 1 })
+    );
+    assert_eq!(
+        Duration::MIN.to_days_round(),
+        Err(DaysOverflow { days: min_days })
+    );
+}
+
+/// Decomposition of a `Duration` into days, hours, minutes, seconds, and subseconds, as returned
+/// by `Duration::components()`.
+///
+/// Components are signed: a negative duration is represented by negating all its components, such
+/// that each component has the same sign as the duration itself (or is zero). Hence, the magnitude
+/// of each component may be used directly when printing, after printing a single sign for the
+/// whole duration. The `subseconds` component stores the fractional part of the seconds, and is
+/// strictly less than one second in magnitude.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DurationComponents {
+    pub days: i128,
+    pub hours: i128,
+    pub minutes: i128,
+    pub seconds: i128,
+    pub subseconds: Duration,
+}
+
+impl core::fmt::Display for Duration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+
+        let DurationComponents {
+            days,
+            hours,
+            minutes,
+            seconds,
+            subseconds: remainder,
+        } = self.components();
+        write!(f, "P")?;
+        if days != 0 {
+            write!(f, "{}D", days.abs())?;
+        }
+        write!(f, "T")?;
+        if hours != 0 {
+            write!(f, "{}H", hours.abs())?;
+        }
+        if minutes != 0 {
+            write!(f, "{}M", minutes.abs())?;
+        }
+        if seconds != 0 || !remainder.is_zero() || self.is_zero() {
+            write!(f, "{}", seconds.abs())?;
+            if !remainder.is_zero() {
+                write!(f, ".")?;
+                // Set maximum number of digits after the decimal point printed based on precision
+                // argument given to the formatter.
+                let max_digits_printed = f.precision();
+                for digit in remainder.decimal_digits(max_digits_printed) {
+                    write!(f, "{digit}")?;
+                }
+            }
+            write!(f, "S")?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper type used to render a `Duration` as mission elapsed time. Returned by
+/// `Duration::format_elapsed()`.
+struct ElapsedTime {
+    duration: Duration,
+}
+
+impl core::fmt::Display for ElapsedTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.duration.is_negative() {
+            write!(f, "-")?;
+        }
+
+        let DurationComponents {
+            days,
+            hours,
+            minutes,
+            seconds,
+            subseconds: remainder,
+        } = self.duration.components();
+        write!(
+            f,
+            "{:03}:{:02}:{:02}:{:02}",
+            days.abs(),
+            hours.abs(),
+            minutes.abs(),
+            seconds.abs()
+        )?;
+
+        let precision = f.precision().unwrap_or(3);
+        if precision > 0 {
+            write!(f, ".")?;
+            for digit in remainder.decimal_digits(Some(precision)) {
+                write!(f, "{digit}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Verifies mission elapsed time formatting, including day counts beyond three digits, negative
+/// durations, and custom precision.
+#[cfg(feature = "std")]
+#[test]
+fn elapsed_time_formatting() {
+    let duration = Duration::days(123)
+        + Duration::hours(4)
+        + Duration::minutes(5)
+        + Duration::seconds(6)
+        + Duration::milliseconds(789);
+    assert_eq!(duration.format_elapsed().to_string(), "123:04:05:06.789");
+    assert_eq!(
+        format!("{:.1}", duration.format_elapsed()),
+        "123:04:05:06.7"
+    );
+    assert_eq!(format!("{:.0}", duration.format_elapsed()), "123:04:05:06");
+    assert_eq!(
+        format!("{:.5}", duration.format_elapsed()),
+        "123:04:05:06.78900"
+    );
+
+    let duration = Duration::days(500) + Duration::seconds(1);
+    assert_eq!(duration.format_elapsed().to_string(), "500:00:00:01.000");
+
+    let duration = -(Duration::days(2) + Duration::hours(3) + Duration::milliseconds(250));
+    assert_eq!(duration.format_elapsed().to_string(), "-002:03:00:00.250");
+}
+
+/// Divides `numerator` by `denominator`, rounding towards negative infinity. Equivalent to
+/// `num_integer::div_floor`, but usable in const contexts.
+const fn div_floor(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder != 0 && ((remainder < 0) != (denominator < 0)) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+/// Divides `numerator` by `denominator`, rounding towards positive infinity. Equivalent to
+/// `num_integer::div_ceil`, but usable in const contexts.
+const fn div_ceil(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder != 0 && ((remainder < 0) == (denominator < 0)) {
+        quotient + 1
+    } else {
+        quotient
+    }
+}
+
+/// Message used when arithmetic on `Duration`s overflows.
+const OVERFLOW_MESSAGE: &str = "attotime: Duration overflow";
+
+impl Add for Duration {
+    type Output = Self;
+
+    /// Adds two `Duration`s.
+    ///
+    /// # Panics
+    /// Panics if the result is not representable, in all build modes.
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect(OVERFLOW_MESSAGE)
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Duration {
+    type Output = Self;
+
+    /// Subtracts two `Duration`s.
+    ///
+    /// # Panics
+    /// Panics if the result is not representable, in all build modes.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).expect(OVERFLOW_MESSAGE)
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Duration {
+    type Output = Self;
+
+    /// Negates a `Duration`.
+    ///
+    /// # Panics
+    /// Panics if the result is not representable, which is the case only for `Duration::MIN`.
+    fn neg(self) -> Self::Output {
+        Self {
+            count: self.count.checked_neg().expect(OVERFLOW_MESSAGE),
+        }
+    }
+}
+
+impl From<Days> for Duration {
+    fn from(value: Days) -> Self {
+        value.into_duration()
+    }
+}
+
+impl<T> Mul<T> for Duration
+where
+    T: Into<i128>,
+{
+    type Output = Self;
+
+    /// A `Duration` may not be multiplied with another `Duration` (as that is undefined), but it may
+    /// be multiplied with unitless numbers.
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::Output {
+            count: self.count * rhs.into(),
+        }
+    }
+}
+
+impl Div for Duration {
+    type Output = i128;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.count / rhs.count
+    }
+}
+
+impl<T> Div<T> for Duration
+where
+    T: Into<i128>,
+{
+    type Output = Self;
+
+    /// A `Duration` may may be divided by unitless numbers to obtain a new `Duration`.
+    fn div(self, rhs: T) -> Self::Output {
+        Self::Output {
+            count: self.count / rhs.into(),
+        }
+    }
+}
+
+impl Bounded for Duration {
+    /// Returns the `Duration` value that is nearest to negative infinity.
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    /// Returns the `Duration` value that is nearest to positive infinity.
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl Zero for Duration {
+    /// Returns a `Duration` value that represents no time passed.
+    fn zero() -> Self {
+        Self {
+            count: i128::zero(),
+        }
+    }
+
+    /// Whether this `Duration` has any duration.
+    fn is_zero(&self) -> bool {
+        self.count.is_zero()
+    }
+}
+
+impl ConstZero for Duration {
+    const ZERO: Self = Self::ZERO;
+}
+
+impl Default for Duration {
+    /// The default `Duration` is `Duration::ZERO`.
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl Duration {
+    #[must_use]
+    pub const fn abs(&self) -> Self {
+        Self {
+            count: self.count.abs(),
+        }
+    }
+
+    /// Returns the non-negative difference between two `Duration`s. Unlike `(self - other).abs()`,
+    /// this cannot overflow: if the magnitude of the difference exceeds the largest representable
+    /// `Duration`, it saturates at `Duration::max_value()`.
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Self {
+        Self {
+            count: i128::try_from(self.count.abs_diff(other.count)).unwrap_or(i128::MAX),
+        }
+    }
+
+    /// Returns whether two `Duration`s differ by at most `tolerance`. Useful to compare the results
+    /// of approximate computations, like conversions between time scales with different rates. A
+    /// negative `tolerance` never compares equal.
+    #[must_use]
+    pub fn approx_eq(self, other: Self, tolerance: Self) -> bool {
+        i128::try_from(self.count.abs_diff(other.count))
+            .is_ok_and(|difference| difference <= tolerance.count)
+    }
+
+    /// Returns the positive difference between `self` and `other`: `self - other` if `self` is
+    /// larger than `other`, and zero otherwise.
+    ///
+    /// # Panics
+    /// Panics if the difference is not representable as `Duration`.
+    #[must_use]
+    pub const fn positive_diff(&self, other: &Self) -> Self {
+        if self.count > other.count {
+            match self.count.checked_sub(other.count) {
+                Some(count) => Self { count },
+                None => panic!("{}", OVERFLOW_MESSAGE),
+            }
+        } else {
+            Self { count: 0 }
+        }
+    }
+
+    /// Deprecated alias of `positive_diff`.
+    ///
+    /// # Panics
+    /// Panics if the difference is not representable as `Duration`, as for `positive_diff`.
+    #[deprecated(note = "confusingly named, use `positive_diff` instead")]
+    #[must_use]
+    pub const fn abs_sub(&self, other: &Self) -> Self {
+        self.positive_diff(other)
+    }
+
+    /// Returns the sign of this `Duration`, expressed as a `Duration` of -1, 0, or 1 attoseconds.
+    /// To obtain the sign as a plain number, use `sign` instead.
+    #[must_use]
+    pub const fn signum(&self) -> Self {
+        Self {
+            count: self.count.signum(),
+        }
+    }
+
+    /// Returns -1 if this `Duration` is negative, 0 if it is zero, and 1 if it is positive.
+    #[must_use]
+    pub const fn sign(&self) -> i8 {
+        #[allow(
+            clippy::cast_possible_truncation,
+            reason = "Signum is always -1, 0, or 1"
+        )]
+        let sign = self.count.signum() as i8;
+        sign
+    }
+
+    /// Returns whether this `Duration` is greater than zero. Zero is not positive: use
+    /// `is_non_negative` to include it.
+    #[must_use]
+    pub const fn is_positive(&self) -> bool {
+        self.count.is_positive()
+    }
+
+    /// Returns whether this `Duration` is less than zero. Zero is not negative: use
+    /// `is_non_positive` to include it.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
         self.count.is_negative()
     }
+
+    /// Returns whether this `Duration` is greater than zero. Equivalent to `is_positive`, but
+    /// explicit about zero being excluded.
+    #[must_use]
+    pub const fn is_strictly_positive(&self) -> bool {
+        self.count > 0
+    }
+
+    /// Returns whether this `Duration` is less than zero. Equivalent to `is_negative`, but explicit
+    /// about zero being excluded.
+    #[must_use]
+    pub const fn is_strictly_negative(&self) -> bool {
+        self.count < 0
+    }
+
+    /// Returns whether this `Duration` is greater than or equal to zero.
+    #[must_use]
+    pub const fn is_non_negative(&self) -> bool {
+        self.count >= 0
+    }
+
+    /// Returns whether this `Duration` is less than or equal to zero.
+    #[must_use]
+    pub const fn is_non_positive(&self) -> bool {
+        self.count <= 0
+    }
+
+    /// Returns the smaller of two `Duration`s. Equivalent to `Ord::min`, but usable in constant
+    /// contexts.
+    #[must_use]
+    pub const fn min(self, other: Self) -> Self {
+        if self.count <= other.count {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Returns the larger of two `Duration`s. Equivalent to `Ord::max`, but usable in constant
+    /// contexts.
+    #[must_use]
+    pub const fn max(self, other: Self) -> Self {
+        if self.count >= other.count {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restricts this `Duration` to the interval `[lo, hi]`: returns `lo` if `self` is smaller
+    /// than `lo`, `hi` if `self` is larger than `hi`, and `self` otherwise. Equivalent to
+    /// `Ord::clamp`, but usable in constant contexts.
+    ///
+    /// # Panics
+    /// Panics if `lo > hi`.
+    #[must_use]
+    pub const fn clamp(self, lo: Self, hi: Self) -> Self {
+        assert!(lo.count <= hi.count, "lower bound exceeds upper bound");
+        if self.count < lo.count {
+            lo
+        } else if self.count > hi.count {
+            hi
+        } else {
+            self
+        }
+    }
+}
+
+/// Verifies that arithmetic works up to the boundaries of the representable range.
+#[test]
+fn arithmetic_at_boundaries() {
+    let atto = Duration::ATTOSECOND;
+    assert_eq!((Duration::MAX - atto) + atto, Duration::MAX);
+    assert_eq!((Duration::MIN + atto) - atto, Duration::MIN);
+    assert_eq!(-Duration::MAX, Duration::MIN + atto);
+    let mut duration = Duration::MAX;
+    duration -= Duration::MAX;
+    duration += Duration::MIN;
+    assert_eq!(duration, Duration::MIN);
+}
+
+/// Verifies that addition overflow panics with a clear message, in all build modes.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn add_overflow() {
+    let _ = Duration::MAX + Duration::ATTOSECOND;
+}
+
+/// Verifies that subtraction overflow panics with a clear message, in all build modes.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn sub_overflow() {
+    let mut duration = Duration::MIN;
+    duration -= Duration::ATTOSECOND;
+}
+
+/// Verifies that negation overflow panics with a clear message, in all build modes.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn neg_overflow() {
+    let _ = -Duration::MIN;
+}
+
+/// Verifies the sign predicates for zero, positive, and negative durations.
+#[test]
+fn sign_predicates() {
+    let zero = Duration::ZERO;
+    assert!(zero.is_zero());
+    assert!(!zero.is_positive());
+    assert!(!zero.is_negative());
+    assert!(!zero.is_strictly_positive());
+    assert!(!zero.is_strictly_negative());
+    assert!(zero.is_non_negative());
+    assert!(zero.is_non_positive());
+
+    let positive = Duration::ATTOSECOND;
+    assert!(!positive.is_zero());
+    assert!(positive.is_positive());
+    assert!(!positive.is_negative());
+    assert!(positive.is_strictly_positive());
+    assert!(!positive.is_strictly_negative());
+    assert!(positive.is_non_negative());
+    assert!(!positive.is_non_positive());
+
+    let negative = -Duration::SECOND;
+    assert!(!negative.is_zero());
+    assert!(!negative.is_positive());
+    assert!(negative.is_negative());
+    assert!(!negative.is_strictly_positive());
+    assert!(negative.is_strictly_negative());
+    assert!(!negative.is_non_negative());
+    assert!(negative.is_non_positive());
+}
+
+/// Verifies that the default duration is zero.
+#[test]
+fn default() {
+    assert_eq!(Duration::default(), Duration::ZERO);
+}
+
+/// Verifies that durations compare equal within, and only within, the given tolerance.
+#[test]
+fn approx_eq() {
+    let atto = Duration::ATTOSECOND;
+    let second = Duration::SECOND;
+    assert!(second.approx_eq(second, Duration::ZERO));
+    assert!(second.approx_eq(second + atto, atto));
+    assert!(second.approx_eq(second - atto, atto));
+    assert!(!second.approx_eq(second + atto, Duration::ZERO));
+    assert!(!second.approx_eq(second, -atto));
+    assert!(Duration::MIN.approx_eq(-atto, Duration::MAX));
+    assert!(!Duration::MIN.approx_eq(Duration::ZERO, Duration::MAX));
+    assert!(!Duration::MIN.approx_eq(Duration::MAX, Duration::MAX));
+}
+
+/// Verifies that the absolute difference between two durations is computed correctly, including
+/// at the boundaries of the representable range.
+#[test]
+fn abs_diff() {
+    let a = Duration::seconds(5);
+    let b = Duration::seconds(-3);
+    assert_eq!(a.abs_diff(b), Duration::seconds(8));
+    assert_eq!(b.abs_diff(a), Duration::seconds(8));
+    assert_eq!(a.abs_diff(a), Duration::ZERO);
+
+    let min = Duration::min_value();
+    let max = Duration::max_value();
+    assert_eq!(min.abs_diff(Duration::ZERO), max);
+    assert_eq!(min.abs_diff(max), max);
+    assert_eq!(max.abs_diff(min), max);
+    assert_eq!(
+        min.abs_diff(min + Duration::attoseconds(1)),
+        Duration::attoseconds(1)
+    );
+}
+
+/// Verifies the sign-related helpers for negative, zero, and positive durations.
+#[test]
+fn sign() {
+    let negative = Duration::seconds(-3);
+    let positive = Duration::milliseconds(2);
+    assert_eq!(negative.sign(), -1);
+    assert_eq!(Duration::ZERO.sign(), 0);
+    assert_eq!(positive.sign(), 1);
+    assert_eq!(negative.signum(), Duration::attoseconds(-1));
+    assert_eq!(Duration::ZERO.signum(), Duration::ZERO);
+    assert_eq!(positive.signum(), Duration::attoseconds(1));
+
+    assert_eq!(positive.positive_diff(&negative), positive - negative);
+    assert_eq!(negative.positive_diff(&positive), Duration::ZERO);
+    assert_eq!(Duration::MIN.positive_diff(&Duration::MAX), Duration::ZERO);
+}
+
+/// Verifies that a positive difference that is not representable panics instead of wrapping.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn positive_diff_overflow() {
+    let _ = Duration::MAX.positive_diff(&Duration::MIN);
+}
+
+/// Verifies that clamping restricts durations to the given range, and that `min` and `max` select
+/// the expected value.
+#[test]
+fn clamp() {
+    const CLAMPED: Duration = Duration::days(2).clamp(Duration::ZERO, Duration::days(1));
+    assert_eq!(CLAMPED, Duration::days(1));
+
+    let lo = Duration::seconds(-5);
+    let hi = Duration::minutes(1);
+    assert_eq!(Duration::seconds(10).clamp(lo, hi), Duration::seconds(10));
+    assert_eq!(Duration::seconds(-10).clamp(lo, hi), lo);
+    assert_eq!(Duration::hours(1).clamp(lo, hi), hi);
+    assert_eq!(lo.clamp(lo, hi), lo);
+    assert_eq!(hi.clamp(lo, hi), hi);
+    assert_eq!(Duration::max_value().clamp(lo, lo), lo);
+
+    assert_eq!(lo.min(hi), lo);
+    assert_eq!(hi.min(lo), lo);
+    assert_eq!(lo.max(hi), hi);
+    assert_eq!(hi.max(lo), hi);
+}
+
+/// Verifies that clamping with a lower bound exceeding the upper bound panics.
+#[test]
+#[should_panic(expected = "lower bound exceeds upper bound")]
+fn clamp_inverted_bounds() {
+    let _ = Duration::ZERO.clamp(Duration::seconds(1), Duration::seconds(-1));
+}
+
+/// Verifies the decomposition of durations into components, which all share the sign of the
+/// duration.
+#[test]
+fn components() {
+    use core::str::FromStr;
+    let components = Duration::from_str("-PT25H").unwrap().components();
+    assert_eq!(
+        components,
+        DurationComponents {
+            days: -1,
+            hours: -1,
+            minutes: 0,
+            seconds: 0,
+            subseconds: Duration::ZERO,
+        }
+    );
+
+    let duration = Duration::days(2)
+        + Duration::hours(3)
+        + Duration::minutes(4)
+        + Duration::seconds(5)
+        + Duration::milliseconds(6);
+    let expected = DurationComponents {
+        days: 2,
+        hours: 3,
+        minutes: 4,
+        seconds: 5,
+        subseconds: Duration::milliseconds(6),
+    };
+    assert_eq!(duration.components(), expected);
+    assert_eq!(
+        (-duration).components(),
+        DurationComponents {
+            days: -2,
+            hours: -3,
+            minutes: -4,
+            seconds: -5,
+            subseconds: Duration::milliseconds(-6),
+        }
+    );
+    assert_eq!(
+        Duration::ZERO.components(),
+        DurationComponents {
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            subseconds: Duration::ZERO,
+        }
+    );
+}
+
+/// Verifies that durations constructed from a generic unit match those from the named
+/// constructors.
+#[test]
+fn from_units() {
+    use crate::{Milli, SecondsPerDay};
+    assert_eq!(
+        Duration::from_units::<Milli>(500),
+        Duration::milliseconds(500)
+    );
+    assert_eq!(
+        Duration::from_units::<SecondsPerDay>(-3),
+        Duration::days(-3)
+    );
+    assert_eq!(Duration::from_units::<Second>(0), Duration::ZERO);
+    assert_eq!(
+        Duration::checked_from_units::<Milli>(500),
+        Some(Duration::milliseconds(500))
+    );
+    assert_eq!(
+        Duration::checked_from_units::<SecondsPerDay>(i128::MAX),
+        None
+    );
+}
+
+/// Verifies that durations are correctly identified as whole multiples of a unit.
+#[test]
+fn whole_units() {
+    assert!(Duration::milliseconds(1000).is_whole_seconds());
+    assert!(!Duration::milliseconds(1500).is_whole_seconds());
+    assert!(Duration::milliseconds(-3000).is_whole_seconds());
+    assert!(Duration::ZERO.is_whole_days());
+    assert!(Duration::hours(48).is_whole_days());
+    assert!(!Duration::hours(36).is_whole_days());
+    assert!(Duration::hours(-36).is_whole::<SecondsPerHour>());
+    assert!(Duration::microseconds(5).is_whole::<Nano>());
+    assert!(!Duration::nanoseconds(5).is_whole::<Micro>());
+    assert!(Duration::attoseconds(1).is_whole::<crate::Atto>());
+}
+
+/// Verifies rounding to a given number of fractional digits of a second.
+#[test]
+fn round_to_decimals() {
+    let duration = Duration::seconds(1) + Duration::picoseconds(234_567_890_100);
+    assert_eq!(duration.round_to_decimals(3), Duration::milliseconds(1_235));
+    assert_eq!(duration.round_to_decimals(0), Duration::seconds(1));
+    assert_eq!(
+        duration.round_to_decimals(10),
+        Duration::seconds(1) + Duration::picoseconds(234_567_890_100)
+    );
+    assert_eq!(duration.round_to_decimals(18), duration);
+    assert_eq!(duration.round_to_decimals(u32::MAX), duration);
+
+    // Ties are rounded towards positive infinity.
+    assert_eq!(
+        Duration::milliseconds(1_500).round_to_decimals(0),
+        Duration::seconds(2)
+    );
+    assert_eq!(
+        Duration::milliseconds(-1_500).round_to_decimals(0),
+        Duration::seconds(-1)
+    );
+    assert_eq!(
+        Duration::milliseconds(-1_234).round_to_decimals(1),
+        Duration::milliseconds(-1_200)
+    );
+}
+
+/// Verifies the rounding mode of each division variant, and that division by zero is caught.
+#[test]
+fn division_rounding() {
+    let third = Duration::attoseconds(333_333_333_333_333_333);
+    let atto = Duration::attoseconds(1);
+    let second = Duration::seconds(1);
+    assert_eq!(second.checked_div(3), Some(third));
+    assert_eq!(second.checked_div(0), None);
+    assert_eq!(Duration::min_value().checked_div(-1), None);
+    assert_eq!(second.div_floor(3), third);
+    assert_eq!(second.div_ceil(3), third + atto);
+    assert_eq!(second.div_euclid(3), third);
+
+    assert_eq!((-second).checked_div(3), Some(-third));
+    assert_eq!((-second).div_floor(3), -third - atto);
+    assert_eq!((-second).div_ceil(3), -third);
+    assert_eq!((-second).div_euclid(3), -third - atto);
+    assert_eq!(second.div_euclid(-3), -third);
+    assert_eq!((-second).div_euclid(-3), third + atto);
+}
+
+/// Verifies that Euclidean division by a `Duration` always results in a non-negative remainder.
+#[test]
+fn euclidean_duration_division() {
+    let minute = Duration::minutes(1);
+    assert_eq!(
+        Duration::seconds(-5).rem_euclid(minute),
+        Duration::seconds(55)
+    );
+    assert_eq!(Duration::seconds(-5).div_duration_euclid(minute), -1);
+    assert_eq!(
+        Duration::seconds(65).rem_euclid(minute),
+        Duration::seconds(5)
+    );
+    assert_eq!(Duration::seconds(65).div_duration_euclid(minute), 1);
+    assert_eq!(Duration::seconds(-60).rem_euclid(minute), Duration::ZERO);
+    assert_eq!(Duration::seconds(-60).div_duration_euclid(minute), -1);
+
+    // Only the magnitude of the divisor affects the remainder.
+    assert_eq!(
+        Duration::seconds(-5).rem_euclid(-minute),
+        Duration::seconds(55)
+    );
+    assert_eq!(Duration::seconds(-5).div_duration_euclid(-minute), 1);
+
+    for seconds in -130..130 {
+        let duration = Duration::seconds(seconds);
+        let quotient = duration.div_duration_euclid(minute);
+        let remainder = duration.rem_euclid(minute);
+        assert!(remainder.is_non_negative() && remainder < minute);
+        assert_eq!(minute * quotient + remainder, duration);
+    }
+}
+
+/// Verifies the greatest common divisor and least common multiple of durations.
+#[test]
+fn gcd_and_lcm() {
+    let seconds = Duration::seconds;
+    assert_eq!(seconds(6).gcd(seconds(9)), seconds(3));
+    assert_eq!(seconds(-6).gcd(seconds(9)), seconds(3));
+    assert_eq!(
+        seconds(1).gcd(Duration::milliseconds(1_500)),
+        Duration::milliseconds(500)
+    );
+    assert_eq!(seconds(1).gcd(Duration::ATTOSECOND), Duration::ATTOSECOND);
+    assert_eq!(seconds(6).gcd(Duration::ZERO), seconds(6));
+
+    assert_eq!(seconds(6).lcm(seconds(9)), Some(seconds(18)));
+    assert_eq!(seconds(-6).lcm(seconds(9)), Some(seconds(18)));
+    assert_eq!(
+        Duration::milliseconds(250).lcm(Duration::milliseconds(400)),
+        Some(seconds(2))
+    );
+    assert_eq!(seconds(6).lcm(Duration::ZERO), Some(Duration::ZERO));
+    assert_eq!(
+        Duration::MAX.lcm(Duration::MAX - Duration::ATTOSECOND),
+        None
+    );
+    assert_eq!(Duration::MIN.lcm(Duration::MIN), None);
+}
+
+/// Verifies the fallible float constructors, including non-finite and out-of-range input.
+#[test]
+fn from_f64() {
+    assert_eq!(
+        Duration::from_seconds_f64(1.5),
+        Ok(Duration::milliseconds(1_500))
+    );
+    assert_eq!(
+        Duration::from_minutes_f64(-2.5),
+        Ok(Duration::seconds(-150))
+    );
+    assert_eq!(Duration::from_days_f64(0.25), Ok(Duration::hours(6)));
+    assert_eq!(
+        Duration::from_seconds_f64(f64::NAN),
+        Err(FloatDurationError::NonFinite)
+    );
+    assert_eq!(
+        Duration::from_days_f64(f64::NEG_INFINITY),
+        Err(FloatDurationError::NonFinite)
+    );
+    assert_eq!(
+        Duration::from_seconds_f64(1e30),
+        Err(FloatDurationError::OutOfRange)
+    );
+    assert_eq!(
+        Duration::from_days_f64(-1e20),
+        Err(FloatDurationError::OutOfRange)
+    );
+}
+
+/// Verifies that the associated constants are usable in const contexts and match their
+/// constructors.
+#[test]
+fn associated_constants() {
+    const TIMEOUT: Duration = Duration::SECOND.checked_mul(30).unwrap();
+    const fn classify(duration: Duration) -> &'static str {
+        match duration {
+            Duration::ZERO => "zero",
+            Duration::SECOND => "second",
+            Duration::DAY => "day",
+            _ => "other",
+        }
+    }
+    assert_eq!(TIMEOUT, Duration::seconds(30));
+    assert_eq!(classify(Duration::seconds(1)), "second");
+    assert_eq!(classify(Duration::hours(24)), "day");
+    assert_eq!(classify(Duration::attoseconds(0)), "zero");
+    assert_eq!(classify(Duration::MINUTE), "other");
+
+    assert_eq!(Duration::ATTOSECOND, Duration::attoseconds(1));
+    assert_eq!(Duration::NANOSECOND, Duration::nanoseconds(1));
+    assert_eq!(Duration::MICROSECOND, Duration::microseconds(1));
+    assert_eq!(Duration::MILLISECOND, Duration::milliseconds(1));
+    assert_eq!(Duration::MINUTE, Duration::minutes(1));
+    assert_eq!(Duration::HOUR, Duration::hours(1));
+    assert_eq!(Duration::WEEK, Duration::weeks(1));
+    assert_eq!(Duration::MAX, Duration::max_value());
+    assert_eq!(Duration::MIN, Duration::min_value());
+    assert_eq!(<Duration as ConstZero>::ZERO, Duration::ZERO);
 }
@@ -6,7 +6,7 @@
 
 use thiserror::Error;
 
-use crate::{Date, DurationDesignator, HistoricDate, Month};
+use crate::{Date, Duration, DurationDesignator, HistoricDate, Month};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("{day} {month} {year} does not exist in the historic calendar")]
@@ -48,6 +48,20 @@ pub struct InvalidDayOfYearCount {
     pub year: i32,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("{year}-W{week:02}-{week_day} is not a valid ISO week date")]
+pub struct InvalidIsoWeek {
+    pub year: i32,
+    pub week: u8,
+    pub week_day: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("{days} days cannot be represented as `Days`")]
+pub struct DaysOverflow {
+    pub days: i128,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid month number {month}")]
 pub struct InvalidMonthNumber {
@@ -68,6 +82,50 @@ pub struct InvalidTimeOfDay {
     pub second: u8,
 }
 
+/// Shared interface of the errors returned when constructing time points from date-times
+///
+/// Each time scale reports invalid date-times using its own error type, so that scale-specific
+/// details may be retained. This trait exposes the classification that generic code needs, for
+/// example to skip civil times that do not exist in some time scale.
+pub trait DateTimeError: core::error::Error {
+    /// Whether the date-time is well-formed, but does not occur in the time scale: for example, a
+    /// 60th second on a date without leap second, or a second removed by a negative leap second.
+    /// Malformed input, like an hour of 24, is not considered nonexistent.
+    fn is_nonexistent(&self) -> bool;
+
+    /// Whether the date-time occurs more than once in the time scale, such that it does not
+    /// identify a unique instant. None of the built-in time scales has ambiguous date-times, so
+    /// the default implementation returns `false`.
+    fn is_ambiguous(&self) -> bool {
+        false
+    }
+}
+
+impl DateTimeError for InvalidTimeOfDay {
+    /// A 60th second is well-formed, but only exists in time scales with leap seconds.
+    fn is_nonexistent(&self) -> bool {
+        self.hour < 24 && self.minute < 60 && self.second == 60
+    }
+}
+
+impl DateTimeError for InvalidUtcDateTime {
+    fn is_nonexistent(&self) -> bool {
+        match self {
+            Self::InvalidTimeOfDay(error) => error.is_nonexistent(),
+            Self::NonLeapSecondDateTime { .. } | Self::DeletedSecond { .. } => true,
+        }
+    }
+}
+
+impl DateTimeError for InvalidGlonassDateTime {
+    fn is_nonexistent(&self) -> bool {
+        match self {
+            Self::InvalidTimeOfDay(error) => error.is_nonexistent(),
+            Self::NonLeapSecondDateTime { .. } | Self::DeletedSecond { .. } => true,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid historic date-time")]
 pub enum InvalidHistoricDateTime<InvalidDateTime> {
@@ -76,6 +134,24 @@ pub enum InvalidHistoricDateTime<InvalidDateTime> {
     InvalidDateTime(#[source] InvalidDateTime),
 }
 
+impl<InvalidDateTime: DateTimeError + 'static> DateTimeError
+    for InvalidHistoricDateTime<InvalidDateTime>
+{
+    fn is_nonexistent(&self) -> bool {
+        match self {
+            Self::InvalidHistoricDate(_) => false,
+            Self::InvalidDateTime(error) => error.is_nonexistent(),
+        }
+    }
+
+    fn is_ambiguous(&self) -> bool {
+        match self {
+            Self::InvalidHistoricDate(_) => false,
+            Self::InvalidDateTime(error) => error.is_ambiguous(),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid Gregorian date-time")]
 pub enum InvalidGregorianDateTime<InvalidDateTime> {
@@ -84,6 +160,24 @@ pub enum InvalidGregorianDateTime<InvalidDateTime> {
     InvalidDateTime(#[source] InvalidDateTime),
 }
 
+impl<InvalidDateTime: DateTimeError + 'static> DateTimeError
+    for InvalidGregorianDateTime<InvalidDateTime>
+{
+    fn is_nonexistent(&self) -> bool {
+        match self {
+            Self::InvalidGregorianDate(_) => false,
+            Self::InvalidDateTime(error) => error.is_nonexistent(),
+        }
+    }
+
+    fn is_ambiguous(&self) -> bool {
+        match self {
+            Self::InvalidGregorianDate(_) => false,
+            Self::InvalidDateTime(error) => error.is_ambiguous(),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid Julian date-time")]
 pub enum InvalidJulianDateTime<InvalidDateTime> {
@@ -92,6 +186,24 @@ pub enum InvalidJulianDateTime<InvalidDateTime> {
     InvalidDateTime(#[source] InvalidDateTime),
 }
 
+impl<InvalidDateTime: DateTimeError + 'static> DateTimeError
+    for InvalidJulianDateTime<InvalidDateTime>
+{
+    fn is_nonexistent(&self) -> bool {
+        match self {
+            Self::InvalidJulianDate(_) => false,
+            Self::InvalidDateTime(error) => error.is_nonexistent(),
+        }
+    }
+
+    fn is_ambiguous(&self) -> bool {
+        match self {
+            Self::InvalidJulianDate(_) => false,
+            Self::InvalidDateTime(error) => error.is_ambiguous(),
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 pub enum InvalidUtcDateTime {
     #[error("invalid time-of-day")]
@@ -103,6 +215,13 @@ pub enum InvalidUtcDateTime {
         minute: u8,
         second: u8,
     },
+    #[error("UTC date-time was removed by a negative leap second: {}T{hour:02}-{minute:02}-{second:02}", <Date as Into<HistoricDate>>::into(*date))]
+    DeletedSecond {
+        date: Date,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
@@ -116,6 +235,13 @@ pub enum InvalidGlonassDateTime {
         minute: u8,
         second: u8,
     },
+    #[error("GLONASST date-time was removed by a negative leap second: {}T{hour:02}-{minute:02}-{second:02}", <Date as Into<HistoricDate>>::into(*date))]
+    DeletedSecond {
+        date: Date,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
@@ -139,6 +265,32 @@ pub enum HistoricDateParsingError {
     UnexpectedRemainder,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing modified Julian date")]
+pub enum ModifiedJulianDateParsingError {
+    #[error(transparent)]
+    IntegerParsingError(#[from] lexical_core::Error),
+    #[error("could not parse entire string: data remains after modified Julian date")]
+    UnexpectedRemainder,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing ISO week date")]
+pub enum IsoWeekDateParsingError {
+    #[error(transparent)]
+    IntegerParsingError(#[from] lexical_core::Error),
+    #[error(transparent)]
+    InvalidIsoWeek(#[from] InvalidIsoWeek),
+    #[error("expected but did not find year-week delimiter '-W'")]
+    ExpectedYearWeekDelimiter,
+    #[error("week representation must be exactly two digits")]
+    WeekRepresentationNotTwoDigits,
+    #[error("week day representation must be exactly one digit")]
+    WeekDayRepresentationNotOneDigit,
+    #[error("could not parse entire string: data remains after ISO week date")]
+    UnexpectedRemainder,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
 #[error("error parsing `GregorianDate`")]
 pub enum GregorianDateParsingError {
@@ -201,16 +353,91 @@ pub enum TimeOfDayParsingError {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
 #[error("error parsing `Duration`")]
 pub enum DurationParsingError {
+    /// A numeric component could not be parsed.
     #[error(transparent)]
     IntegerParsingError(#[from] lexical_core::Error),
+    /// The string did not start with the duration prefix 'P', after an optional sign.
     #[error("input string did not start with \'P\'")]
     ExpectedDurationPrefix,
-    #[error("expected duration designator")]
-    ExpectedDurationDesignator,
-    #[error("could not parse entire string: data remains after duration")]
+    /// A numeric component was not followed by a supported unit designator. The position is the
+    /// byte offset at which the designator was expected.
+    #[error("expected duration designator at byte {position}")]
+    ExpectedDurationDesignator { position: usize },
+    /// The string continued after what must have been its final component. The position is the
+    /// byte offset of the first unexpected character.
+    #[error("could not parse entire string: data remains after duration at byte {position}")]
+    UnexpectedRemainder { position: usize },
+    /// A unit designator was given after a designator of the same or a smaller unit. The position
+    /// is the byte offset of the offending designator.
+    #[error(
+        "unit designators must be provided in decreasing order, but found {current} at byte {position}"
+    )]
+    NonDecreasingDesignators {
+        current: DurationDesignator,
+        position: usize,
+    },
+    /// The duration is too large to be represented with attosecond precision.
+    #[error("duration cannot be represented with attosecond precision")]
+    Overflow,
+    /// The duration did not contain any components, like a bare `P` or `PT`. ISO 8601 requires
+    /// at least one component to be present: a zero duration may be written as `PT0S`.
+    #[error("duration must contain at least one component")]
+    EmptyDuration,
+    /// A component other than the seconds was fractional, which a `CalendarDuration` cannot
+    /// retain.
+    #[error("only the seconds component of a calendar duration may be fractional")]
+    FractionalCalendarComponent,
+}
+
+impl DurationParsingError {
+    /// Returns the byte offset into the input string at which parsing failed, if known. Only
+    /// errors caused by an unexpected designator or trailing data carry a position.
+    #[must_use]
+    pub const fn position(&self) -> Option<usize> {
+        match self {
+            Self::ExpectedDurationDesignator { position }
+            | Self::UnexpectedRemainder { position }
+            | Self::NonDecreasingDesignators { position, .. } => Some(*position),
+            Self::IntegerParsingError(_)
+            | Self::ExpectedDurationPrefix
+            | Self::EmptyDuration
+            | Self::Overflow
+            | Self::FractionalCalendarComponent => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("UTC offset of {offset:?} is not a whole number of minutes between -23:59 and +23:59")]
+pub struct InvalidUtcOffset {
+    pub offset: Duration,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+pub enum InvalidOffsetDateTime {
+    #[error(transparent)]
+    InvalidUtcOffset(#[from] InvalidUtcOffset),
+    #[error(transparent)]
+    InvalidUtcDateTime(#[from] InvalidUtcDateTime),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing RFC 3339 timestamp")]
+pub enum Rfc3339ParsingError {
+    #[error(transparent)]
+    DateParsingError(#[from] GregorianDateParsingError),
+    #[error(transparent)]
+    TimeOfDayParsingError(#[from] TimeOfDayParsingError),
+    #[error("expected but did not find time designator 'T'")]
+    ExpectedTimeDesignator,
+    #[error("expected but did not find UTC offset 'Z' or '+HH:MM'")]
+    ExpectedOffset,
+    #[error("UTC offset must be of the form '+HH:MM', with at most 23 hours and 59 minutes")]
+    InvalidOffset,
+    #[error("could not parse entire string: data remains after timestamp")]
     UnexpectedRemainder,
-    #[error("unit designators must be provided in decreasing error, but found {current}")]
-    NonDecreasingDesignators { current: DurationDesignator },
+    #[error(transparent)]
+    DateTimeError(#[from] InvalidUtcDateTime),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
@@ -231,3 +458,37 @@ pub enum TimePointParsingError<DateTimeError> {
 
     DateTimeError(#[source] DateTimeError),
 }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("interval end lies before its start")]
+pub struct InvalidInterval;
+
+#[cfg(feature = "chrono")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+pub enum ChronoDurationConversionError {
+    #[error("`chrono` durations cannot represent sub-nanosecond precision")]
+    SubnanosecondPrecision,
+    #[error("duration exceeds the range representable by `chrono` durations")]
+    OutOfRange,
+}
+
+#[cfg(feature = "time")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("time point lies outside the range representable by `time::OffsetDateTime`")]
+pub struct OffsetDateTimeOutOfRange;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+pub enum FormatError {
+    #[error("unknown format specifier '%{0}'")]
+    UnknownSpecifier(char),
+    #[error("format pattern ends in an incomplete specifier")]
+    IncompleteSpecifier,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+pub enum FloatDurationError {
+    #[error("float duration is not finite")]
+    NonFinite,
+    #[error("float duration cannot be represented with attosecond precision")]
+    OutOfRange,
+}
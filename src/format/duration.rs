@@ -0,0 +1,169 @@
+//! Implementation of pattern-based formatting for `Duration` types.
+
+use alloc::{format, string::String};
+
+use super::{
+    format_fraction,
+    pattern::{PatternItem, PatternItems},
+};
+use crate::{
+    Duration, Second, SecondsPerDay, SecondsPerHour, SecondsPerMinute, errors::FormatError,
+};
+
+impl Duration {
+    /// Formats this duration according to a `strftime`-like pattern. The following specifiers
+    /// are supported:
+    /// - `%D`: whole days;
+    /// - `%H`: whole hours, zero-padded to two digits;
+    /// - `%M`: whole minutes, zero-padded to two digits;
+    /// - `%S`: whole seconds, zero-padded to two digits;
+    /// - `%f`: fractional seconds, truncated to nine digits;
+    /// - `%%`: a literal percent sign.
+    ///
+    /// Zero padding is suppressed by the `-` modifier, as in `%-H`. For `%-f`, all significant
+    /// fractional digits are printed instead, down to attosecond precision.
+    ///
+    /// Units that are absent from the pattern are absorbed into the next smaller unit that is
+    /// present: for example, 90 minutes is formatted as `01:30:00` by `%H:%M:%S`, but as `90:00`
+    /// by `%M:%S`. Negative durations are formatted as their magnitude, prefixed by a minus sign.
+    ///
+    /// # Errors
+    /// Returns an error if the pattern contains an unknown or incomplete specifier.
+    pub fn format_pattern(&self, pattern: &str) -> Result<String, FormatError> {
+        let mut has_days = false;
+        let mut has_hours = false;
+        let mut has_minutes = false;
+        for item in PatternItems::new(pattern) {
+            match item? {
+                PatternItem::Specifier { specifier, .. } => match specifier {
+                    'D' => has_days = true,
+                    'H' => has_hours = true,
+                    'M' => has_minutes = true,
+                    'S' | 'f' => {}
+                    _ => return Err(FormatError::UnknownSpecifier(specifier)),
+                },
+                PatternItem::Literal(_) => {}
+            }
+        }
+
+        // Since `factor_out` truncates towards zero, all components share the sign of the
+        // duration: only their magnitudes need to be printed.
+        let mut remainder = *self;
+        let mut factor_out = |present: bool, factor: fn(Self) -> (i128, Self)| {
+            if present {
+                let (count, new_remainder) = factor(remainder);
+                remainder = new_remainder;
+                count.unsigned_abs()
+            } else {
+                0
+            }
+        };
+        let days = factor_out(has_days, Self::factor_out::<SecondsPerDay>);
+        let hours = factor_out(has_hours, Self::factor_out::<SecondsPerHour>);
+        let minutes = factor_out(has_minutes, Self::factor_out::<SecondsPerMinute>);
+        let seconds = factor_out(true, Self::factor_out::<Second>);
+        let subseconds = remainder;
+
+        let mut output = String::new();
+        if self.is_negative() {
+            output.push('-');
+        }
+        for item in PatternItems::new(pattern) {
+            let Ok(item) = item else { unreachable!() };
+            match item {
+                PatternItem::Literal(literal) => output.push_str(literal),
+                PatternItem::Specifier { specifier, padded } => {
+                    let component = match (specifier, padded) {
+                        ('D', _) => format!("{days}"),
+                        ('H', true) => format!("{hours:02}"),
+                        ('M', true) => format!("{minutes:02}"),
+                        ('S', true) => format!("{seconds:02}"),
+                        ('H', false) => format!("{hours}"),
+                        ('M', false) => format!("{minutes}"),
+                        ('S', false) => format!("{seconds}"),
+                        ('f', padded) => format_fraction(subseconds, padded),
+                        _ => unreachable!(),
+                    };
+                    output.push_str(&component);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// Verifies formatting of durations using patterns, including padding and absorption of units
+/// absent from the pattern.
+#[test]
+fn format_pattern() {
+    let duration = Duration::minutes(90);
+    assert_eq!(duration.format_pattern("%H:%M:%S").unwrap(), "01:30:00");
+    assert_eq!(duration.format_pattern("%-H:%M:%S").unwrap(), "1:30:00");
+    assert_eq!(duration.format_pattern("%M:%S").unwrap(), "90:00");
+    assert_eq!(duration.format_pattern("%S s").unwrap(), "5400 s");
+    assert_eq!(
+        duration.format_pattern("%D days, %H:%M").unwrap(),
+        "0 days, 01:30"
+    );
+
+    let duration = Duration::days(3) + Duration::hours(4) + Duration::seconds(5);
+    assert_eq!(
+        duration.format_pattern("%Dd %Hh %Mm %Ss").unwrap(),
+        "3d 04h 00m 05s"
+    );
+    assert_eq!(duration.format_pattern("%H:%M").unwrap(), "76:00");
+    assert_eq!(duration.format_pattern("100%% done").unwrap(), "100% done");
+}
+
+/// Verifies formatting of fractional seconds.
+#[test]
+fn format_pattern_fraction() {
+    let duration = Duration::milliseconds(1500);
+    assert_eq!(duration.format_pattern("%S.%f").unwrap(), "01.500000000");
+    assert_eq!(duration.format_pattern("%-S.%-f").unwrap(), "1.5");
+    assert_eq!(
+        Duration::seconds(2).format_pattern("%-S.%-f").unwrap(),
+        "2.0"
+    );
+    assert_eq!(
+        Duration::attoseconds(1).format_pattern("%-S.%-f").unwrap(),
+        "0.000000000000000001"
+    );
+    assert_eq!(
+        Duration::attoseconds(1).format_pattern("%S.%f").unwrap(),
+        "00.000000000"
+    );
+}
+
+/// Verifies that negative durations are formatted as their magnitude with a sign, and that
+/// invalid patterns are rejected.
+#[test]
+fn format_pattern_edge_cases() {
+    use num_traits::Bounded;
+    let duration = Duration::minutes(-90) - Duration::milliseconds(250);
+    assert_eq!(
+        duration.format_pattern("%H:%M:%S.%-f").unwrap(),
+        "-01:30:00.25"
+    );
+    assert_eq!(
+        Duration::min_value().format_pattern("%D").unwrap(),
+        format!("-{}", Duration::min_value().whole_days().unsigned_abs())
+    );
+
+    assert_eq!(
+        Duration::ZERO.format_pattern("%H:%Q"),
+        Err(FormatError::UnknownSpecifier('Q'))
+    );
+    assert_eq!(
+        Duration::ZERO.format_pattern("%Y"),
+        Err(FormatError::UnknownSpecifier('Y'))
+    );
+    assert_eq!(
+        Duration::ZERO.format_pattern("%H:%"),
+        Err(FormatError::IncompleteSpecifier)
+    );
+    assert_eq!(
+        Duration::ZERO.format_pattern("%-"),
+        Err(FormatError::IncompleteSpecifier)
+    );
+}
@@ -0,0 +1,168 @@
+//! Implementation of ISO 8601 duration formatting with a configurable largest unit.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::format_fraction;
+use crate::{
+    Duration, Second, SecondsPerDay, SecondsPerHour, SecondsPerMinute, SecondsPerMonth,
+    SecondsPerWeek, SecondsPerYear,
+};
+
+/// Largest unit designator used when formatting a `Duration` as ISO 8601 duration expression
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LargestUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Duration {
+    /// Formats this duration as ISO 8601 duration expression, using no designators larger than
+    /// `largest_unit`. All smaller designators are used as well, except for weeks: ISO 8601 does
+    /// not permit combining weeks with other designators, so they are only used if they are the
+    /// largest unit and the duration is a whole number of weeks. Otherwise, days are used
+    /// instead. Zero-valued components are omitted, except that a zero duration is formatted as
+    /// `PT0S`. Negative durations are prefixed with a minus sign.
+    ///
+    /// Years and months are not of fixed length, so, as elsewhere in this library, the averaged
+    /// definitions are used: a year is an average Gregorian year of 365.2425 days, and a month is
+    /// one twelfth thereof. Hence, `Duration::years(1)` is formatted as `P1Y` with
+    /// `LargestUnit::Years`, but as `P365DT5H49M12S` with `LargestUnit::Days`.
+    #[must_use]
+    #[allow(
+        clippy::missing_panics_doc,
+        reason = "Writing to a `String` is infallible"
+    )]
+    pub fn to_iso8601(&self, largest_unit: LargestUnit) -> String {
+        // Since `factor_out` truncates towards zero, all components share the sign of the
+        // duration: only their magnitudes need to be printed.
+        let mut remainder = *self;
+        let mut factor_out = |present: bool, factor: fn(Self) -> (i128, Self)| {
+            if present {
+                let (count, new_remainder) = factor(remainder);
+                remainder = new_remainder;
+                count.unsigned_abs()
+            } else {
+                0
+            }
+        };
+        let years = factor_out(
+            largest_unit >= LargestUnit::Years,
+            Self::factor_out::<SecondsPerYear>,
+        );
+        let months = factor_out(
+            largest_unit >= LargestUnit::Months,
+            Self::factor_out::<SecondsPerMonth>,
+        );
+        let whole_weeks = self.count() % Self::weeks(1).count() == 0;
+        let weeks = factor_out(
+            largest_unit == LargestUnit::Weeks && whole_weeks,
+            Self::factor_out::<SecondsPerWeek>,
+        );
+        let days = factor_out(
+            largest_unit >= LargestUnit::Days,
+            Self::factor_out::<SecondsPerDay>,
+        );
+        let hours = factor_out(
+            largest_unit >= LargestUnit::Hours,
+            Self::factor_out::<SecondsPerHour>,
+        );
+        let minutes = factor_out(
+            largest_unit >= LargestUnit::Minutes,
+            Self::factor_out::<SecondsPerMinute>,
+        );
+        let seconds = factor_out(true, Self::factor_out::<Second>);
+        let subseconds = remainder;
+
+        let mut output = String::new();
+        if self.is_negative() {
+            output.push('-');
+        }
+        output.push('P');
+        for (count, designator) in [(years, 'Y'), (months, 'M'), (weeks, 'W'), (days, 'D')] {
+            if count != 0 {
+                write!(output, "{count}{designator}").unwrap();
+            }
+        }
+
+        let has_seconds = seconds != 0 || subseconds.count() != 0;
+        if hours != 0 || minutes != 0 || has_seconds {
+            output.push('T');
+        } else if output.ends_with('P') {
+            output.push_str("T0S");
+            return output;
+        }
+        for (count, designator) in [(hours, 'H'), (minutes, 'M')] {
+            if count != 0 {
+                write!(output, "{count}{designator}").unwrap();
+            }
+        }
+        if has_seconds {
+            write!(output, "{seconds}").unwrap();
+            if subseconds.count() != 0 {
+                output.push('.');
+                output.push_str(&format_fraction(subseconds, false));
+            }
+            output.push('S');
+        }
+        output
+    }
+}
+
+/// Verifies formatting of a one-year duration using different largest units.
+#[test]
+fn largest_units() {
+    let year = Duration::years(1);
+    assert_eq!(year.to_iso8601(LargestUnit::Years), "P1Y");
+    assert_eq!(year.to_iso8601(LargestUnit::Months), "P12M");
+    assert_eq!(year.to_iso8601(LargestUnit::Weeks), "P365DT5H49M12S");
+    assert_eq!(year.to_iso8601(LargestUnit::Days), "P365DT5H49M12S");
+    assert_eq!(year.to_iso8601(LargestUnit::Hours), "PT8765H49M12S");
+    assert_eq!(year.to_iso8601(LargestUnit::Minutes), "PT525949M12S");
+    assert_eq!(year.to_iso8601(LargestUnit::Seconds), "PT31556952S");
+
+    let duration = Duration::years(2) + Duration::months(3) + Duration::milliseconds(1_500);
+    assert_eq!(duration.to_iso8601(LargestUnit::Years), "P2Y3MT1.5S");
+    assert_eq!((-duration).to_iso8601(LargestUnit::Years), "-P2Y3MT1.5S");
+    assert_eq!(Duration::weeks(2).to_iso8601(LargestUnit::Years), "P14D");
+    assert_eq!(Duration::weeks(2).to_iso8601(LargestUnit::Weeks), "P2W");
+    assert_eq!(Duration::days(-14).to_iso8601(LargestUnit::Weeks), "-P2W");
+    assert_eq!(Duration::days(15).to_iso8601(LargestUnit::Weeks), "P15D");
+    assert_eq!(Duration::ZERO.to_iso8601(LargestUnit::Years), "PT0S");
+    assert_eq!(
+        Duration::attoseconds(-1).to_iso8601(LargestUnit::Days),
+        "-PT0.000000000000000001S"
+    );
+}
+
+/// Verifies that durations formatted with any largest unit parse back to the same value.
+#[test]
+fn roundtrip() {
+    use core::str::FromStr;
+    for duration in [
+        Duration::years(1),
+        Duration::days(3) + Duration::minutes(4) + Duration::attoseconds(5),
+        Duration::hours(-36),
+        Duration::weeks(3),
+        Duration::months(-5) - Duration::weeks(1),
+        Duration::ZERO,
+    ] {
+        for largest_unit in [
+            LargestUnit::Years,
+            LargestUnit::Months,
+            LargestUnit::Weeks,
+            LargestUnit::Days,
+            LargestUnit::Hours,
+            LargestUnit::Minutes,
+            LargestUnit::Seconds,
+        ] {
+            let string = duration.to_iso8601(largest_unit);
+            assert_eq!(Duration::from_str(&string), Ok(duration), "{string}");
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Implementation of formatting functionality based on `strftime`-like patterns. Complements the
+//! fixed formats provided by the `Display` implementations of the types in this library.
+
+mod duration;
+mod iso8601;
+pub use iso8601::LargestUnit;
+mod pattern;
+mod scientific;
+mod time_point;
+
+use alloc::string::String;
+
+use crate::Duration;
+
+/// Formats the fractional digits of a sub-second duration, as used for the `%f` specifier. If
+/// padded, exactly nine digits are printed, truncating any further digits. Otherwise, all
+/// significant digits are printed, with a minimum of one.
+fn format_fraction(subseconds: Duration, padded: bool) -> String {
+    let precision = if padded { Some(9) } else { None };
+    let digits: String = subseconds
+        .decimal_digits(precision)
+        .map(|digit| char::from(b'0' + digit))
+        .collect();
+    if digits.is_empty() {
+        String::from("0")
+    } else {
+        digits
+    }
+}
@@ -0,0 +1,82 @@
+//! Tokenization of `strftime`-like formatting patterns.
+
+use crate::errors::FormatError;
+
+/// Single element of a formatting pattern.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PatternItem<'a> {
+    /// Text that must be reproduced verbatim.
+    Literal(&'a str),
+    /// Conversion specifier like `%H`, together with whether zero padding is requested. Padding
+    /// is suppressed by the `-` modifier, as in `%-H`.
+    Specifier { specifier: char, padded: bool },
+}
+
+/// Iterator over the elements of a formatting pattern. A literal percent sign may be included
+/// through `%%`. Whether specifiers are known is not verified: that is up to the consumer.
+pub struct PatternItems<'a> {
+    remainder: &'a str,
+}
+
+impl<'a> PatternItems<'a> {
+    pub const fn new(pattern: &'a str) -> Self {
+        Self { remainder: pattern }
+    }
+}
+
+impl<'a> Iterator for PatternItems<'a> {
+    type Item = Result<PatternItem<'a>, FormatError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_empty() {
+            return None;
+        }
+
+        let Some(specifier) = self.remainder.strip_prefix('%') else {
+            let literal_length = self.remainder.find('%').unwrap_or(self.remainder.len());
+            let (literal, remainder) = self.remainder.split_at(literal_length);
+            self.remainder = remainder;
+            return Some(Ok(PatternItem::Literal(literal)));
+        };
+
+        let padded = !specifier.starts_with('-');
+        let specifier = specifier.strip_prefix('-').unwrap_or(specifier);
+        let mut characters = specifier.chars();
+        let item = match characters.next() {
+            Some('%') if padded => Ok(PatternItem::Literal("%")),
+            Some(specifier) => Ok(PatternItem::Specifier { specifier, padded }),
+            None => Err(FormatError::IncompleteSpecifier),
+        };
+        self.remainder = characters.as_str();
+        Some(item)
+    }
+}
+
+/// Verifies tokenization of patterns into literals and specifiers.
+#[cfg(feature = "std")]
+#[test]
+fn pattern_items() {
+    let items: Result<Vec<_>, _> = PatternItems::new("%H:%-M %% 100%").collect();
+    assert_eq!(items, Err(FormatError::IncompleteSpecifier));
+
+    let mut items = PatternItems::new("%H:%-M %%done");
+    assert_eq!(
+        items.next(),
+        Some(Ok(PatternItem::Specifier {
+            specifier: 'H',
+            padded: true
+        }))
+    );
+    assert_eq!(items.next(), Some(Ok(PatternItem::Literal(":"))));
+    assert_eq!(
+        items.next(),
+        Some(Ok(PatternItem::Specifier {
+            specifier: 'M',
+            padded: false
+        }))
+    );
+    assert_eq!(items.next(), Some(Ok(PatternItem::Literal(" "))));
+    assert_eq!(items.next(), Some(Ok(PatternItem::Literal("%"))));
+    assert_eq!(items.next(), Some(Ok(PatternItem::Literal("done"))));
+    assert_eq!(items.next(), None);
+}
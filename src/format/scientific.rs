@@ -0,0 +1,41 @@
+//! Implementation of scientific notation formatting for `Duration`s.
+
+use alloc::{format, string::String};
+
+use crate::{Duration, Second};
+
+impl Duration {
+    /// Formats this duration as a number of seconds in scientific notation, like `1.5e-9 s`. The
+    /// exponent is chosen such that the mantissa lies in `[1, 10)`, and the mantissa is printed
+    /// with the least number of digits that uniquely identifies the underlying `f64` value. Zero is
+    /// formatted as `0 s`, and negative durations are prefixed with a minus sign.
+    ///
+    /// Since this representation is based on the `f64` approximation returned by `as_float`, it
+    /// is not exact for durations that require more than about 16 significant digits.
+    #[must_use]
+    pub fn to_scientific_seconds(&self) -> String {
+        if *self == Self::ZERO {
+            return String::from("0 s");
+        }
+        let seconds: f64 = self.as_float::<f64, Second>();
+        format!("{seconds:e} s")
+    }
+}
+
+/// Verifies the scientific formatting of some durations that span the range of magnitudes.
+#[test]
+fn scientific_seconds() {
+    assert_eq!(Duration::nanoseconds(1).to_scientific_seconds(), "1e-9 s");
+    assert_eq!(Duration::attoseconds(1).to_scientific_seconds(), "1e-18 s");
+    assert_eq!(
+        Duration::picoseconds(1_500).to_scientific_seconds(),
+        "1.5e-9 s"
+    );
+    assert_eq!(
+        Duration::attoseconds(-25).to_scientific_seconds(),
+        "-2.5e-17 s"
+    );
+    assert_eq!(Duration::seconds(1).to_scientific_seconds(), "1e0 s");
+    assert_eq!(Duration::hours(1).to_scientific_seconds(), "3.6e3 s");
+    assert_eq!(Duration::ZERO.to_scientific_seconds(), "0 s");
+}
@@ -0,0 +1,163 @@
+//! Implementation of pattern-based formatting for `TimePoint` types.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use super::{
+    format_fraction,
+    pattern::{PatternItem, PatternItems},
+};
+use crate::{IntoFineDateTime, TimePoint, errors::FormatError, time_scale::TimeScale};
+
+impl<Scale> TimePoint<Scale>
+where
+    Scale: ?Sized + TimeScale,
+    Self: IntoFineDateTime,
+{
+    /// Formats this time point according to a `strftime`-like pattern, based on its date-time in
+    /// the historic calendar. The following specifiers are supported:
+    /// - `%Y`: year, zero-padded to four digits;
+    /// - `%m`: month number, zero-padded to two digits;
+    /// - `%d`: day of the month, zero-padded to two digits;
+    /// - `%H`: hour, zero-padded to two digits;
+    /// - `%M`: minute, zero-padded to two digits;
+    /// - `%S`: second, zero-padded to two digits;
+    /// - `%f`: fractional seconds, truncated to nine digits;
+    /// - `%Z`: abbreviation of the time scale;
+    /// - `%%`: a literal percent sign.
+    ///
+    /// Zero padding is suppressed by the `-` modifier, as in `%-d`. For `%-f`, all significant
+    /// fractional digits are printed instead, down to attosecond precision. During a leap second,
+    /// `%S` renders as `60`.
+    ///
+    /// # Errors
+    /// Returns an error if the pattern contains an unknown or incomplete specifier.
+    pub fn format(&self, pattern: &str) -> Result<String, FormatError> {
+        let (date, hour, minute, second, subseconds) = self.into_fine_historic_datetime();
+        let mut output = String::new();
+        for item in PatternItems::new(pattern) {
+            match item? {
+                PatternItem::Literal(literal) => output.push_str(literal),
+                PatternItem::Specifier { specifier, padded } => {
+                    let component = match (specifier, padded) {
+                        ('Y', true) => format!("{:04}", date.year()),
+                        ('m', true) => format!("{:02}", date.month() as u8),
+                        ('d', true) => format!("{:02}", date.day()),
+                        ('H', true) => format!("{hour:02}"),
+                        ('M', true) => format!("{minute:02}"),
+                        ('S', true) => format!("{second:02}"),
+                        ('Y', false) => format!("{}", date.year()),
+                        ('m', false) => format!("{}", date.month() as u8),
+                        ('d', false) => format!("{}", date.day()),
+                        ('H', false) => format!("{hour}"),
+                        ('M', false) => format!("{minute}"),
+                        ('S', false) => format!("{second}"),
+                        ('f', padded) => format_fraction(subseconds, padded),
+                        ('Z', _) => String::from(Scale::ABBREVIATION),
+                        _ => return Err(FormatError::UnknownSpecifier(specifier)),
+                    };
+                    output.push_str(&component);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+impl<Scale> TimePoint<Scale>
+where
+    Scale: ?Sized,
+    Self: IntoFineDateTime,
+{
+    /// Renders this time point as `YYYY-MM-DDThh:mm:ss.fff`, like its `Display` implementation,
+    /// but without the trailing time scale abbreviation.
+    #[must_use]
+    pub fn to_naive_string(&self) -> String {
+        self.display_naive().to_string()
+    }
+}
+
+/// Verifies formatting of time points using patterns.
+#[test]
+fn format_pattern() {
+    use crate::{Duration, Month, TaiTime};
+    let time = TaiTime::from_fine_historic_datetime(
+        2024,
+        Month::January,
+        2,
+        3,
+        4,
+        5,
+        Duration::milliseconds(60),
+    )
+    .unwrap();
+    assert_eq!(time.format("%Y-%m-%d").unwrap(), "2024-01-02");
+    assert_eq!(time.format("%-d/%-m/%Y").unwrap(), "2/1/2024");
+    assert_eq!(
+        time.format("%Y-%m-%dT%H:%M:%S.%f %Z").unwrap(),
+        "2024-01-02T03:04:05.060000000 TAI"
+    );
+    assert_eq!(time.format("%-H:%-M:%-S.%-f").unwrap(), "3:4:5.06");
+    assert_eq!(time.format("100%% %Z").unwrap(), "100% TAI");
+
+    assert_eq!(
+        time.format("%Y %q"),
+        Err(FormatError::UnknownSpecifier('q'))
+    );
+    assert_eq!(time.format("%Y %"), Err(FormatError::IncompleteSpecifier));
+}
+
+/// Verifies that leap seconds are rendered as second 60.
+#[test]
+fn format_leap_second() {
+    use crate::{Duration, Month, UtcTime};
+    let leap_second = UtcTime::from_fine_historic_datetime(
+        2016,
+        Month::December,
+        31,
+        23,
+        59,
+        60,
+        Duration::milliseconds(500),
+    )
+    .unwrap();
+    assert_eq!(
+        leap_second.format("%Y-%m-%d %H:%M:%S.%-f %Z").unwrap(),
+        "2016-12-31 23:59:60.5 UTC"
+    );
+    assert_eq!(
+        (leap_second + Duration::milliseconds(500))
+            .format("%H:%M:%S")
+            .unwrap(),
+        "00:00:00"
+    );
+}
+
+/// Verifies that naive rendering equals the tagged rendering without the time scale abbreviation.
+#[test]
+fn naive_string() {
+    use crate::{Duration, GpsTime, Month, UtcTime};
+    let time = GpsTime::from_fine_historic_datetime(
+        2024,
+        Month::January,
+        2,
+        3,
+        4,
+        5,
+        Duration::milliseconds(60),
+    )
+    .unwrap();
+    assert_eq!(time.to_string(), "2024-01-02T03:04:05.06 GPST");
+    assert_eq!(time.to_naive_string(), "2024-01-02T03:04:05.06");
+    assert_eq!(
+        format!("{:.3}", time.display_naive()),
+        "2024-01-02T03:04:05.060"
+    );
+    assert_eq!(format!("{time:.1}"), "2024-01-02T03:04:05.0 GPST");
+
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert_eq!(leap_second.to_naive_string(), "2016-12-31T23:59:60");
+}
@@ -0,0 +1,79 @@
+//! Conversions between `Duration` and the `chrono` crate's `TimeDelta` (also known as
+//! `chrono::Duration`).
+
+use ::chrono::TimeDelta;
+
+use crate::{Duration, Nano, UnitRatio, errors::ChronoDurationConversionError};
+
+impl From<TimeDelta> for Duration {
+    /// Converts a `chrono` duration into a `Duration`. This conversion is always exact, since
+    /// `chrono` durations have nanosecond resolution and a range of about 292 million years.
+    fn from(value: TimeDelta) -> Self {
+        Self::seconds(value.num_seconds().into()) + Self::nanoseconds(value.subsec_nanos().into())
+    }
+}
+
+impl TryFrom<Duration> for TimeDelta {
+    type Error = ChronoDurationConversionError;
+
+    /// Converts a `Duration` into a `chrono` duration. Since `chrono` durations have only
+    /// nanosecond resolution and a much narrower range, this conversion fails rather than losing
+    /// precision or overflowing.
+    fn try_from(value: Duration) -> Result<Self, Self::Error> {
+        let attoseconds = value.count();
+        if attoseconds % Nano::ATTOSECONDS != 0 {
+            return Err(ChronoDurationConversionError::SubnanosecondPrecision);
+        }
+        let nanoseconds = attoseconds / Nano::ATTOSECONDS;
+        let seconds = i64::try_from(nanoseconds.div_euclid(1_000_000_000))
+            .map_err(|_| ChronoDurationConversionError::OutOfRange)?;
+        let Ok(subsec_nanoseconds) = u32::try_from(nanoseconds.rem_euclid(1_000_000_000)) else {
+            unreachable!()
+        };
+        Self::new(seconds, subsec_nanoseconds).ok_or(ChronoDurationConversionError::OutOfRange)
+    }
+}
+
+/// Verifies that durations round-trip through `chrono` durations, including negative durations.
+#[test]
+fn chrono_duration_roundtrip() {
+    let duration = Duration::milliseconds(1500);
+    let delta = TimeDelta::try_from(duration).unwrap();
+    assert_eq!(delta, TimeDelta::milliseconds(1500));
+    assert_eq!(Duration::from(delta), duration);
+
+    let duration = Duration::nanoseconds(-1_500_000_001);
+    let delta = TimeDelta::try_from(duration).unwrap();
+    assert_eq!(delta, TimeDelta::nanoseconds(-1_500_000_001));
+    assert_eq!(Duration::from(delta), duration);
+
+    assert_eq!(
+        Duration::from(TimeDelta::MAX),
+        Duration::milliseconds(i64::MAX.into())
+    );
+    assert_eq!(
+        TimeDelta::try_from(Duration::from(TimeDelta::MIN)),
+        Ok(TimeDelta::MIN)
+    );
+}
+
+/// Verifies that durations that cannot be represented by `chrono` are rejected.
+#[test]
+fn chrono_duration_rejection() {
+    assert_eq!(
+        TimeDelta::try_from(Duration::attoseconds(1)),
+        Err(ChronoDurationConversionError::SubnanosecondPrecision)
+    );
+    assert_eq!(
+        TimeDelta::try_from(Duration::seconds(1) + Duration::picoseconds(1)),
+        Err(ChronoDurationConversionError::SubnanosecondPrecision)
+    );
+    assert_eq!(
+        TimeDelta::try_from(Duration::from(TimeDelta::MAX) + Duration::nanoseconds(1)),
+        Err(ChronoDurationConversionError::OutOfRange)
+    );
+    assert_eq!(
+        TimeDelta::try_from(Duration::years(1_000_000_000)),
+        Err(ChronoDurationConversionError::OutOfRange)
+    );
+}
@@ -0,0 +1,15 @@
+//! Conversions between the types of this library and those of other time libraries, as well as
+//! integration with other widely used crates like `rand` and `proptest`. Each is gated behind an
+//! optional feature named after the corresponding crate, such that no additional dependencies are
+//! pulled in unless explicitly requested.
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rand")]
+pub use self::rand::{UniformDuration, UniformTimePoint};
+#[cfg(feature = "time")]
+mod time;
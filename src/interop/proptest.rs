@@ -0,0 +1,85 @@
+//! Implementations of `proptest::arbitrary::Arbitrary`, such that `Duration`s, `Days`, `Date`s,
+//! and `TimePoint`s may be generated in property-based tests.
+//!
+//! Generated values are bounded to within ten thousand years of their epoch, rather than spanning
+//! the full representable range. This way, they may be freely combined using arithmetic or
+//! converted into calendar dates without overflowing.
+
+use ::proptest::{
+    arbitrary::Arbitrary,
+    strategy::{Map, Strategy},
+};
+use core::ops::RangeInclusive;
+
+use crate::{Date, Days, Duration, TimePoint};
+
+/// Bound on the magnitude of generated durations, in attoseconds: ten thousand average Gregorian
+/// years.
+const MAX_ATTOSECONDS: i128 = Duration::years(10_000).count();
+
+/// Bound on the magnitude of generated day counts: ten thousand average Gregorian years.
+const MAX_DAYS: i32 = 3_652_425;
+
+impl Arbitrary for Duration {
+    type Parameters = ();
+    type Strategy = Map<RangeInclusive<i128>, fn(i128) -> Self>;
+
+    /// Generates durations of at most ten thousand years in magnitude, with attosecond resolution.
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (-MAX_ATTOSECONDS..=MAX_ATTOSECONDS).prop_map(Self::attoseconds)
+    }
+}
+
+impl Arbitrary for Days {
+    type Parameters = ();
+    type Strategy = Map<RangeInclusive<i32>, fn(i32) -> Self>;
+
+    /// Generates day counts of at most ten thousand years in magnitude.
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (-MAX_DAYS..=MAX_DAYS).prop_map(Self::new)
+    }
+}
+
+impl Arbitrary for Date {
+    type Parameters = ();
+    type Strategy = Map<<Days as Arbitrary>::Strategy, fn(Days) -> Self>;
+
+    /// Generates dates within ten thousand years of 1970-01-01.
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        Days::arbitrary().prop_map(Self::from_time_since_epoch)
+    }
+}
+
+impl<Scale> Arbitrary for TimePoint<Scale>
+where
+    Scale: ?Sized + 'static,
+{
+    type Parameters = ();
+    type Strategy = Map<<Duration as Arbitrary>::Strategy, fn(Duration) -> Self>;
+
+    /// Generates time points within ten thousand years of the epoch of their time scale.
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        Duration::arbitrary().prop_map(Self::from_time_since_epoch)
+    }
+}
+
+#[cfg(test)]
+::proptest::proptest! {
+    /// Verifies that arbitrary dates round-trip through their historic calendar representation.
+    #[test]
+    fn historic_date_roundtrip(date: Date) {
+        let historic = crate::HistoricDate::from_date(date);
+        ::proptest::prop_assert_eq!(historic.into_date(), date);
+    }
+
+    /// Verifies that arbitrary time points round-trip through their date-time representation.
+    #[test]
+    fn tai_datetime_roundtrip(time_point: crate::TaiTime) {
+        use crate::{FromFineDateTime, IntoFineDateTime};
+        let (date, hour, minute, second, subseconds) = time_point.into_fine_datetime();
+        ::proptest::prop_assert_eq!(
+            crate::TaiTime::from_fine_datetime(date, hour, minute, second, subseconds),
+            Ok(time_point)
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Random sampling of `Duration`s and `TimePoint`s using the `rand` crate, which is useful for
+//! fuzzing and property-based testing.
+//!
+//! The `StandardUniform` distribution samples uniformly over all representable values, while
+//! uniform distributions over a range may be constructed through `Uniform`, or directly using
+//! `Rng::random_range`.
+
+use core::marker::PhantomData;
+
+use ::rand::{
+    Rng,
+    distr::{
+        Distribution, StandardUniform,
+        uniform::{Error, SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+    },
+};
+
+use crate::{Duration, TimePoint};
+
+impl Distribution<Duration> for StandardUniform {
+    /// Samples a `Duration` uniformly from all representable durations.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Duration {
+        Duration::attoseconds(rng.random())
+    }
+}
+
+/// Sampler for `Duration`s that are uniformly distributed over some range, with attosecond
+/// resolution. Used through `Uniform<Duration>` or `Rng::random_range`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UniformDuration {
+    attoseconds: UniformInt<i128>,
+}
+
+impl UniformSampler for UniformDuration {
+    type X = Duration;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ok(Self {
+            attoseconds: UniformInt::new(low.borrow().count(), high.borrow().count())?,
+        })
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ok(Self {
+            attoseconds: UniformInt::new_inclusive(low.borrow().count(), high.borrow().count())?,
+        })
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Duration::attoseconds(self.attoseconds.sample(rng))
+    }
+}
+
+impl SampleUniform for Duration {
+    type Sampler = UniformDuration;
+}
+
+impl<Scale: ?Sized> Distribution<TimePoint<Scale>> for StandardUniform {
+    /// Samples a `TimePoint` uniformly from all representable time points.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TimePoint<Scale> {
+        TimePoint::from_time_since_epoch(self.sample(rng))
+    }
+}
+
+/// Sampler for `TimePoint`s that are uniformly distributed over some range, with attosecond
+/// resolution. Used through `Uniform<TimePoint<Scale>>` or `Rng::random_range`.
+pub struct UniformTimePoint<Scale: ?Sized> {
+    time_since_epoch: UniformDuration,
+    time_scale: PhantomData<Scale>,
+}
+
+impl<Scale: ?Sized> UniformSampler for UniformTimePoint<Scale> {
+    type X = TimePoint<Scale>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ok(Self {
+            time_since_epoch: UniformDuration::new(
+                low.borrow().time_since_epoch(),
+                high.borrow().time_since_epoch(),
+            )?,
+            time_scale: PhantomData,
+        })
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Ok(Self {
+            time_since_epoch: UniformDuration::new_inclusive(
+                low.borrow().time_since_epoch(),
+                high.borrow().time_since_epoch(),
+            )?,
+            time_scale: PhantomData,
+        })
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        TimePoint::from_time_since_epoch(self.time_since_epoch.sample(rng))
+    }
+}
+
+impl<Scale: ?Sized> SampleUniform for TimePoint<Scale> {
+    type Sampler = UniformTimePoint<Scale>;
+}
+
+/// Verifies that durations sampled from a bounded range fall inside that range.
+#[test]
+fn sample_duration_range() {
+    use ::rand::{SeedableRng, distr::Uniform};
+    let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(877);
+    let low = Duration::seconds(-5);
+    let high = Duration::milliseconds(1_500);
+    let distribution = Uniform::new(low, high).unwrap();
+    for _ in 0..1000 {
+        let duration = distribution.sample(&mut rng);
+        assert!(low <= duration && duration < high);
+        let duration = rng.random_range(low..=high);
+        assert!(low <= duration && duration <= high);
+    }
+    assert!(Uniform::new(high, low).is_err());
+
+    // The standard distribution covers the full range, so it should hardly ever sample values
+    // within a single second.
+    let duration: Duration = rng.random();
+    assert!(duration.abs_diff(Duration::ZERO) > Duration::SECOND);
+}
+
+/// Verifies that time points sampled from a bounded range fall inside that range.
+#[test]
+fn sample_time_point_range() {
+    use crate::{GpsTime, Month};
+    use ::rand::{SeedableRng, distr::Uniform};
+    let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(878);
+    let low = GpsTime::from_historic_datetime(2000, Month::January, 1, 0, 0, 0).unwrap();
+    let high = GpsTime::from_historic_datetime(2000, Month::January, 2, 0, 0, 0).unwrap();
+    let distribution = Uniform::new(low, high).unwrap();
+    for _ in 0..1000 {
+        let time_point = distribution.sample(&mut rng);
+        assert!(low <= time_point && time_point < high);
+        let time_point = rng.random_range(low..=high);
+        assert!(low <= time_point && time_point <= high);
+    }
+}
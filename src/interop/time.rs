@@ -0,0 +1,105 @@
+//! Conversions between `UtcTime` and the `time` crate's `OffsetDateTime`.
+
+use ::time::OffsetDateTime;
+
+use crate::{Days, FromDateTime, IntoFineDateTime, UtcTime, errors::OffsetDateTimeOutOfRange};
+
+impl UtcTime {
+    /// Constructs a `UtcTime` representing the same instant as the given `time` date-time. Since
+    /// `OffsetDateTime` has nanosecond resolution and cannot represent leap seconds, this
+    /// conversion is always exact.
+    #[must_use]
+    pub fn from_time_offsetdatetime(date_time: OffsetDateTime) -> Self {
+        Self::from_unix_timestamp_nanos(date_time.unix_timestamp_nanos())
+    }
+
+    /// Converts into a `time` date-time at UTC offset zero. Sub-nanosecond precision is truncated
+    /// towards the past.
+    ///
+    /// The `time` crate does not model leap seconds. Any instant within a leap second 23:59:60 is
+    /// mapped to 23:59:59.999999999 of the same day, the last instant before midnight that
+    /// `OffsetDateTime` can represent. Hence, the date is preserved and ordering is retained
+    /// (although not strictly), but such instants do not round-trip.
+    ///
+    /// # Errors
+    /// Returns an error if this time point lies outside the range representable by
+    /// `OffsetDateTime`.
+    pub fn to_time_offsetdatetime(&self) -> Result<OffsetDateTime, OffsetDateTimeOutOfRange> {
+        let (date, _, _, second, _) = self.into_fine_datetime();
+        let unix_timestamp_nanos = if second == 60 {
+            let Ok(midnight) = Self::from_datetime(date + Days::new(1), 0, 0, 0) else {
+                unreachable!()
+            };
+            midnight.unix_timestamp_nanos() - 1
+        } else {
+            self.unix_timestamp_nanos()
+        };
+        OffsetDateTime::from_unix_timestamp_nanos(unix_timestamp_nanos)
+            .map_err(|_| OffsetDateTimeOutOfRange)
+    }
+}
+
+/// Verifies that ordinary time points round-trip through `time` date-times.
+#[test]
+fn offsetdatetime_roundtrip() {
+    use crate::{Duration, Month};
+    let utc = UtcTime::from_fine_historic_datetime(
+        2024,
+        Month::February,
+        29,
+        13,
+        14,
+        15,
+        Duration::nanoseconds(123_456_789),
+    )
+    .unwrap();
+    let date_time = utc.to_time_offsetdatetime().unwrap();
+    assert_eq!(date_time.unix_timestamp(), 1_709_212_455);
+    assert_eq!(date_time.nanosecond(), 123_456_789);
+    assert_eq!(date_time.offset(), ::time::UtcOffset::UTC);
+    assert_eq!(UtcTime::from_time_offsetdatetime(date_time), utc);
+
+    // Offsets are accounted for: the same instant at a different offset maps to the same UTC time.
+    let offset = ::time::UtcOffset::from_hms(2, 0, 0).unwrap();
+    assert_eq!(
+        UtcTime::from_time_offsetdatetime(date_time.to_offset(offset)),
+        utc
+    );
+
+    // Sub-nanosecond precision is truncated.
+    let truncated = (utc + Duration::picoseconds(999)).to_time_offsetdatetime();
+    assert_eq!(truncated, Ok(date_time));
+
+    let far_future = UtcTime::from_historic_datetime(10_000, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(
+        far_future.to_time_offsetdatetime(),
+        Err(OffsetDateTimeOutOfRange)
+    );
+}
+
+/// Verifies that leap seconds are mapped onto the last representable instant of the day.
+#[test]
+fn offsetdatetime_leap_second() {
+    use crate::{Duration, Month};
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    let date_time = leap_second.to_time_offsetdatetime().unwrap();
+    assert_eq!(date_time.year(), 2016);
+    assert_eq!(date_time.day(), 31);
+    assert_eq!(date_time.hour(), 23);
+    assert_eq!(date_time.minute(), 59);
+    assert_eq!(date_time.second(), 59);
+    assert_eq!(date_time.nanosecond(), 999_999_999);
+
+    let late = (leap_second + Duration::milliseconds(500)).to_time_offsetdatetime();
+    assert_eq!(late, Ok(date_time));
+
+    let before = UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 59).unwrap();
+    let after = UtcTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 0).unwrap();
+    assert!(before.to_time_offsetdatetime().unwrap() < date_time);
+    assert!(date_time < after.to_time_offsetdatetime().unwrap());
+    assert_eq!(
+        UtcTime::from_time_offsetdatetime(date_time),
+        before + Duration::nanoseconds(999_999_999)
+    );
+}
@@ -0,0 +1,199 @@
+//! Implementation of intervals, representing the span of time between two time points.
+
+use core::{fmt::Debug, hash::Hash};
+
+use crate::{Duration, TimePoint, errors::InvalidInterval};
+
+/// Span of time between two instants
+///
+/// An `Interval` is the half-open range of time points from its `start` (inclusive) up to its
+/// `end` (exclusive). Consequently, two intervals of which one ends exactly where the other starts
+/// are adjacent, but do not overlap. Intervals are guaranteed to have a start that does not lie
+/// after their end; an interval of which both coincide is empty.
+pub struct Interval<Scale: ?Sized> {
+    start: TimePoint<Scale>,
+    end: TimePoint<Scale>,
+}
+
+impl<Scale: ?Sized> Interval<Scale> {
+    /// Constructs the interval from `start` (inclusive) up to `end` (exclusive).
+    ///
+    /// # Errors
+    /// Returns an error if `end` lies before `start`.
+    pub fn try_new(
+        start: TimePoint<Scale>,
+        end: TimePoint<Scale>,
+    ) -> Result<Self, InvalidInterval> {
+        if start <= end {
+            Ok(Self { start, end })
+        } else {
+            Err(InvalidInterval)
+        }
+    }
+
+    /// Returns the first time point contained in this interval.
+    #[must_use]
+    pub const fn start(&self) -> TimePoint<Scale> {
+        self.start
+    }
+
+    /// Returns the time point at which this interval ends. This time point itself is not contained
+    /// in the interval.
+    #[must_use]
+    pub const fn end(&self) -> TimePoint<Scale> {
+        self.end
+    }
+
+    /// Returns the time elapsed between the start and end of this interval. Saturates at
+    /// `Duration::max_value()` if this duration cannot be represented.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end.abs_diff(self.start)
+    }
+
+    /// Returns whether this interval contains no time points, i.e., whether its start and end
+    /// coincide.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns whether the given time point lies within this interval.
+    #[must_use]
+    pub fn contains(&self, time_point: TimePoint<Scale>) -> bool {
+        self.start <= time_point && time_point < self.end
+    }
+
+    /// Returns whether this interval and `other` share any time point. Adjacent intervals do not
+    /// overlap.
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns the interval of time points contained in both this interval and `other`, or `None`
+    /// if they do not overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if self.overlaps(other) {
+            Some(Self {
+                start: self.start.max(other.start),
+                end: self.end.min(other.end),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the interval of time points contained in either this interval or `other`, provided
+    /// that they overlap or are adjacent. Returns `None` if a gap lies between both intervals,
+    /// since their union is then not a single interval.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.start <= other.end && other.start <= self.end {
+            Some(Self {
+                start: self.start.min(other.start),
+                end: self.end.max(other.end),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<Scale: ?Sized> Debug for Interval<Scale> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Interval")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+impl<Scale: ?Sized> Copy for Interval<Scale> {}
+
+impl<Scale: ?Sized> Clone for Interval<Scale> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Scale: ?Sized> PartialEq for Interval<Scale> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<Scale: ?Sized> Eq for Interval<Scale> {}
+
+impl<Scale: ?Sized> Hash for Interval<Scale> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.start.hash(state);
+        self.end.hash(state);
+    }
+}
+
+/// Returns the TAI interval between the given number of seconds since the TAI epoch.
+#[cfg(test)]
+fn seconds_interval(start: i128, end: i128) -> Interval<crate::Tai> {
+    Interval::try_new(
+        TimePoint::from_time_since_epoch(Duration::seconds(start)),
+        TimePoint::from_time_since_epoch(Duration::seconds(end)),
+    )
+    .unwrap()
+}
+
+/// Verifies construction of intervals and the time points they contain.
+#[test]
+fn construction() {
+    let start = TimePoint::<crate::Tai>::from_time_since_epoch(Duration::seconds(10));
+    let end = TimePoint::from_time_since_epoch(Duration::seconds(20));
+    assert_eq!(Interval::try_new(end, start), Err(InvalidInterval));
+
+    let interval = Interval::try_new(start, end).unwrap();
+    assert_eq!(interval.duration(), Duration::seconds(10));
+    assert!(interval.contains(start));
+    assert!(!interval.contains(end));
+    assert!(interval.contains(end - Duration::attoseconds(1)));
+    assert!(!interval.contains(start - Duration::attoseconds(1)));
+
+    let empty = Interval::try_new(start, start).unwrap();
+    assert!(empty.is_empty());
+    assert!(!empty.contains(start));
+}
+
+/// Verifies that overlapping intervals intersect, and may be joined.
+#[test]
+fn overlapping_intervals() {
+    let a = seconds_interval(0, 10);
+    let b = seconds_interval(5, 15);
+    assert!(a.overlaps(&b));
+    assert!(b.overlaps(&a));
+    assert_eq!(a.intersection(&b), Some(seconds_interval(5, 10)));
+    assert_eq!(a.union(&b), Some(seconds_interval(0, 15)));
+
+    let inner = seconds_interval(2, 3);
+    assert_eq!(a.intersection(&inner), Some(inner));
+    assert_eq!(a.union(&inner), Some(a));
+}
+
+/// Verifies that disjoint intervals neither intersect nor join.
+#[test]
+fn disjoint_intervals() {
+    let a = seconds_interval(0, 10);
+    let b = seconds_interval(11, 15);
+    assert!(!a.overlaps(&b));
+    assert_eq!(a.intersection(&b), None);
+    assert_eq!(b.union(&a), None);
+}
+
+/// Verifies that adjacent intervals do not overlap, but may be joined.
+#[test]
+fn touching_intervals() {
+    let a = seconds_interval(0, 10);
+    let b = seconds_interval(10, 15);
+    assert!(!a.overlaps(&b));
+    assert_eq!(a.intersection(&b), None);
+    assert_eq!(a.union(&b), Some(seconds_interval(0, 15)));
+    assert_eq!(b.union(&a), Some(seconds_interval(0, 15)));
+}
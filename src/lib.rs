@@ -1,15 +1,36 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
 mod calendar;
 pub use calendar::*;
+mod calendar_duration;
+pub use calendar_duration::*;
 mod duration;
 pub use duration::*;
 pub mod errors;
+#[cfg(feature = "alloc")]
+mod format;
+#[cfg(feature = "alloc")]
+pub use format::*;
 mod fractional_digits;
 pub use fractional_digits::*;
+mod interop;
+#[cfg(feature = "rand")]
+pub use interop::{UniformDuration, UniformTimePoint};
+mod interval;
+pub use interval::*;
+#[cfg(feature = "std")]
+mod monotonic_clock;
+#[cfg(feature = "std")]
+pub use monotonic_clock::*;
+mod offset_time;
+pub use offset_time::*;
 mod parse;
 pub use parse::*;
+#[cfg(feature = "serde")]
+pub mod serde_attoseconds;
 mod time_point;
 pub use time_point::*;
 mod time_scale;
@@ -0,0 +1,85 @@
+//! Implementation of a monotonic clock, which may be used to measure elapsed time without being
+//! affected by adjustments of the system's wall clock.
+
+use std::time::Instant;
+
+use crate::Duration;
+
+/// Monotonic clock, wrapping a `std::time::Instant` at which it was started.
+///
+/// Unlike `UtcTime::now()`, which is based on the system's wall clock and may jump forwards or
+/// backwards when the system time is adjusted, the time elapsed since starting a `MonotonicClock`
+/// never decreases. This makes it suitable for measuring latencies and other intervals. However,
+/// the readings of a monotonic clock are not related to any time scale: only differences between
+/// them are meaningful.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonotonicClock {
+    start: Instant,
+}
+
+impl MonotonicClock {
+    /// Starts a new monotonic clock at the current instant.
+    #[must_use]
+    pub fn start() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+
+    /// Returns the time elapsed since this clock was started. The result retains the nanosecond
+    /// resolution of `std::time::Instant`.
+    ///
+    /// # Panics
+    /// Panics if the elapsed time does not fit in a `Duration`, which will only happen after
+    /// about five trillion years.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        nanoseconds(self.start.elapsed())
+    }
+
+    /// Restarts this clock at the current instant, returning the time elapsed since it was
+    /// previously started. Useful to measure consecutive intervals without gaps between them.
+    ///
+    /// # Panics
+    /// Panics if the elapsed time does not fit in a `Duration`, which will only happen after
+    /// about five trillion years.
+    pub fn restart(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.start);
+        self.start = now;
+        nanoseconds(elapsed)
+    }
+}
+
+impl Default for MonotonicClock {
+    /// Starts a new monotonic clock at the current instant.
+    fn default() -> Self {
+        Self::start()
+    }
+}
+
+/// Converts a `std::time::Duration` into a `Duration`, retaining its full nanosecond resolution.
+fn nanoseconds(duration: std::time::Duration) -> Duration {
+    Duration::nanoseconds(
+        duration
+            .as_nanos()
+            .try_into()
+            .expect("elapsed time does not fit in a `Duration`"),
+    )
+}
+
+/// Verifies that the time elapsed during a short sleep is measured as positive, and within some
+/// loose bounds of the requested sleep duration.
+#[test]
+fn measure_sleep() {
+    let mut clock = MonotonicClock::start();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    let elapsed = clock.elapsed();
+    assert!(elapsed.is_strictly_positive());
+    assert!(elapsed >= Duration::milliseconds(10));
+    assert!(elapsed < Duration::seconds(10));
+
+    let restarted = clock.restart();
+    assert!(restarted >= elapsed);
+    assert!(clock.elapsed() < restarted);
+}
@@ -0,0 +1,218 @@
+//! Implementation of time points that are expressed relative to a fixed offset from UTC, as
+//! commonly used for civil time. No daylight saving time rules are applied: the offset is fixed.
+
+use core::fmt::Display;
+
+use num_traits::Zero;
+
+use crate::{
+    Date, Duration, UtcTime,
+    errors::{InvalidOffsetDateTime, InvalidUtcOffset},
+    units::SecondsPerMinute,
+};
+
+/// Time point with a fixed UTC offset
+///
+/// Pairs a `UtcTime` with a fixed offset from UTC, such that it may be converted to and from the
+/// local wall-clock time at that offset. This covers civil time in all regions that do not
+/// observe daylight saving time (or during periods in which it does not change). Offsets are
+/// restricted to whole numbers of minutes, between -23:59 and +23:59.
+///
+/// Equality compares both the instant and the offset: use `utc()` to compare instants only.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OffsetTime {
+    utc: UtcTime,
+    offset: Duration,
+}
+
+impl OffsetTime {
+    /// Pairs the given UTC time point with a fixed offset from UTC.
+    ///
+    /// # Errors
+    /// Returns an error if the offset is not a whole number of minutes, or if it exceeds the range
+    /// of -23:59 to +23:59.
+    pub fn new(utc: UtcTime, offset: Duration) -> Result<Self, InvalidUtcOffset> {
+        utc_offset_minutes(offset)?;
+        Ok(Self { utc, offset })
+    }
+
+    /// Pairs the given UTC time point with a zero offset.
+    #[must_use]
+    pub const fn from_utc(utc: UtcTime) -> Self {
+        Self {
+            utc,
+            offset: Duration::ZERO,
+        }
+    }
+
+    /// Constructs an `OffsetTime` from a local wall-clock date-time at the given offset from UTC.
+    /// Leap seconds may be given in local time as well, for example as 01:59:60 at +02:00.
+    ///
+    /// # Errors
+    /// Returns an error if the offset is not a whole number of minutes, if it exceeds the range of
+    /// -23:59 to +23:59, or if the resulting UTC date-time does not exist.
+    pub fn from_local_datetime(
+        date: Date,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        offset: Duration,
+    ) -> Result<Self, InvalidOffsetDateTime> {
+        let offset_minutes = utc_offset_minutes(offset)?;
+        let utc = UtcTime::from_local_fine_datetime(
+            date,
+            hour,
+            minute,
+            second,
+            Duration::ZERO,
+            offset_minutes,
+        )?;
+        Ok(Self { utc, offset })
+    }
+
+    /// Returns the UTC time point represented by this `OffsetTime`.
+    #[must_use]
+    pub const fn utc(&self) -> UtcTime {
+        self.utc
+    }
+
+    /// Returns the offset from UTC of this `OffsetTime`.
+    #[must_use]
+    pub const fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// Returns a new `OffsetTime` that represents the same instant, but at a different offset.
+    ///
+    /// # Errors
+    /// Returns an error if the offset is not a whole number of minutes, or if it exceeds the range
+    /// of -23:59 to +23:59.
+    pub fn with_offset(self, offset: Duration) -> Result<Self, InvalidUtcOffset> {
+        Self::new(self.utc, offset)
+    }
+
+    /// Returns the local wall-clock date-time at the offset of this `OffsetTime`, as a tuple of
+    /// date, hour, minute, and second. Leap seconds are retained as second 60.
+    #[must_use]
+    pub fn local_datetime(&self) -> (Date, u8, u8, u8) {
+        self.utc.into_local_datetime(self.offset_minutes())
+    }
+
+    /// Returns the offset of this `OffsetTime` in minutes. Infallible, since it is checked upon
+    /// construction.
+    fn offset_minutes(&self) -> i32 {
+        let Ok(offset_minutes) = utc_offset_minutes(self.offset) else {
+            unreachable!()
+        };
+        offset_minutes
+    }
+}
+
+/// Converts a UTC offset into a whole number of minutes, as required for `OffsetTime`.
+///
+/// # Errors
+/// Returns an error if the offset is not a whole number of minutes, or if it exceeds the range
+/// of -23:59 to +23:59.
+fn utc_offset_minutes(offset: Duration) -> Result<i32, InvalidUtcOffset> {
+    let (minutes, remainder) = offset.factor_out::<SecondsPerMinute>();
+    if !remainder.is_zero() || minutes.abs() >= 24 * 60 {
+        return Err(InvalidUtcOffset { offset });
+    }
+    let Ok(minutes) = minutes.try_into() else {
+        unreachable!()
+    };
+    Ok(minutes)
+}
+
+impl From<UtcTime> for OffsetTime {
+    fn from(utc: UtcTime) -> Self {
+        Self::from_utc(utc)
+    }
+}
+
+impl From<OffsetTime> for UtcTime {
+    fn from(offset_time: OffsetTime) -> Self {
+        offset_time.utc
+    }
+}
+
+/// Renders the local date-time, followed by the offset as `+HH:MM` or `-HH:MM`. This is a valid
+/// RFC 3339 timestamp.
+impl Display for OffsetTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.utc.write_rfc3339(f, Some(self.offset_minutes()))
+    }
+}
+
+/// Verifies that a UTC instant may be converted into local time at +09:00, and back.
+#[test]
+fn local_datetime_roundtrip() {
+    use crate::Month;
+    let utc = UtcTime::from_historic_datetime(2024, Month::March, 31, 18, 30, 15).unwrap();
+    let tokyo = OffsetTime::new(utc, Duration::hours(9)).unwrap();
+    let local_date = Date::from_historic_date(2024, Month::April, 1).unwrap();
+    assert_eq!(tokyo.local_datetime(), (local_date, 3, 30, 15));
+
+    let roundtrip =
+        OffsetTime::from_local_datetime(local_date, 3, 30, 15, Duration::hours(9)).unwrap();
+    assert_eq!(roundtrip, tokyo);
+    assert_eq!(roundtrip.utc(), utc);
+    assert_eq!(UtcTime::from(roundtrip), utc);
+
+    let new_york = tokyo.with_offset(-Duration::hours(4)).unwrap();
+    assert_eq!(new_york.utc(), utc);
+    assert_eq!(
+        new_york.local_datetime(),
+        (
+            Date::from_historic_date(2024, Month::March, 31).unwrap(),
+            14,
+            30,
+            15
+        )
+    );
+
+    // Leap seconds are retained in local time.
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    let local = OffsetTime::new(leap_second, Duration::hours(9)).unwrap();
+    let local_date = Date::from_historic_date(2017, Month::January, 1).unwrap();
+    assert_eq!(local.local_datetime(), (local_date, 8, 59, 60));
+    assert_eq!(
+        OffsetTime::from_local_datetime(local_date, 8, 59, 60, Duration::hours(9)),
+        Ok(local)
+    );
+}
+
+/// Verifies that offsets that are not a whole number of minutes are rejected.
+#[test]
+fn invalid_offsets() {
+    let utc = UtcTime::from_time_since_epoch(Duration::ZERO);
+    let offset = Duration::minutes(30) + Duration::seconds(1);
+    assert_eq!(
+        OffsetTime::new(utc, offset),
+        Err(InvalidUtcOffset { offset })
+    );
+    assert!(OffsetTime::new(utc, Duration::hours(24)).is_err());
+    assert_eq!(
+        OffsetTime::from_local_datetime(utc.date(), 0, 0, 0, offset),
+        Err(InvalidOffsetDateTime::InvalidUtcOffset(InvalidUtcOffset {
+            offset
+        }))
+    );
+}
+
+/// Verifies that the offset is displayed as `+HH:MM` or `-HH:MM`.
+#[cfg(feature = "std")]
+#[test]
+fn display_offset() {
+    use crate::Month;
+    let utc = UtcTime::from_historic_datetime(2024, Month::January, 1, 0, 0, 0).unwrap();
+    let tokyo = OffsetTime::new(utc, Duration::hours(9)).unwrap();
+    assert_eq!(tokyo.to_string(), "2024-01-01T09:00:00+09:00");
+    let newfoundland = OffsetTime::new(utc, -Duration::minutes(210)).unwrap();
+    assert_eq!(newfoundland.to_string(), "2023-12-31T20:30:00-03:30");
+    assert_eq!(
+        OffsetTime::from_utc(utc).to_string(),
+        "2024-01-01T00:00:00+00:00"
+    );
+}
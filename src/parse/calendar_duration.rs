@@ -0,0 +1,302 @@
+//! Implementation of parsing logic for `CalendarDuration` types.
+
+use core::str::FromStr;
+
+use super::duration::{DURATION_PREFIX, FRACTIONAL_SEPARATORS, TIME_DESIGNATOR, offset};
+use crate::{CalendarDuration, Duration, DurationDesignator, errors::DurationParsingError};
+
+impl FromStr for CalendarDuration {
+    type Err = DurationParsingError;
+
+    /// Parses an ISO 8601 duration string, retaining its individual components. The same syntax
+    /// as for `Duration` is accepted, except that only the seconds component may be fractional:
+    /// fractions of other units cannot be retained without collapsing them into smaller units.
+    ///
+    /// Designators must be given in decreasing order. An 'M' designator is interpreted as months
+    /// if it is the first component, or directly follows the years, and as minutes otherwise. As
+    /// for `Duration`, the prefix and all designators are case-insensitive.
+    fn from_str(mut string: &str) -> Result<Self, Self::Err> {
+        let input = string;
+        let is_negative = string.starts_with('-');
+        if let Some(remainder) = string.strip_prefix('-') {
+            string = remainder;
+        }
+        string = string
+            .strip_prefix(DURATION_PREFIX)
+            .ok_or(DurationParsingError::ExpectedDurationPrefix)?;
+        if string.is_empty() || string.strip_prefix(TIME_DESIGNATOR) == Some("") {
+            return Err(DurationParsingError::EmptyDuration);
+        }
+
+        let mut duration = Self::default();
+        let mut previous: Option<DurationDesignator> = None;
+        let mut is_time = false;
+        while !string.is_empty() {
+            if !is_time && let Some(remainder) = string.strip_prefix(TIME_DESIGNATOR) {
+                // After the time designator, only hours, minutes, and seconds may follow.
+                is_time = true;
+                if previous.is_none_or(|previous| previous > DurationDesignator::Days) {
+                    previous = Some(DurationDesignator::Days);
+                }
+                string = remainder;
+                continue;
+            }
+
+            let (count, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
+            (_, string) = string.split_at(consumed_bytes);
+
+            let mut fraction = None;
+            if let Some(remainder) = string.strip_prefix(FRACTIONAL_SEPARATORS) {
+                let (numerator, fractional_digits) =
+                    lexical_core::parse_partial(remainder.as_bytes())?;
+                let denominator = u32::try_from(fractional_digits)
+                    .ok()
+                    .and_then(|digits| 10i128.checked_pow(digits))
+                    .ok_or(DurationParsingError::Overflow)?;
+                fraction = Some(
+                    Duration::seconds(1)
+                        .checked_mul(numerator)
+                        .ok_or(DurationParsingError::Overflow)?
+                        .div_round(denominator),
+                );
+                (_, string) = remainder.split_at(fractional_digits);
+            }
+
+            let designator_position = offset(input, string);
+            let mut characters = string.chars();
+            let Some(designator) = characters
+                .next()
+                .and_then(|character| parse_designator(character, is_time, previous))
+            else {
+                return Err(DurationParsingError::ExpectedDurationDesignator {
+                    position: designator_position,
+                });
+            };
+            string = characters.as_str();
+
+            if previous.is_some_and(|previous| designator >= previous) {
+                return Err(DurationParsingError::NonDecreasingDesignators {
+                    current: designator,
+                    position: designator_position,
+                });
+            }
+            previous = Some(designator);
+
+            match designator {
+                DurationDesignator::Years => duration.years = count,
+                DurationDesignator::Months => duration.months = count,
+                DurationDesignator::Days => duration.days = count,
+                DurationDesignator::Hours => duration.hours = count,
+                DurationDesignator::Minutes => duration.minutes = count,
+                DurationDesignator::Seconds => duration.seconds = count,
+            }
+
+            if let Some(fraction) = fraction {
+                if designator != DurationDesignator::Seconds {
+                    return Err(DurationParsingError::FractionalCalendarComponent);
+                }
+                duration.subseconds = fraction;
+            }
+        }
+
+        duration
+            .checked_to_duration()
+            .ok_or(DurationParsingError::Overflow)?;
+        if is_negative {
+            duration = Self {
+                years: -duration.years,
+                months: -duration.months,
+                days: -duration.days,
+                hours: -duration.hours,
+                minutes: -duration.minutes,
+                seconds: -duration.seconds,
+                subseconds: -duration.subseconds,
+            };
+        }
+        Ok(duration)
+    }
+}
+
+/// Maps a designator character to the component it denotes, case-insensitively. An 'M' denotes
+/// months if it is not preceded by the time designator and follows at most the years, and minutes
+/// otherwise.
+fn parse_designator(
+    character: char,
+    is_time: bool,
+    previous: Option<DurationDesignator>,
+) -> Option<DurationDesignator> {
+    match character.to_ascii_uppercase() {
+        'Y' => Some(DurationDesignator::Years),
+        'M' if !is_time
+            && previous.is_none_or(|previous| previous == DurationDesignator::Years) =>
+        {
+            Some(DurationDesignator::Months)
+        }
+        'M' => Some(DurationDesignator::Minutes),
+        'D' => Some(DurationDesignator::Days),
+        'H' => Some(DurationDesignator::Hours),
+        'S' => Some(DurationDesignator::Seconds),
+        _ => None,
+    }
+}
+
+/// Verifies that calendar durations collapse to the same `Duration` as obtained by parsing
+/// directly, while retaining the original components.
+#[test]
+fn collapse_to_duration() {
+    for string in [
+        "P1Y2M",
+        "P14M",
+        "P1Y2M3DT4H5M6.5S",
+        "P3D",
+        "PT36H",
+        "P1DT12H",
+        "PT1,25S",
+        "-P2Y",
+        "-PT0.000000000000000001S",
+        "P1S",
+    ] {
+        let calendar_duration = CalendarDuration::from_str(string).unwrap();
+        assert_eq!(
+            calendar_duration.to_duration(),
+            Duration::from_str(string).unwrap(),
+        );
+    }
+
+    let calendar_duration = CalendarDuration::from_str("P1Y2M").unwrap();
+    assert_eq!(calendar_duration.years, 1);
+    assert_eq!(calendar_duration.months, 2);
+    assert_ne!(
+        calendar_duration,
+        CalendarDuration::from_str("P14M").unwrap()
+    );
+
+    let calendar_duration = CalendarDuration::from_str("P2MT2M").unwrap();
+    assert_eq!(calendar_duration.months, 2);
+    assert_eq!(calendar_duration.minutes, 2);
+    assert_eq!(
+        calendar_duration.to_duration(),
+        Duration::months(2) + Duration::minutes(2)
+    );
+}
+
+/// Verifies that the prefix and designators of calendar durations are case-insensitive.
+#[test]
+fn lowercase_calendar_designators() {
+    assert_eq!(
+        CalendarDuration::from_str("p1y2m3dt4h5m6s"),
+        CalendarDuration::from_str("P1Y2M3DT4H5M6S")
+    );
+    let calendar_duration = CalendarDuration::from_str("p2mt2m").unwrap();
+    assert_eq!(calendar_duration.months, 2);
+    assert_eq!(calendar_duration.minutes, 2);
+}
+
+/// Verifies that formatting a parsed calendar duration reproduces the original string.
+#[cfg(feature = "std")]
+#[test]
+fn calendar_duration_roundtrip() {
+    for string in [
+        "P1Y2M",
+        "P14M",
+        "P1Y2M3DT4H5M6.5S",
+        "PT36H",
+        "P1DT12H",
+        "-P2Y3D",
+        "-PT0.25S",
+        "PT0S",
+        "PT0.000000000000000001S",
+    ] {
+        let calendar_duration = CalendarDuration::from_str(string).unwrap();
+        assert_eq!(calendar_duration.to_string(), string);
+    }
+
+    assert_eq!(CalendarDuration::default().to_string(), "PT0S");
+    assert_eq!(
+        CalendarDuration::from_str("PT1,5S").unwrap().to_string(),
+        "PT1.5S"
+    );
+}
+
+/// Verifies that calendar durations whose seconds are not representable as `Duration` may still
+/// be formatted, also if seconds and subseconds have opposite signs.
+#[cfg(feature = "std")]
+#[test]
+fn format_extreme_seconds() {
+    let duration = CalendarDuration {
+        seconds: i128::MAX,
+        subseconds: Duration::milliseconds(1_500),
+        ..CalendarDuration::default()
+    };
+    assert_eq!(
+        duration.to_string(),
+        "PT170141183460469231731687303715884105728.5S"
+    );
+
+    let duration = CalendarDuration {
+        seconds: i128::MIN,
+        ..CalendarDuration::default()
+    };
+    assert_eq!(
+        duration.to_string(),
+        "-PT170141183460469231731687303715884105728S"
+    );
+
+    let duration = CalendarDuration {
+        hours: 1,
+        seconds: 2,
+        subseconds: Duration::milliseconds(-250),
+        ..CalendarDuration::default()
+    };
+    assert_eq!(duration.to_string(), "PT1H1.75S");
+
+    let duration = CalendarDuration {
+        hours: 1,
+        subseconds: Duration::milliseconds(-250),
+        ..CalendarDuration::default()
+    };
+    assert_eq!(duration.to_string(), "PT1H-0.25S");
+}
+
+/// Verifies that malformed or unsupported calendar durations are rejected.
+#[test]
+fn invalid_calendar_durations() {
+    assert_eq!(
+        CalendarDuration::from_str("1Y"),
+        Err(DurationParsingError::ExpectedDurationPrefix)
+    );
+    assert_eq!(
+        CalendarDuration::from_str("P1.5D"),
+        Err(DurationParsingError::FractionalCalendarComponent)
+    );
+    assert_eq!(
+        CalendarDuration::from_str("P1D2Y"),
+        Err(DurationParsingError::NonDecreasingDesignators {
+            current: DurationDesignator::Years,
+            position: 4
+        })
+    );
+    assert_eq!(
+        CalendarDuration::from_str("PT1D"),
+        Err(DurationParsingError::NonDecreasingDesignators {
+            current: DurationDesignator::Days,
+            position: 3
+        })
+    );
+    assert_eq!(
+        CalendarDuration::from_str("P1X"),
+        Err(DurationParsingError::ExpectedDurationDesignator { position: 2 })
+    );
+    assert_eq!(
+        CalendarDuration::from_str("PT1000000000000000000000000000000M"),
+        Err(DurationParsingError::Overflow)
+    );
+    assert_eq!(
+        CalendarDuration::from_str("P"),
+        Err(DurationParsingError::EmptyDuration)
+    );
+    assert_eq!(
+        CalendarDuration::from_str("pt"),
+        Err(DurationParsingError::EmptyDuration)
+    );
+}
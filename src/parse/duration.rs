@@ -2,54 +2,218 @@
 
 use core::str::FromStr;
 
-use num_traits::ConstZero;
+#[cfg(feature = "serde")]
+use alloc::string::{String, ToString};
+
+use num_integer::Integer;
 
 use crate::{Duration, errors::DurationParsingError};
 
 impl FromStr for Duration {
     type Err = DurationParsingError;
 
-    /// Parses a `Duration` type based on some ISO 8601 duration string. However, we additionally
-    /// impose that months may not be used as duration, to prevent confusion with minutes (and
-    /// because their precise duration cannot be unambiguously defined). Furthermore, we do not
-    /// support use of the time designator ('T') inside duration expressions. Finally, we support
-    /// years, days, hours, minutes, and seconds with any number of digits.
+    /// Parses a `Duration` type based on some ISO 8601 duration string. Years, months, days,
+    /// hours, minutes, and seconds are supported with any number of digits.
+    ///
+    /// The time designator ('T') separates the date components from the time components. It may
+    /// be omitted before hours and seconds, but is required to express minutes, since an 'M'
+    /// before it denotes months.
     ///
     /// For years, following the rest of this library, a duration of 31556952 seconds is used, which
-    /// corresponds with the exact average duration of a Gregorian year.
+    /// corresponds with the exact average duration of a Gregorian year. Months are one twelfth
+    /// thereof.
+    ///
+    /// Negative durations may be expressed using a leading minus sign, as permitted by ISO 8601-2
+    /// and as produced by the `Display` implementation of `Duration`. Fractional components may
+    /// use either a full stop or a comma as decimal separator.
+    ///
+    /// The prefix, time designator, and component designators are case-insensitive, so `p1dt2h`
+    /// is accepted as well. As for uppercase input, a lowercase `m` denotes months before the time
+    /// designator and minutes after it.
+    ///
+    /// Weeks may be given only as the sole component of a duration, as in `P2W`, since ISO 8601
+    /// does not permit combining them with other designators.
+    ///
+    /// At least one component must be given: a bare `P` or `PT` is rejected, while a zero
+    /// duration may be expressed as `PT0S`.
     fn from_str(mut string: &str) -> Result<Self, Self::Err> {
+        let input = string;
+
+        // Parse the optional sign.
+        let is_negative = string.starts_with('-');
+        if is_negative {
+            string = string.get(1..).unwrap();
+        }
+
         // Parse the mandatory duration prefix 'P'.
-        if string.starts_with('P') {
+        if string.starts_with(DURATION_PREFIX) {
             string = string.get(1..).unwrap();
         } else {
             return Err(DurationParsingError::ExpectedDurationPrefix);
         }
-        parse_years_duration(string)
+        let duration = parse_years_duration(input, string)?;
+        if is_negative {
+            Ok(-duration)
+        } else {
+            Ok(duration)
+        }
     }
 }
 
-/// Parses the remainder of an ISO 8601 duration string after a 'P'.
+impl Duration {
+    /// Parses a `Duration` from a plain decimal number of seconds, like `"1.5"` or `"3600"`, as
+    /// commonly found in data formats that do not use ISO 8601 durations. An optional sign and
+    /// an exponent in scientific notation (`"1e-9"`) are supported. Any number of fractional
+    /// digits may be given: digits beyond attosecond precision are truncated.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a decimal number, or if the resulting duration is
+    /// not representable.
+    pub fn from_seconds_str(mut string: &str) -> Result<Self, DurationParsingError> {
+        let input = string;
+        let is_negative = string.starts_with('-');
+        if let Some(remainder) = string.strip_prefix(['-', '+']) {
+            string = remainder;
+        }
+
+        let integer_digits = string.bytes().take_while(u8::is_ascii_digit).count();
+        let (integer, mut string) = string.split_at(integer_digits);
+        let mut fraction = "";
+        if let Some(remainder) = string.strip_prefix('.') {
+            let fractional_digits = remainder.bytes().take_while(u8::is_ascii_digit).count();
+            (fraction, string) = remainder.split_at(fractional_digits);
+        }
+
+        let mut exponent: i32 = 0;
+        if let Some(remainder) = string.strip_prefix(['e', 'E']) {
+            exponent = lexical_core::parse(remainder.as_bytes())?;
+            string = "";
+        }
+
+        if !string.is_empty() {
+            return Err(DurationParsingError::UnexpectedRemainder {
+                position: offset(input, string),
+            });
+        }
+
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(lexical_core::Error::Empty(0).into());
+        }
+
+        // The significant digits are those of the integer and fractional part combined, which
+        // must be scaled by the power of ten that results in a count of attoseconds. Digits that
+        // would fall below attosecond precision are truncated.
+        let fractional_digits: i32 = fraction
+            .len()
+            .try_into()
+            .map_err(|_| DurationParsingError::Overflow)?;
+        let scale = exponent
+            .checked_sub(fractional_digits)
+            .and_then(|scale| scale.checked_add(18))
+            .ok_or(DurationParsingError::Overflow)?;
+        let digits = integer.bytes().chain(fraction.bytes());
+        let truncated_digits = usize::try_from(scale.min(0).unsigned_abs()).unwrap_or(usize::MAX);
+        let retained_digits = (integer.len() + fraction.len()).saturating_sub(truncated_digits);
+        let significand = digits
+            .take(retained_digits)
+            .try_fold(0i128, |count, digit| {
+                count
+                    .checked_mul(10)
+                    .and_then(|count| count.checked_add((digit - b'0').into()))
+                    .ok_or(DurationParsingError::Overflow)
+            })?;
+        let count = 10i128
+            .checked_pow(scale.max(0).unsigned_abs())
+            .and_then(|factor| significand.checked_mul(factor))
+            .ok_or(DurationParsingError::Overflow)?;
+
+        let duration = Self::attoseconds(count);
+        if is_negative {
+            Ok(-duration)
+        } else {
+            Ok(duration)
+        }
+    }
+}
+
+/// The duration prefix 'P' and time designator 'T' are accepted in either case. The same holds
+/// for the component designators, which are uppercased before being matched.
+pub(super) const DURATION_PREFIX: [char; 2] = ['P', 'p'];
+pub(super) const TIME_DESIGNATOR: [char; 2] = ['T', 't'];
+
+/// ISO 8601 permits both the full stop and the comma as decimal separator for fractional
+/// components, with no preference for either.
+pub(super) const FRACTIONAL_SEPARATORS: [char; 2] = ['.', ','];
+
+/// Returns `count` times the given unit, or an overflow error if the result is not representable.
+fn component(unit: Duration, count: i128) -> Result<Duration, DurationParsingError> {
+    unit.checked_mul(count)
+        .ok_or(DurationParsingError::Overflow)
+}
+
+/// Returns `count + numerator / denominator` times the given unit, or an overflow error if the
+/// result is not representable. The unit and denominator are first reduced by their greatest
+/// common divisor, and the whole part of the fraction is split off before multiplying, such that
+/// fractions with many digits do not overflow in intermediate results.
+fn fractional_component(
+    unit: Duration,
+    count: i128,
+    numerator: i128,
+    denominator: i128,
+) -> Result<Duration, DurationParsingError> {
+    let divisor = unit.count().gcd(&denominator);
+    let reduced_unit = Duration::attoseconds(unit.count() / divisor);
+    let denominator = denominator / divisor;
+    let whole = component(reduced_unit, numerator / denominator)?;
+    let fraction = component(reduced_unit, numerator % denominator)?.div_round(denominator);
+    accumulate(accumulate(component(unit, count)?, whole)?, fraction)
+}
+
+/// Adds a newly parsed component to the duration parsed so far, returning an overflow error if
+/// the sum is not representable.
+fn accumulate(duration: Duration, component: Duration) -> Result<Duration, DurationParsingError> {
+    duration
+        .checked_add(component)
+        .ok_or(DurationParsingError::Overflow)
+}
+
+/// Returns the byte offset of `remainder` within `input`, of which it must be a suffix. Used to
+/// report the position at which parsing failed.
+pub(super) const fn offset(input: &str, remainder: &str) -> usize {
+    input.len() - remainder.len()
+}
+
+/// Parses the remainder of an ISO 8601 duration string after a 'P'. At least one component must
+/// be present, also if only the time designator is given.
 #[inline]
-fn parse_years_duration(mut string: &str) -> Result<Duration, DurationParsingError> {
-    if string.starts_with('T') {
+fn parse_years_duration(input: &str, mut string: &str) -> Result<Duration, DurationParsingError> {
+    if string.starts_with(TIME_DESIGNATOR) {
         string = string.get(1..).unwrap();
-        return parse_hours_duration(string, Duration::ZERO);
+        if string.is_empty() {
+            return Err(DurationParsingError::EmptyDuration);
+        }
+        return parse_hours_duration(input, string, Duration::ZERO);
+    }
+
+    if string.is_empty() {
+        return Err(DurationParsingError::EmptyDuration);
     }
 
     let (count, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(consumed_bytes..).unwrap();
-    if string.starts_with('.') {
-        parse_years_fractional_duration(string, count)
+    if string.starts_with(FRACTIONAL_SEPARATORS) {
+        parse_years_fractional_duration(input, string, count)
     } else {
-        parse_years_duration_designator(string, count)
+        parse_years_duration_designator(input, string, count)
     }
 }
 
 /// Parses the fractional duration of an ISO 8601 duration string. Applied to the case where no
-/// other components have been parsed yet: units of years, months, days, hours, and seconds are
-/// possible.
+/// other components have been parsed yet: units of years, months, weeks, days, hours, and seconds
+/// are possible.
 #[inline]
 fn parse_years_fractional_duration(
+    input: &str,
     mut string: &str,
     count: i128,
 ) -> Result<Duration, DurationParsingError> {
@@ -57,53 +221,88 @@ fn parse_years_fractional_duration(
     let (subcount, fractional_digits) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(fractional_digits..).unwrap();
 
-    let denominator = 10i128.pow(fractional_digits.try_into().unwrap());
+    let denominator = u32::try_from(fractional_digits)
+        .ok()
+        .and_then(|digits| 10i128.checked_pow(digits))
+        .ok_or(DurationParsingError::Overflow)?;
     let numerator: i128 = subcount;
 
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
     if !string.is_empty() {
-        return Err(DurationParsingError::UnexpectedRemainder);
+        return Err(DurationParsingError::UnexpectedRemainder {
+            position: offset(input, string),
+        });
     }
 
     match duration_designator {
-        'Y' => Ok(Duration::years(count) + Duration::years(numerator).div_round(denominator)),
-        'M' => Ok(Duration::months(count) + Duration::months(numerator).div_round(denominator)),
-        'D' => Ok(Duration::days(count) + Duration::days(numerator).div_round(denominator)),
-        'H' => Ok(Duration::hours(count) + Duration::hours(numerator).div_round(denominator)),
-        'S' => Ok(Duration::seconds(count) + Duration::seconds(numerator).div_round(denominator)),
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
+        'Y' => fractional_component(Duration::years(1), count, numerator, denominator),
+        'M' => fractional_component(Duration::months(1), count, numerator, denominator),
+        'W' => fractional_component(Duration::weeks(1), count, numerator, denominator),
+        'D' => fractional_component(Duration::days(1), count, numerator, denominator),
+        'H' => fractional_component(Duration::hours(1), count, numerator, denominator),
+        'S' => fractional_component(Duration::seconds(1), count, numerator, denominator),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        }),
     }
 }
 
 /// Parses the duration designator part of an ISO 8601 duration string. Applied to the case where
-/// no other components have been parsed yet: units of years, months, days, hours, and seconds are
-/// possible.
+/// no other components have been parsed yet: units of years, months, weeks, days, hours, and
+/// seconds are possible. Weeks may not be combined with any other component.
 fn parse_years_duration_designator(
+    input: &str,
     mut string: &str,
     count: i128,
 ) -> Result<Duration, DurationParsingError> {
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
     match duration_designator {
-        'Y' => parse_months_duration(string, Duration::years(count)),
-        'M' => parse_days_duration(string, Duration::months(count)),
-        'D' => parse_hours_duration(string, Duration::days(count)),
-        'H' => parse_minutes_duration(string, Duration::hours(count)),
+        'Y' => parse_months_duration(input, string, component(Duration::years(1), count)?),
+        'M' => parse_days_duration(input, string, component(Duration::months(1), count)?),
+        'W' => {
+            if !string.is_empty() {
+                return Err(DurationParsingError::UnexpectedRemainder {
+                    position: offset(input, string),
+                });
+            }
+            component(Duration::weeks(1), count)
+        }
+        'D' => {
+            // Only after days is the time designator not handled by the next parsing step.
+            if string.starts_with(TIME_DESIGNATOR) {
+                string = string.get(1..).unwrap();
+            }
+            parse_hours_duration(input, string, component(Duration::days(1), count)?)
+        }
+        'H' => parse_minutes_duration(input, string, component(Duration::hours(1), count)?),
         'S' => {
             if !string.is_empty() {
-                return Err(DurationParsingError::UnexpectedRemainder);
+                return Err(DurationParsingError::UnexpectedRemainder {
+                    position: offset(input, string),
+                });
             }
-            Ok(Duration::seconds(count))
+            component(Duration::seconds(1), count)
         }
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        }),
     }
 }
 
@@ -111,6 +310,7 @@ fn parse_years_duration_designator(
 /// parsed.
 #[inline]
 fn parse_months_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
 ) -> Result<Duration, DurationParsingError> {
@@ -118,17 +318,17 @@ fn parse_months_duration(
         return Ok(duration);
     }
 
-    if string.starts_with('T') {
+    if string.starts_with(TIME_DESIGNATOR) {
         string = string.get(1..).unwrap();
-        return parse_hours_duration(string, duration);
+        return parse_hours_duration(input, string, duration);
     }
 
     let (count, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(consumed_bytes..).unwrap();
-    if string.starts_with('.') {
-        parse_months_fractional_duration(string, duration, count)
+    if string.starts_with(FRACTIONAL_SEPARATORS) {
+        parse_months_fractional_duration(input, string, duration, count)
     } else {
-        parse_months_duration_designator(string, duration, count)
+        parse_months_duration_designator(input, string, duration, count)
     }
 }
 
@@ -136,6 +336,7 @@ fn parse_months_duration(
 /// years component has been parsed: units of months, days, hours, and seconds are possible.
 #[inline]
 fn parse_months_fractional_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
@@ -144,70 +345,108 @@ fn parse_months_fractional_duration(
     let (subcount, fractional_digits) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(fractional_digits..).unwrap();
 
-    let denominator = 10i128.pow(fractional_digits.try_into().unwrap());
+    let denominator = u32::try_from(fractional_digits)
+        .ok()
+        .and_then(|digits| 10i128.checked_pow(digits))
+        .ok_or(DurationParsingError::Overflow)?;
     let numerator: i128 = subcount;
 
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
     if !string.is_empty() {
-        return Err(DurationParsingError::UnexpectedRemainder);
+        return Err(DurationParsingError::UnexpectedRemainder {
+            position: offset(input, string),
+        });
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
+        }),
+        'M' => accumulate(
+            duration,
+            fractional_component(Duration::months(1), count, numerator, denominator)?,
+        ),
+        'D' => accumulate(
+            duration,
+            fractional_component(Duration::days(1), count, numerator, denominator)?,
+        ),
+        'H' => accumulate(
+            duration,
+            fractional_component(Duration::hours(1), count, numerator, denominator)?,
+        ),
+        'S' => accumulate(
+            duration,
+            fractional_component(Duration::seconds(1), count, numerator, denominator)?,
+        ),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
         }),
-        'M' => Ok(duration
-            + Duration::months(count)
-            + Duration::months(numerator).div_round(denominator)),
-        'D' => {
-            Ok(duration + Duration::days(count) + Duration::days(numerator).div_round(denominator))
-        }
-        'H' => Ok(duration
-            + Duration::hours(count)
-            + Duration::hours(numerator).div_round(denominator)),
-        'S' => Ok(duration
-            + Duration::seconds(count)
-            + Duration::seconds(numerator).div_round(denominator)),
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
     }
 }
 
 /// Parses the duration designator part of an ISO 8601 duration string. Applied to the case where
 /// the years component has been parsed: units of months, days, hours, and seconds are possible.
 fn parse_months_duration_designator(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
 ) -> Result<Duration, DurationParsingError> {
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
-    if string.starts_with('T') {
+    if string.starts_with(TIME_DESIGNATOR) {
         string = string.get(1..).unwrap();
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
-        'M' => parse_days_duration(string, duration + Duration::months(count)),
-        'D' => parse_hours_duration(string, duration + Duration::days(count)),
-        'H' => parse_minutes_duration(string, duration + Duration::hours(count)),
+        'M' => parse_days_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::months(1), count)?)?,
+        ),
+        'D' => parse_hours_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::days(1), count)?)?,
+        ),
+        'H' => parse_minutes_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::hours(1), count)?)?,
+        ),
         'S' => {
             if !string.is_empty() {
-                return Err(DurationParsingError::UnexpectedRemainder);
+                return Err(DurationParsingError::UnexpectedRemainder {
+                    position: offset(input, string),
+                });
             }
-            Ok(duration + Duration::seconds(count))
+            accumulate(duration, component(Duration::seconds(1), count)?)
         }
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        }),
     }
 }
 
@@ -215,6 +454,7 @@ fn parse_months_duration_designator(
 /// parsed.
 #[inline]
 fn parse_days_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
 ) -> Result<Duration, DurationParsingError> {
@@ -222,17 +462,17 @@ fn parse_days_duration(
         return Ok(duration);
     }
 
-    if string.starts_with('T') {
+    if string.starts_with(TIME_DESIGNATOR) {
         string = string.get(1..).unwrap();
-        return parse_hours_duration(string, duration);
+        return parse_hours_duration(input, string, duration);
     }
 
     let (count, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(consumed_bytes..).unwrap();
-    if string.starts_with('.') {
-        parse_days_fractional_duration(string, duration, count)
+    if string.starts_with(FRACTIONAL_SEPARATORS) {
+        parse_days_fractional_duration(input, string, duration, count)
     } else {
-        parse_days_duration_designator(string, duration, count)
+        parse_days_duration_designator(input, string, duration, count)
     }
 }
 
@@ -240,6 +480,7 @@ fn parse_days_duration(
 /// months component has been parsed: units of days, hours, minutes, and seconds are possible.
 #[inline]
 fn parse_days_fractional_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
@@ -248,70 +489,108 @@ fn parse_days_fractional_duration(
     let (subcount, fractional_digits) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(fractional_digits..).unwrap();
 
-    let denominator = 10i128.pow(fractional_digits.try_into().unwrap());
+    let denominator = u32::try_from(fractional_digits)
+        .ok()
+        .and_then(|digits| 10i128.checked_pow(digits))
+        .ok_or(DurationParsingError::Overflow)?;
     let numerator: i128 = subcount;
 
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
     if !string.is_empty() {
-        return Err(DurationParsingError::UnexpectedRemainder);
+        return Err(DurationParsingError::UnexpectedRemainder {
+            position: offset(input, string),
+        });
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
+        }),
+        'D' => accumulate(
+            duration,
+            fractional_component(Duration::days(1), count, numerator, denominator)?,
+        ),
+        'H' => accumulate(
+            duration,
+            fractional_component(Duration::hours(1), count, numerator, denominator)?,
+        ),
+        'M' => accumulate(
+            duration,
+            fractional_component(Duration::minutes(1), count, numerator, denominator)?,
+        ),
+        'S' => accumulate(
+            duration,
+            fractional_component(Duration::seconds(1), count, numerator, denominator)?,
+        ),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
         }),
-        'D' => {
-            Ok(duration + Duration::days(count) + Duration::days(numerator).div_round(denominator))
-        }
-        'H' => Ok(duration
-            + Duration::hours(count)
-            + Duration::hours(numerator).div_round(denominator)),
-        'M' => Ok(duration
-            + Duration::minutes(count)
-            + Duration::minutes(numerator).div_round(denominator)),
-        'S' => Ok(duration
-            + Duration::seconds(count)
-            + Duration::seconds(numerator).div_round(denominator)),
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
     }
 }
 
 /// Parses the duration designator part of an ISO 8601 duration string. Applied to the case where
 /// the months component has been parsed: units of days, hours, minutes, and seconds are possible.
 fn parse_days_duration_designator(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
 ) -> Result<Duration, DurationParsingError> {
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
-    if string.starts_with('T') {
+    if string.starts_with(TIME_DESIGNATOR) {
         string = string.get(1..).unwrap();
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
-        'D' => parse_hours_duration(string, duration + Duration::days(count)),
-        'H' => parse_minutes_duration(string, duration + Duration::hours(count)),
-        'M' => parse_seconds_duration(string, duration + Duration::minutes(count)),
+        'D' => parse_hours_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::days(1), count)?)?,
+        ),
+        'H' => parse_minutes_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::hours(1), count)?)?,
+        ),
+        'M' => parse_seconds_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::minutes(1), count)?)?,
+        ),
         'S' => {
             if !string.is_empty() {
-                return Err(DurationParsingError::UnexpectedRemainder);
+                return Err(DurationParsingError::UnexpectedRemainder {
+                    position: offset(input, string),
+                });
             }
-            Ok(duration + Duration::seconds(count))
+            accumulate(duration, component(Duration::seconds(1), count)?)
         }
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        }),
     }
 }
 
@@ -319,6 +598,7 @@ fn parse_days_duration_designator(
 /// parsed.
 #[inline]
 fn parse_hours_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
 ) -> Result<Duration, DurationParsingError> {
@@ -328,10 +608,10 @@ fn parse_hours_duration(
 
     let (count, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(consumed_bytes..).unwrap();
-    if string.starts_with('.') {
-        parse_hours_fractional_duration(string, duration, count)
+    if string.starts_with(FRACTIONAL_SEPARATORS) {
+        parse_hours_fractional_duration(input, string, duration, count)
     } else {
-        parse_hours_duration_designator(string, duration, count)
+        parse_hours_duration_designator(input, string, duration, count)
     }
 }
 
@@ -339,6 +619,7 @@ fn parse_hours_duration(
 /// days component has been parsed: hours, minutes, and seconds are possible.
 #[inline]
 fn parse_hours_fractional_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
@@ -347,68 +628,103 @@ fn parse_hours_fractional_duration(
     let (subcount, fractional_digits) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(fractional_digits..).unwrap();
 
-    let denominator = 10i128.pow(fractional_digits.try_into().unwrap());
+    let denominator = u32::try_from(fractional_digits)
+        .ok()
+        .and_then(|digits| 10i128.checked_pow(digits))
+        .ok_or(DurationParsingError::Overflow)?;
     let numerator: i128 = subcount;
 
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
     if !string.is_empty() {
-        return Err(DurationParsingError::UnexpectedRemainder);
+        return Err(DurationParsingError::UnexpectedRemainder {
+            position: offset(input, string),
+        });
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
         'D' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Days,
+            position: designator_position,
+        }),
+        'H' => accumulate(
+            duration,
+            fractional_component(Duration::hours(1), count, numerator, denominator)?,
+        ),
+        'M' => accumulate(
+            duration,
+            fractional_component(Duration::minutes(1), count, numerator, denominator)?,
+        ),
+        'S' => accumulate(
+            duration,
+            fractional_component(Duration::seconds(1), count, numerator, denominator)?,
+        ),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
         }),
-        'H' => Ok(duration
-            + Duration::hours(count)
-            + Duration::hours(numerator).div_round(denominator)),
-        'M' => Ok(duration
-            + Duration::minutes(count)
-            + Duration::minutes(numerator).div_round(denominator)),
-        'S' => Ok(duration
-            + Duration::seconds(count)
-            + Duration::seconds(numerator).div_round(denominator)),
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
     }
 }
 
 /// Parses the duration designator part of an ISO 8601 duration string. Applied to the case where
 /// the days component has been parsed: units of hours, minutes, and seconds are possible.
 fn parse_hours_duration_designator(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
 ) -> Result<Duration, DurationParsingError> {
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
         'D' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Days,
+            position: designator_position,
         }),
-        'H' => parse_minutes_duration(string, duration + Duration::hours(count)),
-        'M' => parse_seconds_duration(string, duration + Duration::minutes(count)),
+        'H' => parse_minutes_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::hours(1), count)?)?,
+        ),
+        'M' => parse_seconds_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::minutes(1), count)?)?,
+        ),
         'S' => {
             if !string.is_empty() {
-                return Err(DurationParsingError::UnexpectedRemainder);
+                return Err(DurationParsingError::UnexpectedRemainder {
+                    position: offset(input, string),
+                });
             }
-            Ok(duration + Duration::seconds(count))
+            accumulate(duration, component(Duration::seconds(1), count)?)
         }
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        }),
     }
 }
 
@@ -416,6 +732,7 @@ fn parse_hours_duration_designator(
 /// parsed.
 #[inline]
 fn parse_minutes_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
 ) -> Result<Duration, DurationParsingError> {
@@ -425,10 +742,10 @@ fn parse_minutes_duration(
 
     let (count, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(consumed_bytes..).unwrap();
-    if string.starts_with('.') {
-        parse_minutes_fractional_duration(string, duration, count)
+    if string.starts_with(FRACTIONAL_SEPARATORS) {
+        parse_minutes_fractional_duration(input, string, duration, count)
     } else {
-        parse_minutes_duration_designator(string, duration, count)
+        parse_minutes_duration_designator(input, string, duration, count)
     }
 }
 
@@ -436,6 +753,7 @@ fn parse_minutes_duration(
 /// hours component has been parsed: minutes and seconds are possible.
 #[inline]
 fn parse_minutes_fractional_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
@@ -444,74 +762,106 @@ fn parse_minutes_fractional_duration(
     let (subcount, fractional_digits) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(fractional_digits..).unwrap();
 
-    let denominator = 10i128.pow(fractional_digits.try_into().unwrap());
+    let denominator = u32::try_from(fractional_digits)
+        .ok()
+        .and_then(|digits| 10i128.checked_pow(digits))
+        .ok_or(DurationParsingError::Overflow)?;
     let numerator: i128 = subcount;
 
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
     if !string.is_empty() {
-        return Err(DurationParsingError::UnexpectedRemainder);
+        return Err(DurationParsingError::UnexpectedRemainder {
+            position: offset(input, string),
+        });
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
         'D' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Days,
+            position: designator_position,
         }),
         'H' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Hours,
+            position: designator_position,
+        }),
+        'M' => accumulate(
+            duration,
+            fractional_component(Duration::minutes(1), count, numerator, denominator)?,
+        ),
+        'S' => accumulate(
+            duration,
+            fractional_component(Duration::seconds(1), count, numerator, denominator)?,
+        ),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
         }),
-        'M' => Ok(duration
-            + Duration::minutes(count)
-            + Duration::minutes(numerator).div_round(denominator)),
-        'S' => Ok(duration
-            + Duration::seconds(count)
-            + Duration::seconds(numerator).div_round(denominator)),
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
     }
 }
 
 /// Parses the duration designator part of an ISO 8601 duration string. Applied to the case where
 /// the hours component has been parsed: units of minutes and seconds are possible.
 fn parse_minutes_duration_designator(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
 ) -> Result<Duration, DurationParsingError> {
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
-    if string.starts_with('T') {
+    if string.starts_with(TIME_DESIGNATOR) {
         string = string.get(1..).unwrap();
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
         'D' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Days,
+            position: designator_position,
         }),
         'H' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Hours,
+            position: designator_position,
         }),
-        'M' => parse_seconds_duration(string, duration + Duration::minutes(count)),
+        'M' => parse_seconds_duration(
+            input,
+            string,
+            accumulate(duration, component(Duration::minutes(1), count)?)?,
+        ),
         'S' => {
             if !string.is_empty() {
-                return Err(DurationParsingError::UnexpectedRemainder);
+                return Err(DurationParsingError::UnexpectedRemainder {
+                    position: offset(input, string),
+                });
             }
-            Ok(duration + Duration::seconds(count))
+            accumulate(duration, component(Duration::seconds(1), count)?)
         }
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        }),
     }
 }
 
@@ -519,6 +869,7 @@ fn parse_minutes_duration_designator(
 /// parsed.
 #[inline]
 fn parse_seconds_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
 ) -> Result<Duration, DurationParsingError> {
@@ -528,10 +879,10 @@ fn parse_seconds_duration(
 
     let (count, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(consumed_bytes..).unwrap();
-    if string.starts_with('.') {
-        parse_seconds_fractional_duration(string, duration, count)
+    if string.starts_with(FRACTIONAL_SEPARATORS) {
+        parse_seconds_fractional_duration(input, string, duration, count)
     } else {
-        parse_seconds_duration_designator(string, duration, count)
+        parse_seconds_duration_designator(input, string, duration, count)
     }
 }
 
@@ -539,6 +890,7 @@ fn parse_seconds_duration(
 /// minutes component has been parsed: only seconds remain.
 #[inline]
 fn parse_seconds_fractional_duration(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
@@ -547,87 +899,122 @@ fn parse_seconds_fractional_duration(
     let (subcount, fractional_digits) = lexical_core::parse_partial(string.as_bytes())?;
     string = string.get(fractional_digits..).unwrap();
 
-    let denominator = 10i128.pow(fractional_digits.try_into().unwrap());
+    let denominator = u32::try_from(fractional_digits)
+        .ok()
+        .and_then(|digits| 10i128.checked_pow(digits))
+        .ok_or(DurationParsingError::Overflow)?;
     let numerator: i128 = subcount;
 
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
     if !string.is_empty() {
-        return Err(DurationParsingError::UnexpectedRemainder);
+        return Err(DurationParsingError::UnexpectedRemainder {
+            position: offset(input, string),
+        });
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
         'D' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Days,
+            position: designator_position,
         }),
         'H' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Hours,
+            position: designator_position,
         }),
         'M' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Minutes,
+            position: designator_position,
+        }),
+        'S' => accumulate(
+            duration,
+            fractional_component(Duration::seconds(1), count, numerator, denominator)?,
+        ),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
         }),
-        'S' => Ok(duration
-            + Duration::seconds(count)
-            + Duration::seconds(numerator).div_round(denominator)),
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
     }
 }
 
 /// Parses the duration designator part of an ISO 8601 duration string. Applied to the case where
 /// the minutes component has been parsed: only units of seconds are possible.
 fn parse_seconds_duration_designator(
+    input: &str,
     mut string: &str,
     duration: Duration,
     count: i128,
 ) -> Result<Duration, DurationParsingError> {
+    let designator_position = offset(input, string);
     let duration_designator = string
         .chars()
         .next()
-        .ok_or(DurationParsingError::ExpectedDurationDesignator)?;
-    string = string.get(1..).unwrap();
+        .map(|designator| designator.to_ascii_uppercase())
+        .ok_or(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        })?;
+    string = &string[duration_designator.len_utf8()..];
 
-    if string.starts_with('T') {
+    if string.starts_with(TIME_DESIGNATOR) {
         string = string.get(1..).unwrap();
     }
 
     match duration_designator {
         'Y' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Years,
+            position: designator_position,
         }),
         'D' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Days,
+            position: designator_position,
         }),
         'H' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Hours,
+            position: designator_position,
         }),
         'M' => Err(DurationParsingError::NonDecreasingDesignators {
             current: DurationDesignator::Minutes,
+            position: designator_position,
         }),
         'S' => {
             if !string.is_empty() {
-                return Err(DurationParsingError::UnexpectedRemainder);
+                return Err(DurationParsingError::UnexpectedRemainder {
+                    position: offset(input, string),
+                });
             }
-            Ok(duration + Duration::seconds(count))
+            accumulate(duration, component(Duration::seconds(1), count)?)
         }
-        _ => Err(DurationParsingError::ExpectedDurationDesignator),
+        _ => Err(DurationParsingError::ExpectedDurationDesignator {
+            position: designator_position,
+        }),
     }
 }
 
+/// Human-readable formats represent a `Duration` by its ISO 8601 duration string, while other
+/// formats use the exact attosecond count, which is more compact.
 #[cfg(feature = "serde")]
 impl serde::Serialize for Duration {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let string = self.to_string();
-        serializer.serialize_str(&string)
+        if serializer.is_human_readable() {
+            let string = self.to_string();
+            serializer.serialize_str(&string)
+        } else {
+            crate::serde_attoseconds::serialize(self, serializer)
+        }
     }
 }
 
@@ -641,18 +1028,152 @@ where
     where
         D: serde::Deserializer<'de>,
     {
-        let string = String::deserialize(deserializer)?;
-        Self::from_str(&string).map_err(serde::de::Error::custom)
+        if deserializer.is_human_readable() {
+            let string = String::deserialize(deserializer)?;
+            Self::from_str(&string).map_err(serde::de::Error::custom)
+        } else {
+            crate::serde_attoseconds::deserialize(deserializer)
+        }
+    }
+}
+
+/// Verifies that serialization of `Duration`s round-trips. Requires only `alloc`, so this test is
+/// also meant to be run without the `std` feature: `cargo test --no-default-features -F serde`.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_roundtrip() {
+    let durations = [
+        Duration::ZERO,
+        Duration::seconds(42),
+        Duration::milliseconds(-1_500),
+        Duration::days(3) + Duration::hours(4) + Duration::attoseconds(5),
+    ];
+    for duration in durations {
+        let string = serde_json::to_string(&duration).unwrap();
+        let deserialized: Duration = serde_json::from_str(&string).unwrap();
+        assert_eq!(duration, deserialized);
+    }
+
+    let deserialized: Duration = serde_json::from_str("\"PT1,5S\"").unwrap();
+    assert_eq!(deserialized, Duration::milliseconds(1500));
+}
+
+/// Verifies that binary formats represent `Duration`s by their exact attosecond count.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_binary_roundtrip() {
+    use num_traits::Bounded;
+    let durations = [
+        Duration::ZERO,
+        Duration::attoseconds(1),
+        Duration::milliseconds(-1_500),
+        Duration::days(3) + Duration::hours(4) + Duration::attoseconds(5),
+        Duration::max_value(),
+        Duration::min_value(),
+    ];
+    for duration in durations {
+        let bytes = postcard::to_allocvec(&duration).unwrap();
+        assert_eq!(bytes, postcard::to_allocvec(&duration.count()).unwrap());
+        let deserialized: Duration = postcard::from_bytes(&bytes).unwrap();
+        assert_eq!(duration, deserialized);
     }
 }
 
-/// The set of duration symbols that are supported when expressing durations as strings.
+/// Verifies that the comma is accepted as decimal separator, and that it is treated in the same
+/// manner as the full stop in all cases.
+#[test]
+fn comma_decimal_separator() {
+    assert_eq!(Duration::from_str("PT1,5S"), Duration::from_str("PT1.5S"));
+    assert_eq!(
+        Duration::from_str("PT1,5S"),
+        Ok(Duration::milliseconds(1500))
+    );
+    assert_eq!(
+        Duration::from_str("P1Y0,25D"),
+        Ok(Duration::years(1) + Duration::hours(6))
+    );
+    assert_eq!(
+        Duration::from_str("P1DT2H0,5M"),
+        Ok(Duration::days(1) + Duration::hours(2) + Duration::seconds(30))
+    );
+    assert_eq!(
+        Duration::from_str("-P0,001S"),
+        Ok(Duration::milliseconds(-1))
+    );
+
+    // Only the last component may be fractional, regardless of the separators used.
+    for string in ["P1.5DT1,5H", "P1,5DT1.5H", "P1,5DT1,5H", "P1.5DT1.5H"] {
+        assert_eq!(
+            Duration::from_str(string),
+            Err(DurationParsingError::UnexpectedRemainder { position: 5 })
+        );
+    }
+    assert_eq!(
+        Duration::from_str("PT1,5.5S"),
+        Duration::from_str("PT1.5,5S")
+    );
+    assert!(Duration::from_str("PT1,5.5S").is_err());
+}
+
+/// Verifies that plain decimal seconds, as used by many data formats, are parsed correctly.
+#[test]
+fn seconds_strings() {
+    assert_eq!(
+        Duration::from_seconds_str("1.5"),
+        Ok(Duration::milliseconds(1500))
+    );
+    assert_eq!(Duration::from_seconds_str("3600"), Ok(Duration::hours(1)));
+    assert_eq!(
+        Duration::from_seconds_str("1e-9"),
+        Ok(Duration::nanoseconds(1))
+    );
+    assert_eq!(
+        Duration::from_seconds_str("-0.000000001"),
+        Ok(Duration::nanoseconds(-1))
+    );
+    assert_eq!(
+        Duration::from_seconds_str("2.5E3"),
+        Ok(Duration::milliseconds(2_500_000))
+    );
+    assert_eq!(
+        Duration::from_seconds_str(".25"),
+        Ok(Duration::milliseconds(250))
+    );
+    assert_eq!(
+        Duration::from_seconds_str("1e+2"),
+        Ok(Duration::seconds(100))
+    );
+
+    // Digits beyond attosecond precision are truncated.
+    assert_eq!(
+        Duration::from_seconds_str("0.0000000000000000019"),
+        Ok(Duration::attoseconds(1))
+    );
+    assert_eq!(Duration::from_seconds_str("1e-19"), Ok(Duration::ZERO));
+
+    assert_eq!(
+        Duration::from_seconds_str("1e100"),
+        Err(DurationParsingError::Overflow)
+    );
+    assert_eq!(
+        Duration::from_seconds_str("1.5s"),
+        Err(DurationParsingError::UnexpectedRemainder { position: 3 })
+    );
+    assert!(Duration::from_seconds_str("").is_err());
+    assert!(Duration::from_seconds_str("-.").is_err());
+    assert!(Duration::from_seconds_str("P1S").is_err());
+}
+
+/// The set of duration symbols that are supported when expressing durations as strings. Marked as
+/// non-exhaustive, such that support for further designators may be added later on.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+#[non_exhaustive]
 pub enum DurationDesignator {
     Seconds,
     Minutes,
     Hours,
     Days,
+    Months,
     Years,
 }
 
@@ -688,6 +1209,15 @@ fn simple_durations() {
     assert_eq!(year, Duration::years(1));
     let years = Duration::from_str("P2000Y").unwrap();
     assert_eq!(years, Duration::years(2000));
+
+    let weeks = Duration::from_str("P52W").unwrap();
+    assert_eq!(weeks, Duration::weeks(52));
+    let weeks = Duration::from_str("P1.5W").unwrap();
+    assert_eq!(weeks, Duration::days(10) + Duration::hours(12));
+    assert_eq!(
+        Duration::from_str("P1W1D"),
+        Err(DurationParsingError::UnexpectedRemainder { position: 3 })
+    );
 }
 
 /// Verifies that simple composite durations can be constructed.
@@ -797,3 +1327,226 @@ fn fractional_durations() {
     let seconds = Duration::from_str("P23H59.5M").unwrap();
     assert_eq!(seconds, Duration::seconds(23 * 3600 + 59 * 60 + 30));
 }
+
+/// Verifies that negative durations may be parsed using a leading minus sign.
+#[test]
+fn negative_durations() {
+    let duration = Duration::from_str("-P1DT2H").unwrap();
+    assert_eq!(duration, -(Duration::days(1) + Duration::hours(2)));
+
+    let duration = Duration::from_str("-PT1.5S").unwrap();
+    assert_eq!(duration, Duration::milliseconds(-1_500));
+}
+
+/// Verifies that components too large to be represented in attoseconds result in an overflow
+/// error, rather than a panic or wrapped result.
+#[test]
+fn component_overflow() {
+    let minutes = "PT1000000000000000000000000000000M";
+    assert_eq!(
+        Duration::from_str(minutes),
+        Err(DurationParsingError::Overflow)
+    );
+    assert_eq!(
+        Duration::from_str("-PT1000000000000000000000000000000M"),
+        Err(DurationParsingError::Overflow)
+    );
+    assert_eq!(
+        Duration::from_str("PT1000000000000000000000000000000.5M"),
+        Err(DurationParsingError::Overflow)
+    );
+    assert_eq!(
+        Duration::from_str("P10000000000000Y"),
+        Err(DurationParsingError::Overflow)
+    );
+
+    // The sum of individually representable components may still overflow.
+    assert_eq!(
+        Duration::from_str("P4000000000000Y1000000000000000D"),
+        Err(DurationParsingError::Overflow)
+    );
+
+    // Large, but representable, components are still accepted.
+    assert_eq!(
+        Duration::from_str("PT1000000000000000000M"),
+        Ok(Duration::minutes(1_000_000_000_000_000_000))
+    );
+}
+
+/// Verifies that parsing errors report the byte position at which parsing failed, and that this
+/// position is included in their error messages.
+#[cfg(feature = "std")]
+#[test]
+fn error_positions() {
+    use crate::DurationDesignator;
+
+    let error = Duration::from_str("P1X").unwrap_err();
+    assert_eq!(
+        error,
+        DurationParsingError::ExpectedDurationDesignator { position: 2 }
+    );
+    assert_eq!(error.to_string(), "expected duration designator at byte 2");
+
+    let error = Duration::from_str("P1DT2H3S4M").unwrap_err();
+    assert_eq!(
+        error,
+        DurationParsingError::UnexpectedRemainder { position: 8 }
+    );
+    assert_eq!(
+        error.to_string(),
+        "could not parse entire string: data remains after duration at byte 8"
+    );
+
+    let error = DurationParsingError::NonDecreasingDesignators {
+        current: DurationDesignator::Years,
+        position: 4,
+    };
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "unit designators must be provided in decreasing order, but found {} at byte 4",
+            DurationDesignator::Years
+        )
+    );
+    assert_eq!(
+        Duration::from_str("1D").unwrap_err().to_string(),
+        "input string did not start with 'P'"
+    );
+}
+
+/// Verifies the positions reported for a range of malformed inputs.
+#[test]
+fn malformed_input_positions() {
+    for (string, position) in [
+        ("P1Y2X", Some(4)),
+        ("-P1Y2X", Some(5)),
+        ("P1", Some(2)),
+        ("PT1.5HT", Some(6)),
+        ("P1DT1S1M", Some(6)),
+        ("P1MT1M1H", Some(7)),
+        ("P1D1Y", Some(4)),
+        ("P1.5D1H", Some(5)),
+        ("1Y", None),
+        ("P1000000000000000000Y", None),
+    ] {
+        let error = Duration::from_str(string).unwrap_err();
+        assert_eq!(error.position(), position, "{string}");
+    }
+
+    assert_eq!(
+        crate::CalendarDuration::from_str("P1Y2X")
+            .unwrap_err()
+            .position(),
+        Some(4)
+    );
+}
+
+/// Verifies that fractional components with more digits than needed for attosecond precision are
+/// parsed without overflowing.
+#[test]
+fn long_fractions() {
+    assert_eq!(
+        Duration::from_str("PT0.123456789012345678H"),
+        Ok(Duration::attoseconds(444_444_440_444_444_440_800))
+    );
+    assert_eq!(
+        Duration::from_str("P0.12345678901234567890123456789012345Y"),
+        Ok(Duration::attoseconds(3_895_919_964_936_719_996_493_672))
+    );
+    assert_eq!(
+        Duration::from_str("PT0.00000000000000000000000000000000001S"),
+        Ok(Duration::ZERO)
+    );
+}
+
+/// Verifies that time components exceeding the size of the next larger unit are converted exactly,
+/// without any rounding in the attosecond representation.
+#[test]
+fn overflowing_sub_units() {
+    for (string, expected) in [
+        ("PT90M", Duration::minutes(90)),
+        ("PT3600S", Duration::hours(1)),
+        ("PT1440M", Duration::days(1)),
+        ("PT86400S", Duration::days(1)),
+        ("PT24H", Duration::days(1)),
+        ("PT168H", Duration::weeks(1)),
+        ("P1DT24H", Duration::days(2)),
+        ("PT1H60M3600S", Duration::hours(3)),
+        ("-PT86400S", Duration::days(-1)),
+        ("PT0.5H", Duration::minutes(30)),
+        ("PT1.5M", Duration::seconds(90)),
+        (
+            "PT86399.999999999999999999S",
+            Duration::days(1) - Duration::ATTOSECOND,
+        ),
+        ("PT1440.5M", Duration::days(1) + Duration::seconds(30)),
+    ] {
+        let duration = Duration::from_str(string).unwrap();
+        assert_eq!(duration, expected, "{string}");
+        assert_eq!(duration.count(), expected.count(), "{string}");
+    }
+    assert_eq!(
+        Duration::from_str("PT90M").unwrap(),
+        Duration::hours(1) + Duration::minutes(30)
+    );
+    assert_eq!(
+        Duration::from_str("PT86400S").unwrap().count(),
+        86_400 * 1_000_000_000_000_000_000
+    );
+}
+
+/// Verifies that durations without any components are rejected, while an explicit zero component
+/// is accepted.
+#[test]
+fn empty_durations() {
+    for string in ["P", "PT", "-P", "-PT", "p", "pt"] {
+        assert_eq!(
+            Duration::from_str(string),
+            Err(DurationParsingError::EmptyDuration),
+            "{string}"
+        );
+    }
+    assert_eq!(Duration::from_str("PT0S"), Ok(Duration::ZERO));
+    assert_eq!(Duration::from_str("P0D"), Ok(Duration::ZERO));
+    assert_eq!(Duration::from_str("-PT0S"), Ok(Duration::ZERO));
+    assert_eq!(
+        crate::CalendarDuration::from_str("PT"),
+        Err(DurationParsingError::EmptyDuration)
+    );
+}
+
+/// Verifies that a zero duration is displayed as `PT0S`, such that it round-trips through parsing.
+#[cfg(feature = "std")]
+#[test]
+fn zero_duration_roundtrip() {
+    assert_eq!(Duration::ZERO.to_string(), "PT0S");
+    assert_eq!(
+        Duration::from_str(&Duration::ZERO.to_string()),
+        Ok(Duration::ZERO)
+    );
+}
+
+/// Verifies that lowercase prefixes and designators are accepted, and that a lowercase `m` is
+/// interpreted as months or minutes depending on its position, like an uppercase `M`.
+#[test]
+fn lowercase_designators() {
+    assert_eq!(
+        Duration::from_str("p1dt2h3m4s"),
+        Duration::from_str("P1DT2H3M4S")
+    );
+    assert_eq!(
+        Duration::from_str("p1dt2h3m4s"),
+        Ok(Duration::days(1) + Duration::hours(2) + Duration::minutes(3) + Duration::seconds(4))
+    );
+    assert_eq!(
+        Duration::from_str("pt1.5s"),
+        Ok(Duration::milliseconds(1_500))
+    );
+    assert_eq!(Duration::from_str("-p0,25d"), Ok(-Duration::hours(6)));
+    assert_eq!(Duration::from_str("p2m"), Ok(Duration::months(2)));
+    assert_eq!(Duration::from_str("pt2m"), Ok(Duration::minutes(2)));
+    assert_eq!(
+        Duration::from_str("P1y2MT3m"),
+        Ok(Duration::years(1) + Duration::months(2) + Duration::minutes(3))
+    );
+}
@@ -0,0 +1,140 @@
+//! Implementation of string parsing logic for ISO 8601 week dates.
+
+use crate::{Date, errors::IsoWeekDateParsingError};
+
+impl Date {
+    /// Parses a `Date` based on an ISO 8601 week date string. Accepts the extended complete week
+    /// date format `YYYY-Www-D` (see ISO 8601 section 5.2.3.1), as well as the reduced format
+    /// `YYYY-Www`, in which case the Monday of that week is returned. As for calendar dates, any
+    /// number of digits is accepted for the year.
+    ///
+    /// # Errors
+    /// Will return an error if the string is not a well-formed week date, or if the parsed
+    /// week does not exist in the given year.
+    pub fn parse_iso_week_date(string: &str) -> Result<Self, IsoWeekDateParsingError> {
+        let (date, remainder) = Self::parse_partial_iso_week_date(string)?;
+        if remainder.is_empty() {
+            Ok(date)
+        } else {
+            Err(IsoWeekDateParsingError::UnexpectedRemainder)
+        }
+    }
+
+    /// Parses a `Date` based on an ISO 8601 week date string, as `parse_iso_week_date`.
+    ///
+    /// On success, returns the resulting `Date` and any remaining input that was not yet parsed.
+    /// On failure, returns a reason indicating why.
+    ///
+    /// # Errors
+    /// Will return an error if the string does not start with a well-formed week date, or if the
+    /// parsed week does not exist in the given year.
+    #[allow(clippy::missing_panics_doc, reason = "Internal error panics only")]
+    pub fn parse_partial_iso_week_date(
+        mut string: &str,
+    ) -> Result<(Self, &str), IsoWeekDateParsingError> {
+        // Parse year component
+        let (year, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
+        string = string.get(consumed_bytes..).unwrap();
+
+        // Parse year-week delimiter
+        if string.starts_with("-W") {
+            string = string.get(2..).unwrap();
+        } else {
+            return Err(IsoWeekDateParsingError::ExpectedYearWeekDelimiter);
+        }
+
+        // Parse week component
+        let (week, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
+        if consumed_bytes != 2 {
+            return Err(IsoWeekDateParsingError::WeekRepresentationNotTwoDigits);
+        }
+        string = string.get(consumed_bytes..).unwrap();
+
+        // Parse optional week day component, defaulting to Monday
+        let mut week_day = 1;
+        if string.starts_with('-') {
+            string = string.get(1..).unwrap();
+            let consumed_bytes;
+            (week_day, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
+            if consumed_bytes != 1 {
+                return Err(IsoWeekDateParsingError::WeekDayRepresentationNotOneDigit);
+            }
+            string = string.get(consumed_bytes..).unwrap();
+        }
+
+        Ok((Self::from_iso_week(year, week, week_day)?, string))
+    }
+}
+
+/// Verifies string parsing for some known week dates, including the reduced format.
+#[test]
+fn known_week_dates() {
+    use crate::Month::*;
+    let date = |year, month, day| Date::from_gregorian_date(year, month, day).unwrap();
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W01-1"),
+        Ok(date(2024, January, 1))
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2026-W01"),
+        Ok(date(2025, December, 29))
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2020-W53-7"),
+        Ok(date(2021, January, 3))
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2009-W01-1"),
+        Ok(date(2008, December, 29))
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W05-3"),
+        Ok(date(2024, January, 31))
+    );
+}
+
+/// Verifies that malformed or non-existent week dates are rejected.
+#[test]
+fn invalid_week_dates() {
+    use crate::errors::InvalidIsoWeek;
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W53-7"),
+        Err(IsoWeekDateParsingError::InvalidIsoWeek(InvalidIsoWeek {
+            year: 2024,
+            week: 53,
+            week_day: 7
+        }))
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W00-1"),
+        Err(IsoWeekDateParsingError::InvalidIsoWeek(InvalidIsoWeek {
+            year: 2024,
+            week: 0,
+            week_day: 1
+        }))
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W01-8"),
+        Err(IsoWeekDateParsingError::InvalidIsoWeek(InvalidIsoWeek {
+            year: 2024,
+            week: 1,
+            week_day: 8
+        }))
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2024-01-01"),
+        Err(IsoWeekDateParsingError::ExpectedYearWeekDelimiter)
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W1-1"),
+        Err(IsoWeekDateParsingError::WeekRepresentationNotTwoDigits)
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W01-12"),
+        Err(IsoWeekDateParsingError::WeekDayRepresentationNotOneDigit)
+    );
+    assert_eq!(
+        Date::parse_iso_week_date("2024-W01-1T"),
+        Err(IsoWeekDateParsingError::UnexpectedRemainder)
+    );
+}
@@ -3,10 +3,14 @@
 //!
 //! Primarily, a subset of ISO 8601 is supported.
 
+mod calendar_duration;
 mod duration;
 mod gregorian_date;
 mod historic_date;
+mod iso_week_date;
 mod julian_date;
+mod modified_julian_date;
+mod rfc3339;
 pub use duration::*;
 mod time_of_day;
 pub use time_of_day::*;
@@ -0,0 +1,43 @@
+//! Implementation of string parsing logic for `ModifiedJulianDate` types.
+
+use core::str::FromStr;
+
+use crate::{Days, ModifiedJulianDate, errors::ModifiedJulianDateParsingError};
+
+impl FromStr for ModifiedJulianDate {
+    type Err = ModifiedJulianDateParsingError;
+
+    /// Parses a `ModifiedJulianDate` from its integer day count, optionally prefixed with `MJD `,
+    /// as produced by its `Display` implementation: both `51544` and `MJD 51544` are accepted.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.strip_prefix("MJD ").unwrap_or(string);
+        let (days, consumed_bytes) = lexical_core::parse_partial::<i32>(string.as_bytes())?;
+        if consumed_bytes != string.len() {
+            return Err(ModifiedJulianDateParsingError::UnexpectedRemainder);
+        }
+        Ok(Self::from_time_since_epoch(Days::new(days)))
+    }
+}
+
+/// Verifies that modified Julian dates round-trip through their string representation.
+#[cfg(feature = "std")]
+#[test]
+fn roundtrip() {
+    use crate::Month;
+    let mjd = ModifiedJulianDate::from_historic_date(2000, Month::January, 1).unwrap();
+    assert_eq!(mjd.to_string(), "MJD 51544");
+    assert_eq!(ModifiedJulianDate::from_str("MJD 51544"), Ok(mjd));
+    assert_eq!(ModifiedJulianDate::from_str("51544"), Ok(mjd));
+    assert_eq!(ModifiedJulianDate::from_str(&mjd.to_string()), Ok(mjd));
+
+    let before_epoch = ModifiedJulianDate::from_time_since_epoch(Days::new(-1));
+    assert_eq!(before_epoch.to_string(), "MJD -1");
+    assert_eq!(ModifiedJulianDate::from_str("MJD -1"), Ok(before_epoch));
+
+    assert_eq!(
+        ModifiedJulianDate::from_str("51544.5"),
+        Err(ModifiedJulianDateParsingError::UnexpectedRemainder)
+    );
+    assert!(ModifiedJulianDate::from_str("MJD").is_err());
+    assert!(ModifiedJulianDate::from_str("JD 51544").is_err());
+}
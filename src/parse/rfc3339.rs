@@ -0,0 +1,340 @@
+//! Implementation of parsing and formatting logic for RFC 3339 timestamps, as commonly used in
+//! internet protocols. Unlike the ISO 8601 representation used for `TimePoint`s, these timestamps
+//! carry a UTC offset rather than a time scale designator.
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+
+use num_traits::Zero;
+
+use crate::{
+    Date, Days, Duration, FromFineDateTime, GregorianDate, IntoDateTime, IntoFineDateTime, UtcTime,
+    errors::{InvalidTimeOfDay, InvalidUtcDateTime, Rfc3339ParsingError},
+    parse::TimeOfDay,
+};
+#[cfg(feature = "alloc")]
+use crate::{OffsetTime, errors::InvalidUtcOffset};
+
+impl UtcTime {
+    /// Parses a `UtcTime` from an RFC 3339 timestamp, like `2024-01-02T03:04:05.678+02:00`. The
+    /// offset may be given as `Z` (or `z`) or as `+HH:MM`/`-HH:MM`, and is subtracted from the
+    /// wall-clock time to obtain the UTC time point. Any number of fractional second digits is
+    /// accepted. Leap seconds may be written as `23:59:60` (shifted by the offset, if any), but
+    /// only where one was actually inserted.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid RFC 3339 timestamp, or if it refers to a
+    /// date-time that does not exist in UTC.
+    pub fn parse_rfc3339(string: &str) -> Result<Self, Rfc3339ParsingError> {
+        let (date, string) = GregorianDate::parse_partial(string)?;
+        let string = string
+            .strip_prefix(['T', 't'])
+            .ok_or(Rfc3339ParsingError::ExpectedTimeDesignator)?;
+        let (time_of_day, string) = TimeOfDay::parse_partial(string)?;
+        let offset_minutes = parse_offset(string)?;
+
+        let TimeOfDay {
+            hour,
+            minute,
+            second,
+            subseconds,
+        } = time_of_day;
+        Ok(Self::from_local_fine_datetime(
+            date.into_date(),
+            hour,
+            minute,
+            second,
+            subseconds,
+            offset_minutes,
+        )?)
+    }
+
+    /// Converts a local wall-clock date-time, at the given offset from UTC (in minutes), into the
+    /// corresponding UTC time point. Leap seconds may be expressed in local time as well, but only
+    /// where one was actually inserted in UTC.
+    ///
+    /// # Errors
+    /// Returns an error if the time-of-day is invalid, or if the resulting UTC date-time does not
+    /// exist.
+    pub(crate) fn from_local_fine_datetime(
+        date: Date,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        subseconds: Duration,
+        offset_minutes: i32,
+    ) -> Result<Self, InvalidUtcDateTime> {
+        if hour > 23 || minute > 59 || second > 60 {
+            return Err(InvalidUtcDateTime::InvalidTimeOfDay(InvalidTimeOfDay {
+                hour,
+                minute,
+                second,
+            }));
+        }
+
+        // The offset is always a whole number of minutes, so it may be applied to the hour and
+        // minute only. This keeps leap seconds (second 60) intact.
+        let (date, hour, minute) = shift_wall_clock(date, hour, minute, -offset_minutes);
+
+        // Leap seconds may only be inserted at the end of a UTC day.
+        if second == 60 && (hour, minute) != (23, 59) {
+            return Err(InvalidUtcDateTime::NonLeapSecondDateTime {
+                date,
+                hour,
+                minute,
+                second,
+            });
+        }
+
+        Self::from_fine_datetime(date, hour, minute, second, subseconds)
+    }
+
+    /// Returns the local wall-clock date-time of this time point, at the given offset from UTC
+    /// (in minutes). Leap seconds are retained as second 60.
+    pub(crate) fn into_local_datetime(self, offset_minutes: i32) -> (Date, u8, u8, u8) {
+        let (date, hour, minute, second) = self.into_datetime();
+        let (date, hour, minute) = shift_wall_clock(date, hour, minute, offset_minutes);
+        (date, hour, minute, second)
+    }
+
+    /// Writes this time point as an RFC 3339 timestamp. If no offset (in minutes) is given, the
+    /// timestamp is written in UTC with a `Z` suffix.
+    pub(crate) fn write_rfc3339(
+        &self,
+        writer: &mut impl Write,
+        offset_minutes: Option<i32>,
+    ) -> core::fmt::Result {
+        let (date, hour, minute, second, subseconds) = self.into_fine_datetime();
+        let (date, hour, minute) =
+            shift_wall_clock(date, hour, minute, offset_minutes.unwrap_or(0));
+        let date = GregorianDate::from_date(date);
+        write!(
+            writer,
+            "{:04}-{:02}-{:02}T{hour:02}:{minute:02}:{second:02}",
+            date.year(),
+            date.month() as u8,
+            date.day(),
+        )?;
+
+        if !subseconds.is_zero() {
+            writer.write_char('.')?;
+            for digit in subseconds.decimal_digits(None) {
+                write!(writer, "{digit}")?;
+            }
+        }
+
+        match offset_minutes {
+            None => writer.write_char('Z'),
+            Some(offset_minutes) => {
+                let sign = if offset_minutes < 0 { '-' } else { '+' };
+                let offset_minutes = offset_minutes.unsigned_abs();
+                write!(
+                    writer,
+                    "{sign}{:02}:{:02}",
+                    offset_minutes / 60,
+                    offset_minutes % 60
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl UtcTime {
+    /// Formats this time point as an RFC 3339 timestamp in UTC, like `2024-01-02T03:04:05.678Z`.
+    /// Fractional seconds are printed only as far as needed to represent the time point exactly.
+    /// During a leap second, the seconds are rendered as `60`.
+    #[must_use]
+    pub fn to_rfc3339(&self) -> String {
+        let mut string = String::new();
+        let Ok(()) = self.write_rfc3339(&mut string, None) else {
+            unreachable!()
+        };
+        string
+    }
+
+    /// Formats this time point as an RFC 3339 timestamp with the given UTC offset, like
+    /// `2024-01-02T08:49:05+05:45`. The displayed wall-clock time is shifted by the offset.
+    ///
+    /// # Errors
+    /// Returns an error if the offset is not a whole number of minutes, or if it exceeds the
+    /// range of -23:59 to +23:59 permitted by RFC 3339.
+    pub fn to_rfc3339_with_offset(&self, offset: Duration) -> Result<String, InvalidUtcOffset> {
+        Ok(OffsetTime::new(*self, offset)?.to_string())
+    }
+}
+
+/// Shifts the given wall-clock date, hour, and minute by some number of minutes. Seconds are not
+/// affected, such that leap seconds remain intact.
+fn shift_wall_clock(date: Date, hour: u8, minute: u8, offset_minutes: i32) -> (Date, u8, u8) {
+    let minutes_in_day = i32::from(hour) * 60 + i32::from(minute) + offset_minutes;
+    let date = date + Days::new(minutes_in_day.div_euclid(24 * 60));
+    let minutes_in_day = minutes_in_day.rem_euclid(24 * 60);
+    let (Ok(hour), Ok(minute)) = (
+        (minutes_in_day / 60).try_into(),
+        (minutes_in_day % 60).try_into(),
+    ) else {
+        unreachable!()
+    };
+    (date, hour, minute)
+}
+
+/// Parses the UTC offset of an RFC 3339 timestamp, which must form the remainder of the string.
+/// Returns the offset in minutes.
+fn parse_offset(string: &str) -> Result<i32, Rfc3339ParsingError> {
+    if let Some(string) = string.strip_prefix(['Z', 'z']) {
+        return if string.is_empty() {
+            Ok(0)
+        } else {
+            Err(Rfc3339ParsingError::UnexpectedRemainder)
+        };
+    }
+
+    let is_negative = string.starts_with('-');
+    let string = string
+        .strip_prefix(['+', '-'])
+        .ok_or(Rfc3339ParsingError::ExpectedOffset)?;
+    let (hours, string) = parse_two_digits(string)?;
+    let string = string
+        .strip_prefix(':')
+        .ok_or(Rfc3339ParsingError::InvalidOffset)?;
+    let (minutes, string) = parse_two_digits(string)?;
+    if hours > 23 || minutes > 59 {
+        return Err(Rfc3339ParsingError::InvalidOffset);
+    }
+
+    if !string.is_empty() {
+        return Err(Rfc3339ParsingError::UnexpectedRemainder);
+    }
+
+    let offset = i32::from(hours) * 60 + i32::from(minutes);
+    Ok(if is_negative { -offset } else { offset })
+}
+
+/// Parses exactly two decimal digits at the start of the string.
+fn parse_two_digits(string: &str) -> Result<(u8, &str), Rfc3339ParsingError> {
+    let digits = string.get(..2).ok_or(Rfc3339ParsingError::InvalidOffset)?;
+    if !digits.bytes().all(|digit| digit.is_ascii_digit()) {
+        return Err(Rfc3339ParsingError::InvalidOffset);
+    }
+    let Ok(value) = lexical_core::parse(digits.as_bytes()) else {
+        unreachable!()
+    };
+    Ok((value, string.get(2..).unwrap_or_default()))
+}
+
+/// Verifies that RFC 3339 timestamps are parsed correctly for a variety of offsets.
+#[test]
+fn rfc3339_offsets() {
+    use crate::{Duration, Month};
+    let utc = UtcTime::from_fine_historic_datetime(
+        2024,
+        Month::January,
+        2,
+        3,
+        4,
+        5,
+        Duration::milliseconds(678),
+    )
+    .unwrap();
+    assert_eq!(UtcTime::parse_rfc3339("2024-01-02T03:04:05.678Z"), Ok(utc));
+    assert_eq!(
+        UtcTime::parse_rfc3339("2024-01-02T05:04:05.678+02:00"),
+        Ok(utc)
+    );
+    assert_eq!(
+        UtcTime::parse_rfc3339("2024-01-01T21:34:05.678-05:30"),
+        Ok(utc)
+    );
+    assert_eq!(
+        UtcTime::parse_rfc3339("2024-01-02t03:04:05.678000000000000000000000000000000000000000z"),
+        Ok(utc)
+    );
+    assert_eq!(
+        UtcTime::parse_rfc3339("2024-01-02T03:04:05.000000000000000001Z"),
+        Ok(utc - Duration::milliseconds(678) + Duration::attoseconds(1))
+    );
+
+    assert_eq!(
+        UtcTime::parse_rfc3339("2024-01-02T03:04:05"),
+        Err(Rfc3339ParsingError::ExpectedOffset)
+    );
+    assert_eq!(
+        UtcTime::parse_rfc3339("2024-01-02T03:04:05+2:00"),
+        Err(Rfc3339ParsingError::InvalidOffset)
+    );
+    assert_eq!(
+        UtcTime::parse_rfc3339("2024-01-02T03:04:05Z UTC"),
+        Err(Rfc3339ParsingError::UnexpectedRemainder)
+    );
+    assert!(UtcTime::parse_rfc3339("2024-01-02T24:04:05Z").is_err());
+}
+
+/// Verifies that leap seconds are accepted in RFC 3339 timestamps, also when they are written
+/// with a non-zero offset, but only where they actually occurred.
+#[test]
+fn rfc3339_leap_second() {
+    use crate::{Duration, Month};
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert_eq!(
+        UtcTime::parse_rfc3339("2016-12-31T23:59:60Z"),
+        Ok(leap_second)
+    );
+    assert_eq!(
+        UtcTime::parse_rfc3339("2017-01-01T01:59:60.5+02:00"),
+        Ok(leap_second + Duration::milliseconds(500))
+    );
+    assert_eq!(
+        UtcTime::parse_rfc3339("2016-12-31T18:59:60-05:00"),
+        Ok(leap_second)
+    );
+    assert!(UtcTime::parse_rfc3339("2016-12-31T23:59:60+02:00").is_err());
+    assert!(UtcTime::parse_rfc3339("2016-06-30T23:59:60Z").is_err());
+}
+
+/// Verifies that RFC 3339 formatting results in the expected strings, and that these round-trip
+/// through parsing.
+#[cfg(feature = "alloc")]
+#[test]
+fn rfc3339_formatting() {
+    use crate::Month;
+    let midnight = UtcTime::from_historic_datetime(2024, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(midnight.to_rfc3339(), "2024-01-01T00:00:00Z");
+    assert_eq!(
+        midnight.to_rfc3339_with_offset(Duration::ZERO).unwrap(),
+        "2024-01-01T00:00:00+00:00"
+    );
+
+    let nepal = Duration::hours(5) + Duration::minutes(45);
+    let time = midnight - Duration::hours(3) + Duration::milliseconds(250);
+    let string = time.to_rfc3339_with_offset(nepal).unwrap();
+    assert_eq!(string, "2024-01-01T02:45:00.25+05:45");
+    assert_eq!(UtcTime::parse_rfc3339(&string), Ok(time));
+    let string = time.to_rfc3339_with_offset(-nepal).unwrap();
+    assert_eq!(string, "2023-12-31T15:15:00.25-05:45");
+    assert_eq!(UtcTime::parse_rfc3339(&string), Ok(time));
+
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert_eq!(leap_second.to_rfc3339(), "2016-12-31T23:59:60Z");
+    assert_eq!(
+        leap_second
+            .to_rfc3339_with_offset(Duration::hours(2))
+            .unwrap(),
+        "2017-01-01T01:59:60+02:00"
+    );
+
+    assert_eq!(
+        midnight.to_rfc3339_with_offset(Duration::seconds(30)),
+        Err(InvalidUtcOffset {
+            offset: Duration::seconds(30)
+        })
+    );
+    assert!(
+        midnight
+            .to_rfc3339_with_offset(Duration::hours(24))
+            .is_err()
+    );
+}
@@ -1,8 +1,6 @@
 //! Implementation of parsing for time-of-day. Also incorporates a helper struct that wraps such
 //! combinations of hour, minute, and second.
 
-use num_traits::ConstZero;
-
 use crate::{Duration, errors::TimeOfDayParsingError};
 
 /// Time-of-day
@@ -64,9 +62,15 @@ impl TimeOfDay {
         // Then, we parse the fractional remainder, if any
         let subseconds = if string.starts_with('.') {
             string = string.get(1..).unwrap();
-            let (fraction, fractional_digits) = lexical_core::parse_partial(string.as_bytes())?;
+            let fractional_digits = string.bytes().take_while(u8::is_ascii_digit).count();
+            // Any number of fractional digits is accepted, but only the first 19 are used: beyond
+            // that, digits can no longer affect the value at attosecond precision, other than in
+            // exact rounding ties. This also prevents overflow for very long fractions.
+            let significant_digits = fractional_digits.min(19);
+            let fraction: i128 =
+                lexical_core::parse(string.get(..significant_digits).unwrap().as_bytes())?;
             string = string.get(fractional_digits..).unwrap();
-            let denominator = 10i128.pow(fractional_digits.try_into().unwrap());
+            let denominator = 10i128.pow(significant_digits.try_into().unwrap());
             Duration::seconds(fraction).div_round(denominator)
         } else {
             Duration::ZERO
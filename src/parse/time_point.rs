@@ -2,7 +2,10 @@
 
 use core::str::FromStr;
 
-#[cfg(test)]
+#[cfg(feature = "serde")]
+use alloc::string::{String, ToString};
+
+#[cfg(any(test, feature = "serde"))]
 use crate::Duration;
 use crate::{
     FromFineDateTime, HistoricDate, TimePoint, errors::TimePointParsingError, parse::TimeOfDay,
@@ -67,18 +70,29 @@ where
     }
 }
 
+/// Human-readable formats represent a `TimePoint` by its date-time string. Other formats use a
+/// tuple of the time scale abbreviation and the exact attosecond count since the scale epoch, which
+/// does not lose precision beyond the digits printed by the formatter.
 #[cfg(feature = "serde")]
 impl<Scale> serde::Serialize for TimePoint<Scale>
 where
     Self: ToString,
-    Scale: ?Sized,
+    Scale: ?Sized + TimeScale,
 {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let string = self.to_string();
-        serializer.serialize_str(&string)
+        use serde::ser::SerializeTuple;
+        if serializer.is_human_readable() {
+            let string = self.to_string();
+            serializer.serialize_str(&string)
+        } else {
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(Scale::ABBREVIATION)?;
+            tuple.serialize_element(&self.time_since_epoch().count())?;
+            tuple.end()
+        }
     }
 }
 
@@ -87,14 +101,25 @@ impl<'de, Scale> serde::Deserialize<'de> for TimePoint<Scale>
 where
     Self: FromStr,
     <Self as FromStr>::Err: core::fmt::Display,
-    Scale: ?Sized,
+    Scale: ?Sized + TimeScale,
 {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        let string = String::deserialize(deserializer)?;
-        Self::from_str(&string).map_err(serde::de::Error::custom)
+        if deserializer.is_human_readable() {
+            let string = String::deserialize(deserializer)?;
+            Self::from_str(&string).map_err(serde::de::Error::custom)
+        } else {
+            let (abbreviation, count) = <(String, i128)>::deserialize(deserializer)?;
+            if abbreviation != Scale::ABBREVIATION {
+                return Err(serde::de::Error::invalid_value(
+                    serde::de::Unexpected::Str(&abbreviation),
+                    &Scale::ABBREVIATION,
+                ));
+            }
+            Ok(Self::from_time_since_epoch(Duration::attoseconds(count)))
+        }
     }
 }
 
@@ -127,8 +152,6 @@ fn check_historic_datetime(
 #[test]
 #[allow(clippy::too_many_lines, reason = "Large number of test values")]
 fn known_timestamps() {
-    use num_traits::ConstZero;
-
     use crate::Month::*;
 
     check_historic_datetime(
@@ -232,3 +255,38 @@ fn known_timestamps() {
         Duration::ZERO,
     );
 }
+
+/// Verifies that human-readable formats use the date-time string, while binary formats preserve
+/// the exact attosecond count and reject time points of other time scales.
+#[cfg(feature = "serde")]
+#[test]
+fn serde_roundtrip() {
+    use crate::{GpsTime, TaiTime};
+    let time_point = TaiTime::from_fine_historic_datetime(
+        2024,
+        crate::Month::March,
+        14,
+        1,
+        59,
+        26,
+        Duration::attoseconds(535_897_932_384_626_433),
+    )
+    .unwrap();
+
+    let string = serde_json::to_string(&time_point).unwrap();
+    assert_eq!(string, "\"2024-03-14T01:59:26.535897932384626433 TAI\"");
+    let deserialized: TaiTime = serde_json::from_str(&string).unwrap();
+    assert_eq!(deserialized, time_point);
+
+    let bytes = postcard::to_allocvec(&time_point).unwrap();
+    let deserialized: TaiTime = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized, time_point);
+    assert_eq!(
+        deserialized.time_since_epoch().count(),
+        time_point.time_since_epoch().count()
+    );
+
+    let gps_time = GpsTime::from_time_since_epoch(Duration::seconds(1));
+    let bytes = postcard::to_allocvec(&gps_time).unwrap();
+    assert!(postcard::from_bytes::<TaiTime>(&bytes).is_err());
+}
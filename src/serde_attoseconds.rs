@@ -0,0 +1,59 @@
+//! Serialization of `Duration`s as their exact attosecond count, for use with
+//! `#[serde(with = "attotime::serde_attoseconds")]`.
+//!
+//! By default, `Duration`s are serialized as ISO 8601 duration strings for human-readable formats
+//! and as attosecond counts otherwise. This module forces the attosecond count for all formats,
+//! which preserves the exact value in a compact representation, also in human-readable formats.
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::Duration;
+
+/// Serializes a `Duration` as its count of attoseconds, an `i128`.
+///
+/// # Errors
+/// Will return an error if the serializer does not support 128-bit integers.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_i128(duration.count())
+}
+
+/// Deserializes a `Duration` from its count of attoseconds, an `i128`.
+///
+/// # Errors
+/// Will return an error if the input does not contain an integer that fits in an `i128`.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    i128::deserialize(deserializer).map(Duration::attoseconds)
+}
+
+/// Verifies that the attosecond representation may be selected for human-readable formats.
+#[test]
+fn serde_with_attoseconds() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Measurement {
+        #[serde(with = "crate::serde_attoseconds")]
+        elapsed: Duration,
+        default: Duration,
+    }
+
+    let measurement = Measurement {
+        elapsed: Duration::seconds(1) + Duration::attoseconds(1),
+        default: Duration::seconds(1),
+    };
+    let string = serde_json::to_string(&measurement).unwrap();
+    assert_eq!(
+        string,
+        r#"{"elapsed":1000000000000000001,"default":"PT1S"}"#
+    );
+    let deserialized: Measurement = serde_json::from_str(&string).unwrap();
+    assert_eq!(deserialized, measurement);
+
+    let bytes = postcard::to_allocvec(&measurement).unwrap();
+    let deserialized: Measurement = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized, measurement);
+}
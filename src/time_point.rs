@@ -12,8 +12,11 @@ use num_traits::{Bounded, Zero};
 use crate::{
     Date, Days, Duration, FromDateTime, FromFineDateTime, FromTimeScale, GregorianDate,
     HistoricDate, IntoDateTime, IntoFineDateTime, JulianDate, ModifiedJulianDate, Month, Second,
-    TerrestrialTime, UnitRatio, Utc,
-    errors::{InvalidGregorianDateTime, InvalidHistoricDateTime, InvalidJulianDateTime},
+    SecondsPerDay, TerrestrialTime, UnitRatio, Utc,
+    errors::{
+        FloatDurationError, InvalidGregorianDateTime, InvalidHistoricDateTime,
+        InvalidJulianDateTime,
+    },
     time_scale::{AbsoluteTimeScale, TimeScale, UniformDateTimeScale},
 };
 
@@ -37,6 +40,13 @@ impl<Scale: ?Sized> TimePoint<Scale> {
         }
     }
 
+    /// Returns the epoch of the time scale associated with this type: the instant at which the
+    /// time since epoch is zero.
+    #[must_use]
+    pub const fn epoch() -> Self {
+        Self::from_time_since_epoch(Duration::ZERO)
+    }
+
     /// Returns the time elapsed since the epoch of the time scale associated with this instant.
     #[must_use]
     pub const fn time_since_epoch(&self) -> Duration {
@@ -49,7 +59,89 @@ impl<Scale: ?Sized> TimePoint<Scale> {
         self.time_since_epoch().count()
     }
 
-    /// Converts towards a different time unit, rounding towards the nearest whole unit.
+    /// Returns the non-negative time elapsed between two time points, regardless of their order.
+    /// Saturates at `Duration::max_value()` if the difference cannot be represented.
+    #[must_use]
+    pub fn abs_diff(self, other: Self) -> Duration {
+        self.time_since_epoch.abs_diff(other.time_since_epoch)
+    }
+
+    /// Returns the time elapsed since `earlier`, or `Duration::ZERO` if `earlier` lies after this
+    /// time point, mirroring `std::time::Instant::saturating_duration_since`. Useful for "time
+    /// remaining" computations that should never go negative. Saturates at `Duration::max_value()`
+    /// if the difference cannot be represented.
+    #[must_use]
+    pub fn saturating_duration_since(self, earlier: Self) -> Duration {
+        if self.time_since_epoch <= earlier.time_since_epoch {
+            Duration::ZERO
+        } else {
+            self.abs_diff(earlier)
+        }
+    }
+
+    /// Returns whether two time points are separated by at most `tolerance`. Useful to compare the
+    /// results of approximate conversions, like those to and from TDB, TCG, or TCB, which may be
+    /// off by some attoseconds due to rounding.
+    #[must_use]
+    pub fn approx_eq(self, other: Self, tolerance: Duration) -> bool {
+        self.time_since_epoch
+            .approx_eq(other.time_since_epoch, tolerance)
+    }
+
+    /// Shifts this time point forward by the given duration, returning `None` if the result is not
+    /// representable.
+    #[must_use]
+    pub const fn checked_add(self, duration: Duration) -> Option<Self> {
+        match self.time_since_epoch.checked_add(duration) {
+            Some(time_since_epoch) => Some(Self::from_time_since_epoch(time_since_epoch)),
+            None => None,
+        }
+    }
+
+    /// Shifts this time point backward by the given duration, returning `None` if the result is
+    /// not representable.
+    #[must_use]
+    pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
+        match self.time_since_epoch.checked_sub(duration) {
+            Some(time_since_epoch) => Some(Self::from_time_since_epoch(time_since_epoch)),
+            None => None,
+        }
+    }
+
+    /// Shifts this time point forward by a number of days, each exactly 86400 seconds long.
+    /// Returns `None` if the result is not representable.
+    #[must_use]
+    pub const fn checked_add_days(self, days: Days) -> Option<Self> {
+        self.checked_add(days.into_duration())
+    }
+
+    /// Shifts this time point backward by a number of days, each exactly 86400 seconds long.
+    /// Returns `None` if the result is not representable.
+    #[must_use]
+    pub const fn checked_sub_days(self, days: Days) -> Option<Self> {
+        self.checked_sub(days.into_duration())
+    }
+
+    /// Shifts this time point forward by the given duration, saturating at
+    /// `TimePoint::min_value()` or `TimePoint::max_value()` if the result is not representable.
+    #[must_use]
+    pub const fn saturating_add(self, duration: Duration) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch.saturating_add(duration))
+    }
+
+    /// Shifts this time point backward by the given duration, saturating at
+    /// `TimePoint::min_value()` or `TimePoint::max_value()` if the result is not representable.
+    #[must_use]
+    pub const fn saturating_sub(self, duration: Duration) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch.saturating_sub(duration))
+    }
+
+    /// Converts towards a different time unit, rounding towards the nearest whole unit. Ties are
+    /// rounded towards positive infinity.
+    ///
+    /// # Panics
+    /// Panics if the rounded time point is not representable, which may only happen within half
+    /// a unit of `TimePoint::min_value()` or `TimePoint::max_value()`.
     #[must_use]
     pub const fn round<Target>(self) -> Self
     where
@@ -60,8 +152,12 @@ impl<Scale: ?Sized> TimePoint<Scale> {
 
     /// Converts towards a different time unit, rounding towards positive infinity if the unit is
     /// not entirely commensurate with the present unit.
+    ///
+    /// # Panics
+    /// Panics if the rounded time point is not representable, which may only happen within one
+    /// unit of `TimePoint::max_value()`.
     #[must_use]
-    pub fn ceil<Target>(self) -> Self
+    pub const fn ceil<Target>(self) -> Self
     where
         Target: UnitRatio,
     {
@@ -70,14 +166,103 @@ impl<Scale: ?Sized> TimePoint<Scale> {
 
     /// Converts towards a different time unit, rounding towards negative infinity if the unit is
     /// not entirely commensurate with the present unit.
+    ///
+    /// # Panics
+    /// Panics if the rounded time point is not representable, which may only happen within one
+    /// unit of `TimePoint::min_value()`.
     #[must_use]
-    pub fn floor<Target>(self) -> Self
+    pub const fn floor<Target>(self) -> Self
     where
         Target: UnitRatio,
     {
         Self::from_time_since_epoch(self.time_since_epoch.floor::<Target>())
     }
 
+    /// Returns the number of whole `Unit`s elapsed since `earlier`, truncated towards zero. If
+    /// `earlier` lies after `self`, the result is negative.
+    #[must_use]
+    pub fn whole_units_since<Unit>(self, earlier: Self) -> i128
+    where
+        Unit: UnitRatio + ?Sized,
+    {
+        let (whole_units, _) = (self - earlier).factor_out::<Unit>();
+        whole_units
+    }
+
+    /// Returns the number of whole seconds elapsed since `earlier`, truncated towards zero.
+    #[must_use]
+    pub fn whole_seconds_since(self, earlier: Self) -> i128 {
+        self.whole_units_since::<Second>(earlier)
+    }
+
+    /// Returns the number of whole days (of 86400 seconds) elapsed since `earlier`, truncated
+    /// towards zero.
+    #[must_use]
+    pub fn whole_days_since(self, earlier: Self) -> i128 {
+        self.whole_units_since::<SecondsPerDay>(earlier)
+    }
+
+    /// Returns the time elapsed since the epoch of this time point's time scale, expressed as a
+    /// whole number of `Unit`s, truncated towards zero.
+    #[must_use]
+    pub fn since_epoch_units<Unit>(&self) -> i128
+    where
+        Unit: UnitRatio + ?Sized,
+    {
+        let (whole_units, _) = self.time_since_epoch.factor_out::<Unit>();
+        whole_units
+    }
+
+    /// Returns the time elapsed since the epoch of this time point's time scale, expressed as a
+    /// floating point number of `Unit`s.
+    #[must_use]
+    pub fn since_epoch_units_f64<Unit>(&self) -> f64
+    where
+        Unit: UnitRatio,
+    {
+        self.time_since_epoch.as_float::<f64, Unit>()
+    }
+
+    /// Rounds this time point to the nearest integer multiple of `granularity` since the epoch of
+    /// its time scale, with ties rounded towards the future. Useful for snapping to arbitrary
+    /// intervals, like 15 minutes or 100 milliseconds. A zero `granularity` returns the time point
+    /// unchanged.
+    ///
+    /// # Panics
+    /// Panics if the rounded time since epoch is not representable, as for `Duration::round_to`.
+    #[must_use]
+    pub const fn round_to(self, granularity: Duration) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch.round_to(granularity))
+    }
+
+    /// Rounds this time point towards the past, to an integer multiple of `granularity` since the
+    /// epoch of its time scale. A zero `granularity` returns the time point unchanged.
+    ///
+    /// # Panics
+    /// Panics if the rounded time since epoch is not representable, as for `Duration::floor_to`.
+    #[must_use]
+    pub const fn floor_to(self, granularity: Duration) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch.floor_to(granularity))
+    }
+
+    /// Rounds this time point towards the future, to an integer multiple of `granularity` since
+    /// the epoch of its time scale. A zero `granularity` returns the time point unchanged.
+    ///
+    /// # Panics
+    /// Panics if the rounded time since epoch is not representable, as for `Duration::ceil_to`.
+    #[must_use]
+    pub const fn ceil_to(self, granularity: Duration) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch.ceil_to(granularity))
+    }
+
+    /// Returns the fractional-second part of this time point, in the range `[0, 1)` seconds. This
+    /// is the same subsecond remainder as returned by `into_fine_datetime`. Because it is computed
+    /// by flooring, the result is non-negative even for time points before the epoch.
+    #[must_use]
+    pub fn subsecond(self) -> Duration {
+        self - self.floor::<Second>()
+    }
+
     /// Constructs a `TimePoint` in the given time scale, based on a historic date-time.
     ///
     /// # Errors
@@ -169,24 +354,13 @@ where
     #[cfg(feature = "std")]
     #[must_use]
     pub fn now() -> Self {
-        use crate::{SecondsPerDay, UtcTime};
+        use crate::UtcTime;
 
-        let time_since_epoch = match std::time::UNIX_EPOCH.elapsed() {
-            Ok(duration) => {
-                Duration::seconds(duration.as_secs().into())
-                    + Duration::nanoseconds(duration.subsec_nanos().into())
-            }
-            Err(error) => {
-                let duration = error.duration();
-                -Duration::seconds(duration.as_secs().into())
-                    - Duration::nanoseconds(duration.subsec_nanos().into())
-            }
+        let nanoseconds_since_epoch = match std::time::UNIX_EPOCH.elapsed() {
+            Ok(duration) => duration.as_nanos().try_into().unwrap(),
+            Err(error) => -i128::try_from(error.duration().as_nanos()).unwrap(),
         };
-        let (days, time_of_day) = time_since_epoch.factor_out::<SecondsPerDay>();
-        let days = Days::new(days.try_into().unwrap());
-        let date = Date::from_time_since_epoch(days);
-        let utc_time = UtcTime::from_datetime(date, 0, 0, 0).unwrap() + time_of_day;
-        Self::from_utc(utc_time)
+        Self::from_utc(UtcTime::from_unix_timestamp_nanos(nanoseconds_since_epoch))
     }
 }
 
@@ -214,12 +388,37 @@ where
         let days_since_epoch = mjd.time_since_epoch() - epoch_julian_day;
         Self::from_time_since_epoch(days_since_epoch.into())
     }
+
+    /// Constructs a time point from a fractional modified Julian date, expressed in the resulting
+    /// time scale itself. This is the inverse of `to_mjd_f64`, and is supported only for uniform
+    /// date time scales for the same reasons as `from_modified_julian_date`.
+    ///
+    /// # Errors
+    /// Returns an error if `mjd` is not finite, or if the resulting time point is not
+    /// representable.
+    pub fn from_mjd_f64(mjd: f64) -> Result<Self, FloatDurationError> {
+        let epoch: Duration = ModifiedJulianDate::from_date(Scale::EPOCH)
+            .time_since_epoch()
+            .into();
+        let days_since_mjd_epoch = Duration::try_from_float::<f64, SecondsPerDay>(mjd)?;
+        let time_since_epoch = days_since_mjd_epoch
+            .checked_sub(epoch)
+            .ok_or(FloatDurationError::OutOfRange)?;
+        Ok(Self::from_time_since_epoch(time_since_epoch))
+    }
 }
 
 impl<Scale> TimePoint<Scale>
 where
     Scale: ?Sized + AbsoluteTimeScale,
 {
+    /// Returns the calendar date on which the epoch of this time scale falls, as expressed in the
+    /// time scale itself.
+    #[must_use]
+    pub const fn epoch_date() -> Date {
+        Scale::EPOCH
+    }
+
     /// Converts this time point into the equivalent Julian day representation.
     #[allow(clippy::missing_panics_doc, reason = "Infallible")]
     #[must_use]
@@ -333,6 +532,84 @@ where
     }
 }
 
+impl<Scale> TimePoint<Scale>
+where
+    Self: IntoDateTime + FromFineDateTime + FromDateTime,
+    Scale: ?Sized,
+{
+    /// Returns the `TimePoint` on the same date as this one, but at the given time-of-day. Any
+    /// subseconds are discarded.
+    ///
+    /// # Errors
+    /// Will raise an error if the requested time-of-day does not exist on this date, for example
+    /// a 60th second on a UTC date without leap second.
+    pub fn with_time_of_day(
+        self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, <Self as FromDateTime>::Error> {
+        let (date, _, _, _) = self.into_datetime();
+        Self::from_datetime(date, hour, minute, second)
+    }
+
+    /// Returns the `TimePoint` at the start of the civil date on which this one falls, i.e., at
+    /// 00:00:00 of that date.
+    ///
+    /// # Errors
+    /// Will raise an error if midnight cannot be represented on this date.
+    pub fn start_of_day(self) -> Result<Self, <Self as FromDateTime>::Error> {
+        self.with_time_of_day(0, 0, 0)
+    }
+
+    /// Returns the `TimePoint` at the start of the last second of the civil date on which this one
+    /// falls. That is 23:59:59 on most dates, but 23:59:60 on UTC dates with a leap second.
+    ///
+    /// # Errors
+    /// Will raise an error if neither 23:59:60 nor 23:59:59 exists on this date, which happens
+    /// only on dates on which a leap second is deleted.
+    pub fn end_of_day(self) -> Result<Self, <Self as FromDateTime>::Error> {
+        self.with_time_of_day(23, 59, 60)
+            .or_else(|_| self.with_time_of_day(23, 59, 59))
+    }
+
+    /// Returns the `TimePoint` on the same date as this one, but at the given subsecond-accuracy
+    /// time-of-day.
+    ///
+    /// # Errors
+    /// Will raise an error if the requested time-of-day does not exist on this date, for example
+    /// a 60th second on a UTC date without leap second.
+    pub fn with_hms_subsecond(
+        self,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        subseconds: Duration,
+    ) -> Result<Self, <Self as FromFineDateTime>::Error> {
+        let (date, _, _, _) = self.into_datetime();
+        Self::from_fine_datetime(date, hour, minute, second, subseconds)
+    }
+
+    /// Returns the `TimePoint` at the same time-of-day as this one, but `days` calendar days later
+    /// (or earlier, if negative). This differs from adding `Duration::days(days)`, which always
+    /// adds a fixed 86400 seconds per day: on scales with leap seconds, such as UTC, that results
+    /// in a different time-of-day whenever a leap second is crossed.
+    ///
+    /// # Errors
+    /// Will raise an error if the time-of-day does not exist on the resulting date, which may
+    /// happen when starting from a leap second.
+    ///
+    /// # Panics
+    /// Panics if the resulting date is not representable.
+    pub fn add_calendar_days(self, days: i32) -> Result<Self, <Self as FromFineDateTime>::Error> {
+        let (date, hour, minute, second, subseconds) = self.into_fine_datetime();
+        let date = date
+            .step_forward(Days::new(days))
+            .expect("calendar date is not representable");
+        Self::from_fine_datetime(date, hour, minute, second, subseconds)
+    }
+}
+
 impl<Scale> IntoFineDateTime for TimePoint<Scale>
 where
     Scale: ?Sized,
@@ -370,6 +647,29 @@ where
         let (date, hour, minute, second) = self.into_datetime();
         (date.into(), hour, minute, second)
     }
+
+    /// Returns the ISO 8601 week-numbering year and week number of the civil date of this time
+    /// point, as given by `Date::iso_week`. During a leap second, the week is taken from the
+    /// displayed civil date, i.e., the day to which the leap second is appended.
+    #[must_use]
+    pub fn iso_week(self) -> (i32, u8) {
+        self.into_historic_datetime().0.into_date().iso_week()
+    }
+
+    /// Returns the calendar quarter (1 to 4) of the civil date of this time point, as given by
+    /// `HistoricDate::quarter`. During a leap second, the quarter is taken from the displayed
+    /// civil date.
+    #[must_use]
+    pub fn quarter(self) -> u8 {
+        self.into_historic_datetime().0.quarter()
+    }
+
+    /// Returns the day-of-year of the civil date of this time point, in the historic calendar.
+    /// During a leap second, the day-of-year is taken from the displayed civil date.
+    #[must_use]
+    pub fn day_of_year(self) -> u16 {
+        self.into_historic_datetime().0.day_of_year()
+    }
 }
 
 impl<Scale: ?Sized> TimePoint<Scale>
@@ -393,6 +693,62 @@ where
         let (date, hour, minute, second, subseconds) = self.into_fine_datetime();
         (date.into(), hour, minute, second, subseconds)
     }
+
+    /// Returns the calendar date on which this `TimePoint` falls, discarding the time-of-day.
+    #[must_use]
+    pub fn date(self) -> Date {
+        let (date, _, _, _, _) = self.into_fine_datetime();
+        date
+    }
+
+    /// Returns the fractional modified Julian date of this time point, expressed in its own time
+    /// scale: the number of days since 17 November 1858, including the fraction of the current
+    /// day. The result is rounded once, when converting to `f64`.
+    ///
+    /// The fraction is computed from the time-of-day, assuming days of 86400 seconds. Hence, it is
+    /// not well-defined on days containing a leap second: during a leap second, the fraction
+    /// reaches or exceeds one. Following the IAU recommendation, fractional Julian dates should
+    /// not be used to express instants in time scales with leap seconds.
+    #[must_use]
+    pub fn to_mjd_f64(self) -> f64 {
+        let days: Duration = ModifiedJulianDate::from_date(self.date())
+            .time_since_epoch()
+            .into();
+        (days + self.time_of_day()).as_float::<f64, SecondsPerDay>()
+    }
+
+    /// Returns the time elapsed since midnight of the date on which this `TimePoint` falls,
+    /// including subseconds. This is computed from the date-time representation, so leap seconds
+    /// are reflected as well: during a leap second 23:59:60, the returned value will exceed 24
+    /// hours minus one second.
+    #[must_use]
+    pub fn time_of_day(self) -> Duration {
+        let (_, hour, minute, second, subseconds) = self.into_fine_datetime();
+        Duration::hours(hour.into())
+            + Duration::minutes(minute.into())
+            + Duration::seconds(second.into())
+            + subseconds
+    }
+}
+
+impl<Scale> TimePoint<Scale>
+where
+    Scale: ?Sized + TimeScale,
+{
+    /// Returns the full (English) name of the time scale of this time point. Equivalent to
+    /// `Scale::NAME`, but does not require the `TimeScale` trait to be in scope.
+    #[must_use]
+    pub const fn scale_name() -> &'static str {
+        Scale::NAME
+    }
+
+    /// Returns the abbreviation of the time scale of this time point, as used when displaying it.
+    /// Equivalent to `Scale::ABBREVIATION`, but does not require the `TimeScale` trait to be in
+    /// scope.
+    #[must_use]
+    pub const fn scale_abbreviation() -> &'static str {
+        Scale::ABBREVIATION
+    }
 }
 
 impl<Scale> Display for TimePoint<Scale>
@@ -402,7 +758,35 @@ where
     Self: IntoFineDateTime,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let (historic_date, hour, minute, second, subseconds) = self.into_fine_historic_datetime();
+        Display::fmt(&self.display_naive(), f)?;
+        write!(f, " {}", Scale::ABBREVIATION)
+    }
+}
+
+impl<Scale: ?Sized> TimePoint<Scale> {
+    /// Returns a formatter that renders this time point in the same manner as its `Display`
+    /// implementation, but without the trailing time scale abbreviation: `YYYY-MM-DDThh:mm:ss.fff`.
+    /// Useful when embedding time points in a larger format.
+    #[must_use]
+    pub const fn display_naive(&self) -> DisplayNaive<Scale> {
+        DisplayNaive { time_point: *self }
+    }
+}
+
+/// Helper type used to render a `TimePoint` without its time scale abbreviation. Returned by
+/// `TimePoint::display_naive()`.
+pub struct DisplayNaive<Scale: ?Sized> {
+    time_point: TimePoint<Scale>,
+}
+
+impl<Scale> Display for DisplayNaive<Scale>
+where
+    Scale: ?Sized,
+    TimePoint<Scale>: IntoFineDateTime,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (historic_date, hour, minute, second, subseconds) =
+            self.time_point.into_fine_historic_datetime();
         write!(
             f,
             "{:04}-{:02}-{:02}T{hour:02}:{minute:02}:{second:02}",
@@ -421,8 +805,7 @@ where
                 write!(f, "{digit}")?;
             }
         }
-
-        write!(f, " {}", Scale::ABBREVIATION)
+        Ok(())
     }
 }
 
@@ -532,6 +915,470 @@ fn random_formatting() {
     }
 }
 
+/// Verifies that the date and time-of-day accessors agree with the full date-time decomposition
+/// for a uniform time scale.
+#[test]
+fn date_and_time_of_day() {
+    let time = crate::TaiTime::from_fine_historic_datetime(
+        2025,
+        Month::July,
+        16,
+        16,
+        23,
+        24,
+        Duration::milliseconds(250),
+    )
+    .unwrap();
+    assert_eq!(
+        time.date(),
+        Date::from_historic_date(2025, Month::July, 16).unwrap()
+    );
+    assert_eq!(
+        time.time_of_day(),
+        Duration::hours(16)
+            + Duration::minutes(23)
+            + Duration::seconds(24)
+            + Duration::milliseconds(250)
+    );
+}
+
+/// Verifies that the time-of-day is reported correctly around a UTC leap second.
+#[test]
+fn time_of_day_near_leap_second() {
+    use crate::UtcTime;
+    let leap_second_date = Date::from_historic_date(2016, Month::December, 31).unwrap();
+    let before = UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 59).unwrap();
+    assert_eq!(before.date(), leap_second_date);
+    assert_eq!(
+        before.time_of_day(),
+        Duration::hours(23) + Duration::minutes(59) + Duration::seconds(59)
+    );
+
+    let leap_second = before + Duration::milliseconds(1_500);
+    assert_eq!(leap_second.date(), leap_second_date);
+    assert_eq!(
+        leap_second.time_of_day(),
+        Duration::hours(23)
+            + Duration::minutes(59)
+            + Duration::seconds(60)
+            + Duration::milliseconds(500)
+    );
+
+    let after = UtcTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(
+        after.date(),
+        Date::from_historic_date(2017, Month::January, 1).unwrap()
+    );
+    assert_eq!(after.time_of_day(), Duration::ZERO);
+}
+
+/// Verifies that the absolute difference between time points is independent of their order.
+#[test]
+fn abs_diff() {
+    use crate::TaiTime;
+    let a = TaiTime::from_historic_datetime(2025, Month::July, 16, 16, 23, 24).unwrap();
+    let b = TaiTime::from_historic_datetime(2025, Month::July, 16, 16, 24, 0).unwrap();
+    assert_eq!(a.abs_diff(b), Duration::seconds(36));
+    assert_eq!(b.abs_diff(a), Duration::seconds(36));
+    assert_eq!(
+        TaiTime::min_value().abs_diff(TaiTime::max_value()),
+        Duration::max_value()
+    );
+}
+
+/// Verifies that saturating arithmetic pins to the representable extremes, while checked
+/// arithmetic reports overflow.
+#[test]
+fn saturating_arithmetic() {
+    use crate::TaiTime;
+    let max = TaiTime::max_value();
+    let min = TaiTime::min_value();
+    let second = Duration::seconds(1);
+    assert_eq!(max.saturating_add(second), max);
+    assert_eq!(min.saturating_sub(second), min);
+    assert_eq!(min.saturating_add(-second), min);
+    assert_eq!(max.saturating_sub(-second), max);
+    assert_eq!(max.saturating_sub(second), max - second);
+    assert_eq!(
+        min.saturating_add(Duration::max_value()),
+        min + Duration::max_value()
+    );
+
+    assert_eq!(max.checked_add(second), None);
+    assert_eq!(min.checked_sub(second), None);
+    assert_eq!(max.checked_sub(second), Some(max - second));
+
+    let time = TaiTime::from_historic_datetime(2025, Month::July, 16, 16, 23, 24).unwrap();
+    assert_eq!(time.saturating_add(second), time + second);
+    assert_eq!(time.saturating_sub(second), time - second);
+}
+
+/// Verifies rounding of time points to arbitrary granularities.
+#[test]
+fn round_to_granularity() {
+    use crate::TaiTime;
+    let time = TaiTime::from_fine_historic_datetime(
+        2025,
+        Month::July,
+        16,
+        16,
+        23,
+        24,
+        Duration::milliseconds(456),
+    )
+    .unwrap();
+    let quarter_hour = TaiTime::from_historic_datetime(2025, Month::July, 16, 16, 30, 0).unwrap();
+    assert_eq!(time.round_to(Duration::minutes(15)), quarter_hour);
+    assert_eq!(
+        time.floor_to(Duration::minutes(15)),
+        quarter_hour - Duration::minutes(15)
+    );
+    assert_eq!(time.ceil_to(Duration::minutes(15)), quarter_hour);
+
+    let floored = TaiTime::from_fine_historic_datetime(
+        2025,
+        Month::July,
+        16,
+        16,
+        23,
+        24,
+        Duration::milliseconds(400),
+    )
+    .unwrap();
+    assert_eq!(time.floor_to(Duration::milliseconds(100)), floored);
+    assert_eq!(time.round_to(Duration::ZERO), time);
+}
+
+/// Verifies that counting whole units between time points truncates towards zero.
+#[test]
+fn whole_units_since() {
+    use crate::{SecondsPerHour, TaiTime};
+    let before = TaiTime::from_historic_datetime(2024, Month::February, 28, 12, 0, 0).unwrap();
+    let after = TaiTime::from_historic_datetime(2024, Month::March, 1, 12, 0, 0).unwrap();
+    assert_eq!(after.whole_days_since(before), 2);
+    assert_eq!(before.whole_days_since(after), -2);
+    assert_eq!(after.whole_seconds_since(before), 2 * 86_400);
+    assert_eq!(after.whole_units_since::<SecondsPerHour>(before), 48);
+
+    let later = before + Duration::milliseconds(999);
+    assert_eq!(later.whole_seconds_since(before), 0);
+    assert_eq!(before.whole_seconds_since(later), 0);
+}
+
+/// Verifies that the time-of-day of a time point may be replaced while retaining its date.
+#[test]
+fn replace_time_of_day() {
+    use crate::{TaiTime, UtcTime};
+    let time_point = TaiTime::from_fine_historic_datetime(
+        2024,
+        Month::March,
+        14,
+        7,
+        26,
+        53,
+        Duration::milliseconds(589),
+    )
+    .unwrap();
+    assert_eq!(
+        time_point.with_time_of_day(12, 0, 0),
+        Ok(TaiTime::from_historic_datetime(2024, Month::March, 14, 12, 0, 0).unwrap())
+    );
+    assert_eq!(
+        time_point.with_hms_subsecond(23, 59, 59, Duration::milliseconds(250)),
+        Ok(TaiTime::from_fine_historic_datetime(
+            2024,
+            Month::March,
+            14,
+            23,
+            59,
+            59,
+            Duration::milliseconds(250)
+        )
+        .unwrap())
+    );
+    assert!(time_point.with_time_of_day(24, 0, 0).is_err());
+
+    // A 60th second only exists on UTC dates with a leap second.
+    let leap_second_day =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 8, 0, 0).unwrap();
+    let leap_second = leap_second_day.with_time_of_day(23, 59, 60).unwrap();
+    assert_eq!(leap_second.into_historic_datetime().3, 60);
+    let regular_day = UtcTime::from_historic_datetime(2016, Month::December, 30, 8, 0, 0).unwrap();
+    assert!(regular_day.with_time_of_day(23, 59, 60).is_err());
+    assert!(
+        regular_day
+            .with_hms_subsecond(23, 59, 60, Duration::milliseconds(500))
+            .is_err()
+    );
+}
+
+/// Verifies that the start and end of a day are found on normal UTC days and on leap second days.
+#[test]
+fn start_and_end_of_day() {
+    use crate::{TaiTime, UtcTime};
+    let normal_day = UtcTime::from_fine_historic_datetime(
+        2017,
+        Month::March,
+        1,
+        13,
+        14,
+        15,
+        Duration::MILLISECOND,
+    )
+    .unwrap();
+    assert_eq!(
+        normal_day.start_of_day(),
+        Ok(UtcTime::from_historic_datetime(2017, Month::March, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        normal_day.end_of_day(),
+        Ok(UtcTime::from_historic_datetime(2017, Month::March, 1, 23, 59, 59).unwrap())
+    );
+
+    let leap_second_day =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 12, 0, 0).unwrap();
+    let start = leap_second_day.start_of_day().unwrap();
+    let end = leap_second_day.end_of_day().unwrap();
+    assert_eq!(end.into_historic_datetime().3, 60);
+    assert_eq!(end - start, Duration::seconds(86_400));
+    assert_eq!(end.start_of_day(), Ok(start));
+    assert_eq!(end.end_of_day(), Ok(end));
+
+    let tai = TaiTime::from_historic_datetime(2016, Month::December, 31, 12, 0, 0).unwrap();
+    assert_eq!(
+        tai.end_of_day(),
+        Ok(TaiTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 59).unwrap())
+    );
+}
+
+/// Verifies that adding calendar days retains the time-of-day across a leap second, where adding
+/// a fixed number of seconds does not.
+#[test]
+fn add_calendar_days() {
+    use crate::UtcTime;
+    let before = UtcTime::from_historic_datetime(2016, Month::December, 31, 12, 0, 0).unwrap();
+    let after = UtcTime::from_historic_datetime(2017, Month::January, 1, 12, 0, 0).unwrap();
+    assert_eq!(before.add_calendar_days(1), Ok(after));
+    assert_eq!(after.add_calendar_days(-1), Ok(before));
+    assert_eq!(before + Duration::days(1), after - Duration::seconds(1));
+
+    let subseconds = Duration::milliseconds(250);
+    assert_eq!(
+        (before + subseconds).add_calendar_days(1),
+        Ok(after + subseconds)
+    );
+
+    // A leap second does not exist on the next day.
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert!(leap_second.add_calendar_days(1).is_err());
+    assert_eq!(leap_second.add_calendar_days(0), Ok(leap_second));
+}
+
+/// Verifies that `Days` may be added to and subtracted from time points directly, equivalent to
+/// adding the corresponding `Duration`.
+#[test]
+fn add_days() {
+    use crate::TaiTime;
+    let tai = TaiTime::from_historic_datetime(2024, Month::February, 26, 9, 30, 15).unwrap();
+    let week_later = TaiTime::from_historic_datetime(2024, Month::March, 4, 9, 30, 15).unwrap();
+    assert_eq!(tai + Days::new(7), week_later);
+    assert_eq!(tai + Days::new(7), tai + Duration::weeks(1));
+    assert_eq!(week_later - Days::new(7), tai);
+    assert_eq!(tai - Days::new(-7), week_later);
+
+    let mut time_point = tai;
+    time_point += Days::new(7);
+    assert_eq!(time_point, week_later);
+    time_point -= Days::new(7);
+    assert_eq!(time_point, tai);
+}
+
+/// Verifies that the time scale constants may be read through the `TimePoint` type.
+#[test]
+fn scale_constants() {
+    use crate::{GpsTime, TtTime};
+    assert_eq!(
+        GpsTime::epoch_date(),
+        Date::from_historic_date(1980, Month::January, 6).unwrap()
+    );
+    assert_eq!(GpsTime::scale_abbreviation(), "GPST");
+    assert_eq!(GpsTime::scale_name(), "Global Positioning System Time");
+    assert_eq!(
+        TtTime::epoch_date(),
+        Date::from_historic_date(1977, Month::January, 1).unwrap()
+    );
+    assert_eq!(TtTime::scale_abbreviation(), "TT");
+    assert_eq!(TtTime::scale_name(), "Terrestrial Time");
+}
+
+/// Verifies that subtracting days lands on the expected civil date, also across month and year
+/// boundaries, and that subtraction near the representable minimum is caught.
+#[test]
+fn subtract_days() {
+    use crate::GpsTime;
+    let gps = GpsTime::from_historic_datetime(2024, Month::January, 3, 6, 15, 0).unwrap();
+    let week_earlier =
+        GpsTime::from_historic_datetime(2023, Month::December, 27, 6, 15, 0).unwrap();
+    assert_eq!(gps - Days::new(7), week_earlier);
+    assert_eq!(week_earlier + Days::new(7), gps);
+    assert_eq!(gps.checked_sub_days(Days::new(7)), Some(week_earlier));
+    assert_eq!(week_earlier.checked_add_days(Days::new(7)), Some(gps));
+    assert_eq!(
+        gps.checked_sub_days(Days::new(-7)),
+        gps.checked_add_days(Days::new(7))
+    );
+
+    let first = GpsTime::from_time_since_epoch(Duration::MIN);
+    assert_eq!(first.checked_sub_days(Days::new(1)), None);
+    assert_eq!(first.checked_add_days(Days::new(-1)), None);
+    assert_eq!(
+        first.checked_add_days(Days::new(1)),
+        Some(first + Duration::days(1))
+    );
+    let last = GpsTime::from_time_since_epoch(Duration::MAX);
+    assert_eq!(last.checked_add_days(Days::new(1)), None);
+    assert_eq!(last.checked_sub_days(Days::new(-1)), None);
+}
+
+/// Verifies that subtracting days beyond the representable minimum panics rather than wrapping.
+#[test]
+#[should_panic(expected = "attotime: Duration overflow")]
+fn subtract_days_overflow() {
+    let first = crate::GpsTime::from_time_since_epoch(Duration::MIN);
+    let _ = first - Days::new(1);
+}
+
+/// Verifies that the saturating duration since an earlier time point is never negative.
+#[test]
+fn saturating_duration_since() {
+    use crate::TaiTime;
+    let earlier = TaiTime::from_historic_datetime(2024, Month::May, 1, 12, 0, 0).unwrap();
+    let later = earlier + Duration::seconds(90);
+    assert_eq!(
+        later.saturating_duration_since(earlier),
+        Duration::seconds(90)
+    );
+    assert_eq!(earlier.saturating_duration_since(later), Duration::ZERO);
+    assert_eq!(earlier.saturating_duration_since(earlier), Duration::ZERO);
+
+    let first = TaiTime::from_time_since_epoch(Duration::MIN);
+    let last = TaiTime::from_time_since_epoch(Duration::MAX);
+    assert_eq!(last.saturating_duration_since(first), Duration::MAX);
+    assert_eq!(first.saturating_duration_since(last), Duration::ZERO);
+}
+
+/// Verifies that the subsecond part of a time point is non-negative, also before the epoch.
+#[test]
+fn subsecond() {
+    use crate::TaiTime;
+    let subseconds = Duration::milliseconds(250);
+    let before_epoch =
+        TaiTime::from_fine_historic_datetime(1957, Month::March, 4, 5, 6, 7, subseconds).unwrap();
+    assert!(before_epoch.time_since_epoch() < Duration::ZERO);
+    assert_eq!(before_epoch.subsecond(), subseconds);
+    assert_eq!(before_epoch.into_fine_datetime().4, subseconds);
+
+    let just_before_epoch = TaiTime::from_time_since_epoch(Duration::attoseconds(-1));
+    assert_eq!(
+        just_before_epoch.subsecond(),
+        Duration::seconds(1) - Duration::attoseconds(1)
+    );
+    assert_eq!(
+        TaiTime::from_time_since_epoch(Duration::seconds(-3)).subsecond(),
+        Duration::ZERO
+    );
+}
+
+/// Verifies fractional modified Julian dates against known values, and their inverse.
+#[test]
+#[allow(clippy::float_cmp, reason = "Exact values expected")]
+fn fractional_modified_julian_date() {
+    use crate::{TaiTime, TtTime, UtcTime};
+    let midnight = TtTime::from_historic_datetime(2000, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(midnight.to_mjd_f64(), 51_544.0);
+    let noon = TtTime::from_historic_datetime(2000, Month::January, 1, 12, 0, 0).unwrap();
+    assert_eq!(noon.to_mjd_f64(), 51_544.5);
+    assert_eq!(TtTime::from_mjd_f64(51_544.5), Ok(noon));
+    assert_eq!(TtTime::from_mjd_f64(51_544.0), Ok(midnight));
+
+    let before_epoch =
+        TaiTime::from_historic_datetime(1858, Month::November, 16, 18, 0, 0).unwrap();
+    assert_eq!(before_epoch.to_mjd_f64(), -0.25);
+    assert_eq!(TaiTime::from_mjd_f64(-0.25), Ok(before_epoch));
+    assert_eq!(
+        TaiTime::from_mjd_f64(f64::NAN),
+        Err(FloatDurationError::NonFinite)
+    );
+    assert_eq!(
+        TaiTime::from_mjd_f64(1e30),
+        Err(FloatDurationError::OutOfRange)
+    );
+
+    // For UTC, the fraction follows the time-of-day, so leap seconds are not counted.
+    let utc = UtcTime::from_historic_datetime(2000, Month::January, 1, 6, 0, 0).unwrap();
+    assert_eq!(utc.to_mjd_f64(), 51_544.25);
+}
+
+/// Verifies that time points are ordered by their time since epoch only, such that separately
+/// constructed time points referring to the same instant compare equal.
+#[test]
+fn ordering() {
+    use crate::TaiTime;
+    use core::cmp::Ordering;
+    let a = TaiTime::from_historic_datetime(2024, Month::March, 1, 12, 0, 0).unwrap();
+    let b = TaiTime::from_time_since_epoch(a.time_since_epoch());
+    assert_eq!(a.cmp(&b), Ordering::Equal);
+    assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+    assert_eq!(a, b);
+
+    let later = a + Duration::ATTOSECOND;
+    assert_eq!(a.cmp(&later), Ordering::Less);
+    assert_eq!(later.cmp(&a), Ordering::Greater);
+    assert_eq!(a.max(later), later);
+}
+
+/// Verifies that the default time point is the epoch of its time scale.
+#[test]
+fn default() {
+    use crate::{TaiTime, UtcTime};
+    assert_eq!(UtcTime::default(), UtcTime::epoch());
+    assert_eq!(
+        UtcTime::epoch_date(),
+        Date::from_historic_date(1972, Month::January, 1).unwrap()
+    );
+    assert!(
+        UtcTime::default()
+            < UtcTime::from_historic_datetime(1972, Month::January, 1, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        TaiTime::default(),
+        TaiTime::from_historic_datetime(1958, Month::January, 1, 0, 0, 0).unwrap()
+    );
+}
+
+/// Verifies the calendar passthroughs on a UTC instant whose civil date falls in the last ISO
+/// week of the prior year, and on a leap second, which belongs to the day it is appended to.
+#[test]
+fn calendar_passthroughs() {
+    use crate::UtcTime;
+    let new_year = UtcTime::from_historic_datetime(2021, Month::January, 1, 12, 0, 0).unwrap();
+    assert_eq!(new_year.iso_week(), (2020, 53));
+    assert_eq!(new_year.quarter(), 1);
+    assert_eq!(new_year.day_of_year(), 1);
+
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert_eq!(leap_second.iso_week(), (2016, 52));
+    assert_eq!(leap_second.quarter(), 4);
+    assert_eq!(leap_second.day_of_year(), 366);
+
+    let after = leap_second + Duration::seconds(1);
+    assert_eq!(after.iso_week(), (2016, 52));
+    assert_eq!(after.quarter(), 1);
+    assert_eq!(after.day_of_year(), 1);
+}
+
 #[cfg(kani)]
 impl<Scale> kani::Arbitrary for TimePoint<Scale>
 where
@@ -558,9 +1405,19 @@ impl<Scale: ?Sized> Clone for TimePoint<Scale> {
     }
 }
 
+/// The default `TimePoint` is the epoch of its time scale, at which the time since epoch is zero.
+/// Note that the epoch differs per time scale: for UTC, for example, it lies on 1972-01-01. Since
+/// UTC time points also count the leap seconds accumulated before that date, the default UTC time
+/// point is displayed a few seconds before midnight, on 1971-12-31.
+impl<Scale: ?Sized> Default for TimePoint<Scale> {
+    fn default() -> Self {
+        Self::epoch()
+    }
+}
+
 impl<Scale: ?Sized> PartialEq for TimePoint<Scale> {
     fn eq(&self, other: &Self) -> bool {
-        self.time_since_epoch == other.time_since_epoch && self.time_scale == other.time_scale
+        self.time_since_epoch == other.time_since_epoch
     }
 }
 
@@ -572,20 +1429,18 @@ impl<Scale: ?Sized> PartialOrd for TimePoint<Scale> {
     }
 }
 
+/// Time points are ordered chronologically, by their time since epoch. This ordering is defined
+/// only between time points of the same time scale: to compare instants expressed in different
+/// time scales, use `cmp_across`.
 impl<Scale: ?Sized> Ord for TimePoint<Scale> {
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        match self.time_since_epoch.cmp(&other.time_since_epoch) {
-            core::cmp::Ordering::Equal => {}
-            ord => return ord,
-        }
-        self.time_scale.cmp(&other.time_scale)
+        self.time_since_epoch.cmp(&other.time_since_epoch)
     }
 }
 
 impl<Scale: ?Sized> Hash for TimePoint<Scale> {
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.time_since_epoch.hash(state);
-        self.time_scale.hash(state);
     }
 }
 
@@ -640,6 +1495,51 @@ where
     }
 }
 
+/// Adds a fixed number of days, each exactly 86400 SI seconds long. For non-uniform scales like
+/// UTC, this need not retain the time-of-day across leap seconds: use `add_calendar_days` for that.
+impl<Scale> Add<Days> for TimePoint<Scale>
+where
+    Scale: ?Sized,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Days) -> Self::Output {
+        self + rhs.into_duration()
+    }
+}
+
+impl<Scale> AddAssign<Days> for TimePoint<Scale>
+where
+    Scale: ?Sized,
+{
+    fn add_assign(&mut self, rhs: Days) {
+        *self += rhs.into_duration();
+    }
+}
+
+/// Subtracts a fixed number of days, each exactly 86400 SI seconds long. For non-uniform scales
+/// like UTC, this need not retain the time-of-day across leap seconds: use `add_calendar_days` for
+/// that.
+impl<Scale> Sub<Days> for TimePoint<Scale>
+where
+    Scale: ?Sized,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Days) -> Self::Output {
+        self - rhs.into_duration()
+    }
+}
+
+impl<Scale> SubAssign<Days> for TimePoint<Scale>
+where
+    Scale: ?Sized,
+{
+    fn sub_assign(&mut self, rhs: Days) {
+        *self -= rhs.into_duration();
+    }
+}
+
 impl<Scale> Bounded for TimePoint<Scale>
 where
     Scale: ?Sized,
@@ -0,0 +1,275 @@
+//! Implementation of a scale-erased time point, which may hold a time point expressed in any of
+//! the built-in time scales that are related to TAI.
+
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use crate::{
+    Bdt, BeiDouTime, GalileoTime, GlonassTime, Glonasst, GpsTime, Gpst, Gst, QzssTime, Qzsst, Tai,
+    TaiTime, Tcb, Tcg, TcgTime, Tdb, Tt, TtTime, Utc, UtcTime, time_scale::TimeScale,
+};
+
+/// Time point expressed in any of the built-in terrestrial time scales
+///
+/// `TimePoint`s are parametrized over their time scale, which means that time points of different
+/// scales cannot be stored in the same collection. `AnyTime` serves as escape hatch for such
+/// cases: it retains the time point in its original scale, but may be compared, ordered, and
+/// hashed by first converting into TAI. Two `AnyTime`s are thus equal if they represent the same
+/// instant, even when they are expressed in different time scales.
+///
+/// TCB and TDB are not included: their relation to TAI depends on the position of the observer
+/// within the Solar system, so there is no unique TAI instant to compare them by.
+#[derive(Copy, Clone, Debug)]
+pub enum AnyTime {
+    Tai(TaiTime),
+    Utc(UtcTime),
+    Tt(TtTime),
+    Gpst(GpsTime),
+    Gst(GalileoTime),
+    Bdt(BeiDouTime),
+    Qzsst(QzssTime),
+    Glonasst(GlonassTime),
+    Tcg(TcgTime),
+}
+
+impl AnyTime {
+    /// Converts this time point into TAI, regardless of the time scale it is expressed in.
+    #[must_use]
+    pub fn to_tai(self) -> TaiTime {
+        match self {
+            Self::Tai(time_point) => time_point,
+            Self::Utc(time_point) => time_point.into_tai(),
+            Self::Tt(time_point) => time_point.into_tai(),
+            Self::Gpst(time_point) => time_point.into_tai(),
+            Self::Gst(time_point) => time_point.into_tai(),
+            Self::Bdt(time_point) => time_point.into_tai(),
+            Self::Qzsst(time_point) => time_point.into_tai(),
+            Self::Glonasst(time_point) => time_point.into_tai(),
+            Self::Tcg(time_point) => time_point.into_tt().into_tai(),
+        }
+    }
+}
+
+/// Identifier of a built-in time scale
+///
+/// Since time scales are distinct types, they cannot be selected at runtime. `ScaleTag` serves as
+/// their runtime counterpart: it may be obtained from a scale abbreviation, for example when
+/// parsing a time point string with a trailing abbreviation, and then used to decide which
+/// `AnyTime` variant to construct. TCB and TDB have a tag, but no `AnyTime` variant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ScaleTag {
+    Tai,
+    Utc,
+    Tt,
+    Gpst,
+    Gst,
+    Bdt,
+    Qzsst,
+    Glonasst,
+    Tcg,
+    Tcb,
+    Tdb,
+}
+
+impl ScaleTag {
+    /// All built-in time scales, in declaration order.
+    pub const ALL: [Self; 11] = [
+        Self::Tai,
+        Self::Utc,
+        Self::Tt,
+        Self::Gpst,
+        Self::Gst,
+        Self::Bdt,
+        Self::Qzsst,
+        Self::Glonasst,
+        Self::Tcg,
+        Self::Tcb,
+        Self::Tdb,
+    ];
+
+    /// Returns the tag of the built-in time scale with the given abbreviation, as given by
+    /// `TimeScale::ABBREVIATION`. The comparison is case-sensitive. Returns `None` if no built-in
+    /// time scale uses this abbreviation.
+    #[must_use]
+    pub fn from_abbreviation(abbreviation: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|tag| tag.abbreviation() == abbreviation)
+    }
+
+    /// Returns the abbreviation of the time scale identified by this tag.
+    #[must_use]
+    pub const fn abbreviation(self) -> &'static str {
+        match self {
+            Self::Tai => Tai::ABBREVIATION,
+            Self::Utc => Utc::ABBREVIATION,
+            Self::Tt => Tt::ABBREVIATION,
+            Self::Gpst => Gpst::ABBREVIATION,
+            Self::Gst => Gst::ABBREVIATION,
+            Self::Bdt => Bdt::ABBREVIATION,
+            Self::Qzsst => Qzsst::ABBREVIATION,
+            Self::Glonasst => Glonasst::ABBREVIATION,
+            Self::Tcg => Tcg::ABBREVIATION,
+            Self::Tcb => Tcb::ABBREVIATION,
+            Self::Tdb => Tdb::ABBREVIATION,
+        }
+    }
+}
+
+impl AnyTime {
+    /// Returns the tag of the time scale in which this time point is expressed.
+    #[must_use]
+    pub const fn scale_tag(&self) -> ScaleTag {
+        match self {
+            Self::Tai(_) => ScaleTag::Tai,
+            Self::Utc(_) => ScaleTag::Utc,
+            Self::Tt(_) => ScaleTag::Tt,
+            Self::Gpst(_) => ScaleTag::Gpst,
+            Self::Gst(_) => ScaleTag::Gst,
+            Self::Bdt(_) => ScaleTag::Bdt,
+            Self::Qzsst(_) => ScaleTag::Qzsst,
+            Self::Glonasst(_) => ScaleTag::Glonasst,
+            Self::Tcg(_) => ScaleTag::Tcg,
+        }
+    }
+}
+
+macro_rules! impl_from_time_point {
+    ($variant:ident, $time_point:ty) => {
+        impl From<$time_point> for AnyTime {
+            fn from(time_point: $time_point) -> Self {
+                Self::$variant(time_point)
+            }
+        }
+    };
+}
+
+impl_from_time_point!(Tai, TaiTime);
+impl_from_time_point!(Utc, UtcTime);
+impl_from_time_point!(Tt, TtTime);
+impl_from_time_point!(Gpst, GpsTime);
+impl_from_time_point!(Gst, GalileoTime);
+impl_from_time_point!(Bdt, BeiDouTime);
+impl_from_time_point!(Qzsst, QzssTime);
+impl_from_time_point!(Glonasst, GlonassTime);
+impl_from_time_point!(Tcg, TcgTime);
+
+impl Display for AnyTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Tai(time_point) => time_point.fmt(f),
+            Self::Utc(time_point) => time_point.fmt(f),
+            Self::Tt(time_point) => time_point.fmt(f),
+            Self::Gpst(time_point) => time_point.fmt(f),
+            Self::Gst(time_point) => time_point.fmt(f),
+            Self::Bdt(time_point) => time_point.fmt(f),
+            Self::Qzsst(time_point) => time_point.fmt(f),
+            Self::Glonasst(time_point) => time_point.fmt(f),
+            Self::Tcg(time_point) => time_point.fmt(f),
+        }
+    }
+}
+
+impl PartialEq for AnyTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_tai() == other.to_tai()
+    }
+}
+
+impl Eq for AnyTime {}
+
+impl PartialOrd for AnyTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AnyTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_tai().cmp(&other.to_tai())
+    }
+}
+
+impl Hash for AnyTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_tai().hash(state);
+    }
+}
+
+/// Verifies that time points from different scales may be stored in a single collection, and
+/// that sorting them results in chronological order.
+#[cfg(feature = "std")]
+#[test]
+fn sort_mixed_scales() {
+    use crate::Month;
+    let utc = UtcTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 0).unwrap();
+    let tai = TaiTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 30).unwrap();
+    let gpst = GpsTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 59).unwrap();
+    let glonasst = GlonassTime::from_historic_datetime(2017, Month::January, 1, 3, 0, 1).unwrap();
+    let tt = TtTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 0).unwrap();
+
+    let mut times: Vec<AnyTime> = vec![tt.into(), utc.into(), glonasst.into(), tai.into()];
+    times.push(gpst.into());
+    times.sort();
+
+    assert_eq!(
+        times,
+        [
+            AnyTime::Tt(tt),
+            AnyTime::Gpst(gpst),
+            AnyTime::Tai(tai),
+            AnyTime::Utc(utc),
+            AnyTime::Glonasst(glonasst),
+        ]
+    );
+    assert!(matches!(times[1], AnyTime::Gpst(_)));
+    assert!(
+        times
+            .windows(2)
+            .all(|pair| pair[0].to_tai() < pair[1].to_tai())
+    );
+
+    // Equality is based on the instant represented, not on the scale.
+    assert_eq!(AnyTime::Utc(utc), AnyTime::Tai(utc.into_tai()));
+    assert_eq!(
+        AnyTime::Glonasst(glonasst).to_string(),
+        "2017-01-01T03:00:01 GLONASST"
+    );
+    assert_eq!(AnyTime::Utc(utc).to_string(), "2017-01-01T00:00:00 UTC");
+}
+
+/// Verifies that each built-in abbreviation maps to its time scale, and that unknown
+/// abbreviations are rejected.
+#[test]
+fn scale_tags_from_abbreviation() {
+    let expected = [
+        ("TAI", ScaleTag::Tai),
+        ("UTC", ScaleTag::Utc),
+        ("TT", ScaleTag::Tt),
+        ("GPST", ScaleTag::Gpst),
+        ("GST", ScaleTag::Gst),
+        ("BDT", ScaleTag::Bdt),
+        ("QZSST", ScaleTag::Qzsst),
+        ("GLONASST", ScaleTag::Glonasst),
+        ("TCG", ScaleTag::Tcg),
+        ("TCB", ScaleTag::Tcb),
+        ("TDB", ScaleTag::Tdb),
+    ];
+    for (abbreviation, tag) in expected {
+        assert_eq!(ScaleTag::from_abbreviation(abbreviation), Some(tag));
+        assert_eq!(tag.abbreviation(), abbreviation);
+    }
+    assert_eq!(ScaleTag::from_abbreviation("GPS"), None);
+    assert_eq!(ScaleTag::from_abbreviation("utc"), None);
+    assert_eq!(ScaleTag::from_abbreviation(""), None);
+
+    let gpst = AnyTime::Gpst(GpsTime::epoch());
+    assert_eq!(gpst.scale_tag(), ScaleTag::Gpst);
+    assert_eq!(
+        ScaleTag::from_abbreviation(gpst.scale_tag().abbreviation()),
+        Some(ScaleTag::Gpst)
+    );
+}
@@ -54,6 +54,33 @@ impl TerrestrialTime for Bdt {
     const TAI_OFFSET: Duration = Duration::seconds(-33);
 }
 
+impl BeiDouTime {
+    /// Returns the BeiDou week number and seconds-of-week of this time point. Weeks start at
+    /// Sunday 00:00:00 BDT, and are counted since the BDT epoch of 2006-01-01. The full week
+    /// number is returned: unlike the 13-bit week number broadcast by BeiDou satellites, it does
+    /// not roll over after 8192 weeks.
+    ///
+    /// # Panics
+    /// Panics if this time point lies before the BDT epoch, or if the week number does not fit in
+    /// a `u32`.
+    #[must_use]
+    pub fn to_week_sow(&self) -> (u32, Duration) {
+        super::to_week_and_time_of_week(self.time_since_epoch(), Bdt::EPOCH, Bdt::EPOCH)
+    }
+
+    /// Constructs a BeiDou time point from a full (not rolled-over) BeiDou week number and a
+    /// seconds-of-week. The seconds-of-week are not required to lie within a single week.
+    #[must_use]
+    pub fn from_week_sow(week: u32, seconds_of_week: Duration) -> Self {
+        Self::from_time_since_epoch(super::from_week_and_time_of_week(
+            week,
+            seconds_of_week,
+            Bdt::EPOCH,
+            Bdt::EPOCH,
+        ))
+    }
+}
+
 /// Compares with a known timestamp as obtained from the definition of the BeiDou Time: the
 /// epoch itself of the system.
 #[test]
@@ -63,3 +90,21 @@ fn known_timestamps() {
     let bdt = BeiDouTime::from_historic_datetime(2006, Month::January, 1, 0, 0, 0).unwrap();
     assert_eq!(utc, bdt.into_utc());
 }
+
+/// Verifies BeiDou week numbers and seconds-of-week against a known 2020 timestamp: BDT week 0
+/// coincides with GPS week 1356.
+#[test]
+fn week_and_seconds_of_week() {
+    let new_year = BeiDouTime::from_historic_datetime(2020, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(new_year.to_week_sow(), (730, Duration::days(3)));
+    assert_eq!(BeiDouTime::from_week_sow(730, Duration::days(3)), new_year);
+    assert_eq!(new_year.into_gpst().to_week_tow().0, 730 + 1356);
+
+    let sunday = BeiDouTime::from_historic_datetime(2020, Month::January, 5, 0, 0, 0).unwrap();
+    assert_eq!(sunday.to_week_sow(), (731, Duration::ZERO));
+    assert_eq!(
+        (sunday - Duration::ATTOSECOND).to_week_sow(),
+        (730, Duration::WEEK - Duration::ATTOSECOND)
+    );
+    assert_eq!(BeiDouTime::epoch().to_week_sow(), (0, Duration::ZERO));
+}
@@ -1,5 +1,7 @@
 //! Logic related to conversions between time scales.
 
+use core::cmp::Ordering;
+
 use crate::TimePoint;
 
 /// Conversion from tie scale
@@ -36,3 +38,44 @@ where
         TimePoint::from_time_scale(self)
     }
 }
+
+impl<Scale> TimePoint<Scale> {
+    /// Compares this time point with one expressed in another time scale. This is done by first
+    /// converting `self` into the time scale of `other`, so the comparison is only as exact as
+    /// that conversion: for terrestrial time scales it is lossless. Leap seconds are accounted
+    /// for, since UTC time points are stored as continuous time since epoch.
+    #[must_use]
+    pub fn cmp_across<Other>(self, other: TimePoint<Other>) -> Ordering
+    where
+        Self: IntoTimeScale<Other>,
+    {
+        self.into_time_scale().cmp(&other)
+    }
+
+    /// Returns whether this time point refers to the same instant as one expressed in another
+    /// time scale. As with `cmp_across`, this converts `self` into the time scale of `other`.
+    #[must_use]
+    pub fn is_simultaneous_with<Other>(self, other: TimePoint<Other>) -> bool
+    where
+        Self: IntoTimeScale<Other>,
+    {
+        self.cmp_across(other) == Ordering::Equal
+    }
+}
+
+/// Verifies that instants expressed in different time scales can be compared directly.
+#[test]
+fn cross_scale_comparison() {
+    use crate::{Duration, GpsTime, Month, UtcTime};
+    let utc = UtcTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 0).unwrap();
+    let gps = GpsTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 18).unwrap();
+    assert!(gps.is_simultaneous_with(utc));
+    assert!(utc.is_simultaneous_with(gps));
+    assert_eq!(gps.cmp_across(utc), Ordering::Equal);
+
+    let leap_second =
+        UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert_eq!(leap_second.cmp_across(gps), Ordering::Less);
+    assert_eq!(gps.cmp_across(leap_second), Ordering::Greater);
+    assert!(leap_second.is_simultaneous_with(gps - Duration::seconds(1)));
+}
@@ -0,0 +1,102 @@
+//! Support for user-defined time scales that differ from TAI only by a constant offset.
+
+/// Defines a new terrestrial time scale
+///
+/// Many application-specific time scales (such as a mission elapsed time) are nothing more than a
+/// constant offset from TAI, with some convenient epoch. This macro expands to a marker type
+/// implementing `TimeScale`, `AbsoluteTimeScale`, `UniformDateTimeScale`, and `TerrestrialTime`,
+/// such that the resulting scale may be used like any of the built-in terrestrial time scales.
+///
+/// The macro takes the name of the type, its full name, its abbreviation, its epoch (a constant
+/// `Date`), and its offset with respect to TAI (a constant `Duration`). As for all terrestrial
+/// time scales, a date-time in the resulting scale equals the same date-time in TAI plus the TAI
+/// offset.
+///
+/// ```
+/// use attotime::{
+///     Date, Duration, IntoTimeScale, Month, TaiTime, TimePoint, define_terrestrial_scale,
+/// };
+///
+/// define_terrestrial_scale!(
+///     /// Mission elapsed time, starting at launch: 2020-07-30T11:50:00 TAI.
+///     pub Met,
+///     "Mission Elapsed Time",
+///     "MET",
+///     match Date::from_historic_date(2020, Month::July, 30) {
+///         Ok(epoch) => epoch,
+///         Err(_) => unreachable!(),
+///     },
+///     Duration::minutes(-(11 * 60 + 50)),
+/// );
+/// type MissionTime = TimePoint<Met>;
+///
+/// let launch = TaiTime::from_historic_datetime(2020, Month::July, 30, 11, 50, 0).unwrap();
+/// let mission_time: MissionTime = launch.into_time_scale();
+/// assert_eq!(mission_time.time_since_epoch(), Duration::seconds(0));
+/// ```
+#[macro_export]
+macro_rules! define_terrestrial_scale {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $scale:ident,
+        $name:expr,
+        $abbreviation:expr,
+        $epoch:expr,
+        $tai_offset:expr $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        $vis struct $scale;
+
+        impl $crate::TimeScale for $scale {
+            const NAME: &'static str = $name;
+
+            const ABBREVIATION: &'static str = $abbreviation;
+        }
+
+        impl $crate::AbsoluteTimeScale for $scale {
+            const EPOCH: $crate::Date = $epoch;
+        }
+
+        impl $crate::UniformDateTimeScale for $scale {}
+
+        impl $crate::TerrestrialTime for $scale {
+            const TAI_OFFSET: $crate::Duration = $tai_offset;
+        }
+    };
+}
+
+#[cfg(test)]
+define_terrestrial_scale!(
+    /// Mission elapsed time scale used for testing, with launch at 2020-07-30T11:50:00 TAI.
+    MissionElapsedTime,
+    "Mission Elapsed Time",
+    "MET",
+    match crate::Date::from_historic_date(2020, crate::Month::July, 30) {
+        Ok(epoch) => epoch,
+        Err(_) => unreachable!(),
+    },
+    crate::Duration::minutes(-(11 * 60 + 50)),
+);
+
+/// Verifies that a user-defined time scale can be round-tripped through TAI, and that date-times
+/// in that scale are mapped correctly.
+#[test]
+fn mission_elapsed_time_roundtrip() {
+    use crate::{Duration, IntoTimeScale, Month, TaiTime, TimePoint};
+    type MissionTime = TimePoint<MissionElapsedTime>;
+
+    let launch = TaiTime::from_historic_datetime(2020, Month::July, 30, 11, 50, 0).unwrap();
+    let mission_time: MissionTime = launch.into_time_scale();
+    assert_eq!(mission_time.time_since_epoch(), Duration::ZERO);
+    assert_eq!(
+        mission_time,
+        MissionTime::from_historic_datetime(2020, Month::July, 30, 0, 0, 0).unwrap()
+    );
+
+    let later = mission_time + Duration::days(3) + Duration::milliseconds(1);
+    let tai: TaiTime = later.into_time_scale();
+    assert_eq!(tai - launch, Duration::days(3) + Duration::milliseconds(1));
+    let roundtrip: MissionTime = tai.into_time_scale();
+    assert_eq!(later, roundtrip);
+}
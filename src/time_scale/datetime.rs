@@ -24,6 +24,8 @@ pub trait FromDateTime: Sized {
     /// This error may be returned whenever some input date-time is not valid. This may be the case
     /// when the time-of-day is not valid, but also when some date-time does not occur in a chosen
     /// time scale, for example due to leap seconds deletions or daylight saving time switches.
+    /// The errors of the time scales in this crate additionally implement `DateTimeError`, through
+    /// which such cases may be recognized generically.
     type Error: core::error::Error;
 
     /// Maps a given combination of date and time-of-day to an instant on this time scale.
@@ -122,7 +124,7 @@ where
             date,
             hour.try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in hour value that cannot be expressed as `u8`")),
             minute.try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in minute value that cannot be expressed as `u8`")),
-            (second / Duration::seconds(1)).try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in second value that cannot be expressed as `u8`")),
+            second.whole_seconds().try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in second value that cannot be expressed as `u8`")),
         )
     }
 }
@@ -133,3 +135,37 @@ pub trait IntoFineDateTime {
     /// Shall not fail, unless overflow occurs in the underlying integer arithmetic.
     fn into_fine_datetime(self) -> (Date, u8, u8, u8, Duration);
 }
+
+/// Verifies that a 60th second on a date without leap second is reported as nonexistent, but not
+/// as ambiguous, by every kind of time scale, while malformed time-of-days are not nonexistent.
+#[test]
+fn nonexistent_datetimes() {
+    use crate::{GlonassTime, Month, TaiTime, UtcTime, errors::DateTimeError};
+
+    fn is_nonexistent<T>(hour: u8, minute: u8, second: u8) -> bool
+    where
+        T: FromDateTime,
+        T::Error: DateTimeError,
+    {
+        let date = Date::from_historic_date(2017, Month::March, 1).unwrap();
+        let error = T::from_datetime(date, hour, minute, second)
+            .err()
+            .expect("date-time must be invalid");
+        assert!(!error.is_ambiguous());
+        error.is_nonexistent()
+    }
+
+    assert!(is_nonexistent::<UtcTime>(23, 59, 60));
+    assert!(is_nonexistent::<GlonassTime>(23, 59, 60));
+    assert!(is_nonexistent::<GlonassTime>(2, 59, 60));
+    assert!(is_nonexistent::<TaiTime>(23, 59, 60));
+
+    assert!(!is_nonexistent::<UtcTime>(24, 0, 0));
+    assert!(!is_nonexistent::<GlonassTime>(23, 60, 0));
+    assert!(!is_nonexistent::<TaiTime>(23, 59, 61));
+
+    let error = UtcTime::from_historic_datetime(2017, Month::March, 1, 23, 59, 60).unwrap_err();
+    assert!(error.is_nonexistent());
+    let error = UtcTime::from_historic_datetime(2017, Month::February, 29, 0, 0, 0).unwrap_err();
+    assert!(!error.is_nonexistent());
+}
@@ -5,7 +5,10 @@ use crate::{
     LeapSecondProvider, Second, TerrestrialTime, TimePoint,
     calendar::{Date, Month},
     errors::{InvalidGlonassDateTime, InvalidTimeOfDay},
-    time_scale::{AbsoluteTimeScale, TimeScale},
+    time_scale::{
+        AbsoluteTimeScale, TimeScale,
+        leap_seconds::{LeapSecond, leap_second_on_date},
+    },
     units::{SecondsPerDay, SecondsPerHour, SecondsPerMinute},
 };
 
@@ -25,6 +28,8 @@ impl TimeScale for Glonasst {
     const NAME: &'static str = "Glonass Time";
 
     const ABBREVIATION: &'static str = "GLONASST";
+
+    const HAS_LEAP_SECONDS: bool = true;
 }
 
 impl AbsoluteTimeScale for Glonasst {
@@ -78,9 +83,10 @@ impl FromLeapSecondDateTime for GlonassTime {
         }
 
         let utc_date = if hour < 3 { date - Days::new(1) } else { date };
-        let (is_leap_second, total_leap_seconds) =
-            leap_second_provider.leap_seconds_on_date(utc_date);
-        if second == 60 && !is_leap_second {
+        let (leap_second, total_leap_seconds) = leap_second_on_date(leap_second_provider, utc_date);
+        // Inserted leap seconds occur at 23:59:60 UTC, which is 02:59:60 GLONASST on the next
+        // date: checking only the UTC date would accept a 60th second at the end of the day.
+        if second == 60 && ((hour, minute) != (2, 59) || leap_second != LeapSecond::Inserted) {
             return Err(InvalidGlonassDateTime::NonLeapSecondDateTime {
                 date,
                 hour,
@@ -89,6 +95,16 @@ impl FromLeapSecondDateTime for GlonassTime {
             });
         }
 
+        // GLONASST runs three hours ahead of UTC, so a deleted 23:59:59 UTC maps to 02:59:59.
+        if (hour, minute, second) == (2, 59, 59) && leap_second == LeapSecond::Deleted {
+            return Err(InvalidGlonassDateTime::DeletedSecond {
+                date,
+                hour,
+                minute,
+                second,
+            });
+        }
+
         let days_since_scale_epoch = {
             let days_since_1970 = date.time_since_epoch();
             let epoch_days_since_1970 = Glonasst::EPOCH.time_since_epoch();
@@ -140,9 +156,11 @@ impl IntoLeapSecondDateTime for GlonassTime {
             Glonasst::EPOCH.time_since_epoch() + days_since_scale_epoch;
         let date = Date::from_time_since_epoch(days_since_universal_epoch);
 
+        // GLONASST runs three hours ahead of UTC, so an inserted 23:59:60 UTC maps to 02:59:60 on
+        // the next civil date. Numerically, that leap second coincides with 03:00:00 on the same
+        // date, as computed above, so only the time-of-day must be adjusted.
         if is_leap_second {
-            let date = date - Days::new(1);
-            (date, 23, 59, 60)
+            (date, 2, 59, 60)
         } else {
             (
             // We must narrow-cast all results, but only the cast of `date` may fail. The rest will
@@ -150,7 +168,7 @@ impl IntoLeapSecondDateTime for GlonassTime {
             date,
             hour.try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in hour value that cannot be expressed as `u8`")),
             minute.try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in minute value that cannot be expressed as `u8`")),
-            (second / Duration::seconds(1)).try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in second value that cannot be expressed as `u8`")),
+            second.whole_seconds().try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in second value that cannot be expressed as `u8`")),
         )
         }
     }
@@ -202,3 +220,51 @@ fn date_decomposition() {
     date_roundtrip(1643, Month::January, 4, 1, 1, 33);
     date_roundtrip(1996, Month::January, 1, 3, 0, 0);
 }
+
+/// Verifies that GLONASS date-times round-trip around a leap second. Since GLONASST runs three
+/// hours ahead of UTC, the UTC leap second at 2016-12-31T23:59:60 is inserted at
+/// 2017-01-01T02:59:60 GLONASST, after the civil GLONASS date has already crossed midnight.
+#[test]
+fn leap_second_day_boundary() {
+    use crate::UtcTime;
+    let utc = |day, hour, minute, second| {
+        let (year, month) = if day == 31 {
+            (2016, Month::December)
+        } else {
+            (2017, Month::January)
+        };
+        UtcTime::from_historic_datetime(year, month, day, hour, minute, second).unwrap()
+    };
+    let glonasst = |hour, minute, second| {
+        GlonassTime::from_historic_datetime(2017, Month::January, 1, hour, minute, second).unwrap()
+    };
+
+    assert_eq!(glonasst(1, 30, 0).into_utc(), utc(31, 22, 30, 0));
+    assert_eq!(glonasst(2, 59, 59).into_utc(), utc(31, 23, 59, 59));
+    assert_eq!(glonasst(2, 59, 60).into_utc(), utc(31, 23, 59, 60));
+    assert_eq!(glonasst(3, 0, 0).into_utc(), utc(1, 0, 0, 0));
+    assert_eq!(
+        glonasst(3, 0, 0) - glonasst(2, 59, 59),
+        Duration::seconds(2)
+    );
+    assert_eq!(utc(31, 23, 59, 60).into_glonasst(), glonasst(2, 59, 60));
+
+    date_roundtrip(2017, Month::January, 1, 0, 0, 0);
+    date_roundtrip(2017, Month::January, 1, 1, 30, 0);
+    date_roundtrip(2017, Month::January, 1, 2, 59, 59);
+    date_roundtrip(2017, Month::January, 1, 2, 59, 60);
+    date_roundtrip(2017, Month::January, 1, 3, 0, 0);
+    date_roundtrip(2016, Month::December, 31, 23, 59, 59);
+
+    let leap_second = glonasst(2, 59, 60) + Duration::milliseconds(500);
+    let (date, hour, minute, second, subseconds) = leap_second.into_fine_historic_datetime();
+    assert_eq!(
+        date,
+        crate::HistoricDate::new(2017, Month::January, 1).unwrap()
+    );
+    assert_eq!((hour, minute, second), (2, 59, 60));
+    assert_eq!(subseconds, Duration::milliseconds(500));
+
+    // The UTC leap second date carries no leap second in GLONASST.
+    assert!(GlonassTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).is_err());
+}
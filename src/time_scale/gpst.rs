@@ -53,6 +53,33 @@ impl TerrestrialTime for Gpst {
     const TAI_OFFSET: Duration = Duration::seconds(-19);
 }
 
+impl GpsTime {
+    /// Returns the GPS week number and time-of-week of this time point, as commonly used by GNSS
+    /// receivers. Weeks start at Sunday 00:00:00 GPST, and are counted since the GPS epoch of
+    /// 1980-01-06. The full week number is returned: unlike the 10-bit (or 13-bit) week number
+    /// broadcast by GPS satellites, it does not roll over after 1024 (or 8192) weeks.
+    ///
+    /// # Panics
+    /// Panics if this time point lies before the GPS epoch, or if the week number does not fit in
+    /// a `u32`.
+    #[must_use]
+    pub fn to_week_tow(&self) -> (u32, Duration) {
+        super::to_week_and_time_of_week(self.time_since_epoch(), Gpst::EPOCH, Gpst::EPOCH)
+    }
+
+    /// Constructs a GPS time point from a full (not rolled-over) GPS week number and a
+    /// time-of-week. The time-of-week is not required to lie within a single week.
+    #[must_use]
+    pub fn from_week_tow(week: u32, time_of_week: Duration) -> Self {
+        Self::from_time_since_epoch(super::from_week_and_time_of_week(
+            week,
+            time_of_week,
+            Gpst::EPOCH,
+            Gpst::EPOCH,
+        ))
+    }
+}
+
 #[allow(clippy::doc_markdown, reason = "False positive on McClain")]
 /// Compares with a known timestamp as obtained from Vallado and McClain's "Fundamentals of
 /// Astrodynamics".
@@ -63,3 +90,61 @@ fn known_timestamps() {
     let gpst = GpsTime::from_historic_datetime(2004, Month::May, 14, 16, 43, 13).unwrap();
     assert_eq!(tai, gpst.into_tai());
 }
+
+/// Verifies that the epoch of GPS time is 1980-01-06T00:00:00 GPST, which is 19 seconds after
+/// the same date-time in TAI.
+#[test]
+fn epoch() {
+    use crate::{Duration, TaiTime, TimePoint};
+    let epoch = TaiTime::from_historic_datetime(1980, Month::January, 6, 0, 0, 0).unwrap();
+    assert_eq!(GpsTime::epoch().into_tai(), epoch + Duration::seconds(19));
+    assert_eq!(GpsTime::epoch().time_since_epoch(), Duration::ZERO);
+    assert_eq!(
+        GpsTime::epoch_date(),
+        Date::from_historic_date(1980, Month::January, 6).unwrap()
+    );
+    assert_eq!(
+        GpsTime::epoch(),
+        TimePoint::from_historic_datetime(1980, Month::January, 6, 0, 0, 0).unwrap()
+    );
+}
+
+/// Verifies that the time elapsed since the GPS epoch may be obtained in arbitrary units.
+#[test]
+fn since_epoch_units() {
+    use crate::{Second, SecondsPerDay, SecondsPerHour};
+    let time_point = GpsTime::from_historic_datetime(1980, Month::January, 7, 12, 30, 0).unwrap();
+    assert_eq!(GpsTime::epoch().since_epoch_units::<Second>(), 0);
+    assert_eq!(time_point.since_epoch_units::<Second>(), 131_400);
+    assert_eq!(time_point.since_epoch_units::<SecondsPerHour>(), 36);
+    assert_eq!(time_point.since_epoch_units::<SecondsPerDay>(), 1);
+    assert!(
+        (time_point.since_epoch_units_f64::<SecondsPerDay>() - 1.520_833_333_333_333).abs() < 1e-12
+    );
+    let before = GpsTime::epoch() - Duration::hours(36);
+    assert_eq!(before.since_epoch_units::<SecondsPerDay>(), -1);
+    assert!((before.since_epoch_units_f64::<SecondsPerDay>() + 1.5).abs() < 1e-12);
+}
+
+/// Verifies GPS week numbers and time-of-week against known values, including the boundary at
+/// Saturday/Sunday midnight.
+#[test]
+fn week_and_time_of_week() {
+    let new_year = GpsTime::from_historic_datetime(2020, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(new_year.to_week_tow(), (2086, Duration::days(3)));
+    assert_eq!(GpsTime::from_week_tow(2086, Duration::days(3)), new_year);
+
+    let saturday = GpsTime::from_historic_datetime(2020, Month::January, 4, 23, 59, 59).unwrap();
+    assert_eq!(saturday.to_week_tow(), (2086, Duration::seconds(604_799)));
+    let sunday = GpsTime::from_historic_datetime(2020, Month::January, 5, 0, 0, 0).unwrap();
+    assert_eq!(sunday.to_week_tow(), (2087, Duration::ZERO));
+    assert_eq!(GpsTime::from_week_tow(2087, Duration::ZERO), sunday);
+    assert_eq!(GpsTime::from_week_tow(2086, Duration::WEEK), sunday);
+
+    let just_before = sunday - Duration::ATTOSECOND;
+    assert_eq!(
+        just_before.to_week_tow(),
+        (2086, Duration::WEEK - Duration::ATTOSECOND)
+    );
+    assert_eq!(GpsTime::epoch().to_week_tow(), (0, Duration::ZERO));
+}
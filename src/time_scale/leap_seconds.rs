@@ -1,7 +1,7 @@
 //! Leap seconds are applied when converting date-time pairs to underlying time scales, to better
 //! align those time scales with the human-centric time based on the Earth's rotation (UT1).
 
-use crate::{Date, Duration, FromDateTime, IntoDateTime, UtcTime};
+use crate::{Date, Days, FromDateTime, IntoDateTime, UtcTime};
 
 /// Provider of leap second information
 ///
@@ -17,14 +17,87 @@ use crate::{Date, Duration, FromDateTime, IntoDateTime, UtcTime};
 /// it for a table that updates based on the published IANA list, on GNSS constellation navigation
 /// messages, or custom telecommands (for spacecraft, for example).
 pub trait LeapSecondProvider {
-    /// For any given date (expressed in UTC), determines whether a leap second was inserted at the
-    /// end of that day. In tandem, returns the accumulated number of leap seconds before (!) that
+    /// For any given date (expressed in UTC), determines whether a leap second occurs at the end
+    /// of that day. In tandem, returns the accumulated number of leap seconds before (!) that
     /// date.
+    ///
+    /// Whether such a leap second is inserted or deleted follows from the accumulated count of the
+    /// next day: if it is lower than that of the given date, the leap second is negative and the
+    /// final second of the day (23:59:59) does not exist. Otherwise, a 23:59:60 is inserted.
     fn leap_seconds_on_date(&self, utc_date: Date) -> (bool, i32);
 
     /// Given some UTC time, returns the number of leap seconds that apply, and whether the
-    /// requested date-time is a leap second (exactly).
+    /// requested date-time is an inserted leap second (exactly). Since deleted leap seconds do not
+    /// exist as UTC time-of-day, they are never reported here: the leap second count simply
+    /// decreases at the start of the next day.
     fn leap_seconds_at_time(&self, utc_time: UtcTime) -> (bool, i32);
+
+    /// Returns the first date strictly after the given UTC date that ends in a leap second. If no
+    /// such date is known to this provider (for example, because it lies beyond the range of its
+    /// table), returns `None`. The default implementation always returns `None`, so providers
+    /// that are able to look ahead should override it.
+    fn next_leap_second_after(&self, utc_date: Date) -> Option<Date> {
+        let _ = utc_date;
+        None
+    }
+
+    /// Returns the last date strictly before the given UTC date that ends in a leap second. If no
+    /// such date is known to this provider, returns `None`. The default implementation always
+    /// returns `None`, so providers that are able to look back should override it.
+    fn previous_leap_second_before(&self, utc_date: Date) -> Option<Date> {
+        let _ = utc_date;
+        None
+    }
+
+    /// Iterates over all leap seconds known to this provider, in ascending order of date. Yields
+    /// each date that ends in a leap second, together with the accumulated number of leap seconds
+    /// after that date. The default implementation walks the dates returned by
+    /// `next_leap_second_after`, so it yields nothing for providers that do not override it.
+    fn iter_leap_seconds(&self) -> impl Iterator<Item = (Date, i32)>
+    where
+        Self: Sized,
+    {
+        let first_date = Date::from_time_since_epoch(Days::new(i32::MIN));
+        core::iter::successors(self.next_leap_second_after(first_date), |&date| {
+            self.next_leap_second_after(date)
+        })
+        .filter_map(|date| {
+            let next_date = date.step_forward(Days::new(1))?;
+            Some((date, self.leap_seconds_on_date(next_date).1))
+        })
+    }
+}
+
+/// Kind of leap second that occurs at the end of some UTC date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LeapSecond {
+    None,
+    Inserted,
+    Deleted,
+}
+
+/// Determines the kind of leap second (if any) at the end of the given UTC date.
+///
+/// Follows the contract of `LeapSecondProvider::leap_seconds_on_date`. Also returns the
+/// accumulated number of leap seconds before that date.
+pub fn leap_second_on_date(
+    leap_second_provider: &impl LeapSecondProvider,
+    utc_date: Date,
+) -> (LeapSecond, i32) {
+    let (is_leap_second, leap_seconds) = leap_second_provider.leap_seconds_on_date(utc_date);
+    if !is_leap_second {
+        return (LeapSecond::None, leap_seconds);
+    }
+
+    let next_leap_seconds = utc_date
+        .step_forward(Days::new(1))
+        .map(|next_date| leap_second_provider.leap_seconds_on_date(next_date).1);
+    match next_leap_seconds {
+        Some(next_leap_seconds) if next_leap_seconds < leap_seconds => {
+            (LeapSecond::Deleted, leap_seconds)
+        }
+        _ => (LeapSecond::Inserted, leap_seconds),
+    }
 }
 
 /// This trait is the leap second equivalent of `FromDateTime`. It permits the creation of time
@@ -49,6 +122,18 @@ pub trait FromLeapSecondDateTime: Sized {
     ) -> Result<Self, Self::Error>;
 }
 
+/// This trait is the leap second equivalent of `IntoDateTime`. It permits the retrieval of
+/// date-times from time points when a non-standard leap second provider must be used.
+pub trait IntoLeapSecondDateTime: IntoDateTime {
+    /// Maps a time point back to the date and time-of-day that it represents. Returns a tuple of
+    /// date, hour, minute, and second. This function shall not fail, unless overflow occurs in the
+    /// underlying integer arithmetic.
+    ///
+    /// Takes a leap second provider as additional argument, which is used to determine at which
+    /// times leap seconds are inserted or deleted.
+    fn into_datetime(self, leap_second_provider: &impl LeapSecondProvider) -> (Date, u8, u8, u8);
+}
+
 /// We provide a default implementation that uses the static leap second provider.
 impl<TimePoint> FromDateTime for TimePoint
 where
@@ -67,18 +152,6 @@ where
     }
 }
 
-/// This trait is the leap second equivalent of `IntoDateTime`. It permits the retrieval of
-/// date-times from time points when a non-standard leap second provider must be used.
-pub trait IntoLeapSecondDateTime: IntoDateTime {
-    /// Maps a time point back to the date and time-of-day that it represents. Returns a tuple of
-    /// date, hour, minute, and second. This function shall not fail, unless overflow occurs in the
-    /// underlying integer arithmetic.
-    ///
-    /// Takes a leap second provider as additional argument, which is used to determine at which
-    /// times leap seconds are inserted or deleted.
-    fn into_datetime(self, leap_second_provider: &impl LeapSecondProvider) -> (Date, u8, u8, u8);
-}
-
 /// We provide a default implementation that uses the static leap second provider.
 impl<TimePoint> IntoDateTime for TimePoint
 where
@@ -100,6 +173,16 @@ pub struct StaticLeapSecondProvider {}
 /// Convenience constant that may be used to directly obtain a `StaticLeapSecondProvider` object.
 pub const STATIC_LEAP_SECOND_PROVIDER: StaticLeapSecondProvider = StaticLeapSecondProvider {};
 
+/// Dates (expressed as days since 1970-01-01) at the end of which the static leap second table
+/// inserts a leap second, in chronological order. Mirrors the jump tables below, which is verified
+/// by the `static_leap_second_dates_match_tables` test. The jump tables additionally treat
+/// 1971-12-31 as a leap second, but that entry only establishes the 10 seconds offset that applies
+/// at the start of 1972, so it is not listed here.
+const STATIC_LEAP_SECOND_DATES: [i32; 27] = [
+    911, 1095, 1460, 1825, 2190, 2556, 2921, 3286, 3651, 4198, 4563, 4928, 5659, 6573, 7304, 7669,
+    8216, 8581, 8946, 9495, 10042, 10591, 13148, 14244, 15521, 16616, 17166,
+];
+
 impl LeapSecondProvider for StaticLeapSecondProvider {
     /// For the static leap seconds provider, we just use a generated jump table that maps from
     /// days (expressed as `Date`, i.e., `Days` since 1970-01-01) to whether that day
@@ -175,7 +258,7 @@ impl LeapSecondProvider for StaticLeapSecondProvider {
     /// applied only after the leap second itself: during a leap second, the count is still the
     /// same as before.
     fn leap_seconds_at_time(&self, utc_time: UtcTime) -> (bool, i32) {
-        let seconds_since_1972_01_01 = utc_time.time_since_epoch() / Duration::seconds(1);
+        let seconds_since_1972_01_01 = utc_time.time_since_epoch().whole_seconds();
         let (is_leap_second, leap_seconds) = match seconds_since_1972_01_01 {
             1_420_156_837.. => (false, 37),
             1_420_156_836 => (true, 36),
@@ -237,4 +320,176 @@ impl LeapSecondProvider for StaticLeapSecondProvider {
         };
         (is_leap_second, leap_seconds)
     }
+
+    /// Looks up the next leap second in the static table. Since the table only contains leap
+    /// seconds that were announced at build time, `None` is returned for any date after the last
+    /// entry.
+    fn next_leap_second_after(&self, utc_date: Date) -> Option<Date> {
+        let days_since_1970_01_01 = utc_date.time_since_epoch().count();
+        STATIC_LEAP_SECOND_DATES
+            .iter()
+            .find(|&&days| days > days_since_1970_01_01)
+            .map(|&days| Date::from_time_since_epoch(Days::new(days)))
+    }
+
+    /// Looks up the previous leap second in the static table. Returns `None` for any date on or
+    /// before 1972-06-30, the first leap second.
+    fn previous_leap_second_before(&self, utc_date: Date) -> Option<Date> {
+        let days_since_1970_01_01 = utc_date.time_since_epoch().count();
+        STATIC_LEAP_SECOND_DATES
+            .iter()
+            .rev()
+            .find(|&&days| days < days_since_1970_01_01)
+            .map(|&days| Date::from_time_since_epoch(Days::new(days)))
+    }
+
+    /// Iterates directly over the static table, starting from the 10 seconds that apply at the
+    /// start of 1972.
+    fn iter_leap_seconds(&self) -> impl Iterator<Item = (Date, i32)>
+    where
+        Self: Sized,
+    {
+        STATIC_LEAP_SECOND_DATES
+            .iter()
+            .zip(11..)
+            .map(|(&days, leap_seconds)| {
+                (Date::from_time_since_epoch(Days::new(days)), leap_seconds)
+            })
+    }
+}
+
+/// Verifies that the static leap second table can be used to look up the next and previous leap
+/// seconds around a given date.
+#[test]
+fn next_and_previous_leap_seconds() {
+    use crate::Month;
+    let provider = StaticLeapSecondProvider {};
+    let date = |year, month, day| Date::from_historic_date(year, month, day).unwrap();
+
+    assert_eq!(
+        provider.next_leap_second_after(date(2015, Month::July, 1)),
+        Some(date(2016, Month::December, 31))
+    );
+    assert_eq!(
+        provider.next_leap_second_after(date(2015, Month::June, 30)),
+        Some(date(2016, Month::December, 31))
+    );
+    assert_eq!(
+        provider.next_leap_second_after(date(2015, Month::June, 29)),
+        Some(date(2015, Month::June, 30))
+    );
+    assert_eq!(
+        provider.next_leap_second_after(date(1960, Month::January, 1)),
+        Some(date(1972, Month::June, 30))
+    );
+    assert_eq!(
+        provider.next_leap_second_after(date(2016, Month::December, 31)),
+        None
+    );
+
+    assert_eq!(
+        provider.previous_leap_second_before(date(2015, Month::July, 1)),
+        Some(date(2015, Month::June, 30))
+    );
+    assert_eq!(
+        provider.previous_leap_second_before(date(2025, Month::January, 1)),
+        Some(date(2016, Month::December, 31))
+    );
+    assert_eq!(
+        provider.previous_leap_second_before(date(1972, Month::July, 1)),
+        Some(date(1972, Month::June, 30))
+    );
+    assert_eq!(
+        provider.previous_leap_second_before(date(1972, Month::June, 30)),
+        None
+    );
+}
+
+/// Verifies that the list of static leap second dates agrees with both jump tables: every listed
+/// date, and no other date since the start of 1972, ends in a leap second, and the leap second
+/// count increases by one after each of them.
+#[test]
+fn static_leap_second_dates_match_tables() {
+    let provider = StaticLeapSecondProvider {};
+    for days in 730..=STATIC_LEAP_SECOND_DATES[26] + 1 {
+        let date = Date::from_time_since_epoch(Days::new(days));
+        assert_eq!(
+            provider.leap_seconds_on_date(date).0,
+            STATIC_LEAP_SECOND_DATES.contains(&days),
+            "{date:?}"
+        );
+    }
+
+    for (days, leap_seconds) in STATIC_LEAP_SECOND_DATES.into_iter().zip(10..) {
+        let date = Date::from_time_since_epoch(Days::new(days));
+        let next_date = Date::from_time_since_epoch(Days::new(days + 1));
+        assert_eq!(provider.leap_seconds_on_date(date), (true, leap_seconds));
+        assert_eq!(
+            provider.leap_seconds_on_date(next_date),
+            (false, leap_seconds + 1)
+        );
+
+        let leap_second = UtcTime::from_datetime(date, 23, 59, 60).unwrap();
+        let before = UtcTime::from_datetime(date, 23, 59, 59).unwrap();
+        let after = UtcTime::from_datetime(next_date, 0, 0, 0).unwrap();
+        assert_eq!(provider.leap_seconds_at_time(before), (false, leap_seconds));
+        assert_eq!(
+            provider.leap_seconds_at_time(leap_second),
+            (true, leap_seconds)
+        );
+        assert_eq!(
+            provider.leap_seconds_at_time(after),
+            (false, leap_seconds + 1)
+        );
+    }
+}
+
+/// Verifies that the static leap second table yields the 27 leap seconds inserted since 1972, and
+/// that the default iteration over a provider agrees with it.
+#[test]
+fn iterate_leap_seconds() {
+    use crate::Month;
+
+    /// Provider that only implements the required lookups, to exercise the default iteration.
+    struct LookAheadProvider;
+
+    impl LeapSecondProvider for LookAheadProvider {
+        fn leap_seconds_on_date(&self, utc_date: Date) -> (bool, i32) {
+            STATIC_LEAP_SECOND_PROVIDER.leap_seconds_on_date(utc_date)
+        }
+
+        fn leap_seconds_at_time(&self, utc_time: UtcTime) -> (bool, i32) {
+            STATIC_LEAP_SECOND_PROVIDER.leap_seconds_at_time(utc_time)
+        }
+
+        fn next_leap_second_after(&self, utc_date: Date) -> Option<Date> {
+            STATIC_LEAP_SECOND_PROVIDER.next_leap_second_after(utc_date)
+        }
+    }
+
+    let leap_seconds = || STATIC_LEAP_SECOND_PROVIDER.iter_leap_seconds();
+    assert_eq!(leap_seconds().count(), 27);
+    assert_eq!(
+        leap_seconds().next(),
+        Some((Date::from_historic_date(1972, Month::June, 30).unwrap(), 11))
+    );
+    assert_eq!(
+        leap_seconds().last(),
+        Some((
+            Date::from_historic_date(2016, Month::December, 31).unwrap(),
+            37
+        ))
+    );
+    assert!(
+        leap_seconds()
+            .zip(leap_seconds().skip(1))
+            .all(|(previous, next)| previous.0 < next.0 && previous.1 + 1 == next.1)
+    );
+
+    assert!(leap_seconds().eq(LookAheadProvider.iter_leap_seconds()));
+
+    // Providers must remain usable as trait objects.
+    let provider: &dyn LeapSecondProvider = &LookAheadProvider;
+    let first_insertion = Date::from_historic_date(1972, Month::June, 30).unwrap();
+    assert!(provider.leap_seconds_on_date(first_insertion).0);
 }
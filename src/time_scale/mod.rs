@@ -1,7 +1,10 @@
 //! Implementation of timekeeping according to different time scales.
 
+mod any;
+pub use any::{AnyTime, ScaleTag};
 mod convert;
 pub use convert::{FromTimeScale, IntoTimeScale};
+mod custom;
 mod datetime;
 pub use datetime::{
     FromDateTime, FromFineDateTime, IntoDateTime, IntoFineDateTime, UniformDateTimeScale,
@@ -17,8 +20,8 @@ mod gst;
 pub use gst::{GalileoTime, Gst};
 mod leap_seconds;
 pub use leap_seconds::{
-    FromLeapSecondDateTime, IntoLeapSecondDateTime, LeapSecondProvider,
-    STATIC_LEAP_SECOND_PROVIDER, StaticLeapSecondProvider,
+    FromLeapSecondDateTime, IntoLeapSecondDateTime, LeapSecond, LeapSecondProvider,
+    STATIC_LEAP_SECOND_PROVIDER, StaticLeapSecondProvider, leap_second_on_date,
 };
 mod qzsst;
 pub use qzsst::{QzssTime, Qzsst};
@@ -29,7 +32,7 @@ pub use tcg::{Tcg, TcgTime};
 mod tcb;
 pub use tcb::{Tcb, TcbTime};
 mod tdb;
-pub use tdb::{Tdb, TdbTime};
+pub use tdb::{Tdb, TdbModel, TdbTime};
 mod tt;
 pub use tt::{Tt, TtTime};
 mod terrestrial_time;
@@ -37,7 +40,7 @@ pub use terrestrial_time::TerrestrialTime;
 mod utc;
 pub use utc::{Utc, UtcTime};
 
-use crate::Date;
+use crate::{Date, Duration};
 
 /// A `TimeScale` identifies the relativistic time scale in which some `TimePoint` is expressed.
 pub trait TimeScale {
@@ -46,6 +49,16 @@ pub trait TimeScale {
 
     /// The abbreviated string used to represent this time scale.
     const ABBREVIATION: &'static str;
+
+    /// Whether leap seconds are inserted into (or removed from) this time scale, such that a
+    /// minute may contain a 60th second. Generic code may use this to determine whether `:60` can
+    /// appear in the date-time representation of a time point.
+    const HAS_LEAP_SECONDS: bool = false;
+
+    /// Whether this time scale advances uniformly with respect to its date-time representation,
+    /// i.e., whether every day consists of exactly 86400 seconds. By default, this is the case for
+    /// all time scales without leap seconds.
+    const IS_CONTINUOUS: bool = !Self::HAS_LEAP_SECONDS;
 }
 
 /// Absolute time scale
@@ -65,3 +78,35 @@ pub trait AbsoluteTimeScale: TimeScale {
     /// course, it is more convenient to choose the actual epoch where one is defined.
     const EPOCH: Date;
 }
+
+/// Splits the time elapsed since `scale_epoch` into a GNSS-style week number and time-of-week,
+/// with weeks counted from `week_epoch`. Both epochs must fall on the start of a week.
+///
+/// # Panics
+/// Panics if the time point lies before `week_epoch`, or if the week number does not fit in a
+/// `u32`.
+fn to_week_and_time_of_week(
+    time_since_scale_epoch: Duration,
+    scale_epoch: Date,
+    week_epoch: Date,
+) -> (u32, Duration) {
+    let epoch_offset: Duration = scale_epoch.elapsed_calendar_days_since(week_epoch).into();
+    let time_since_week_epoch = time_since_scale_epoch + epoch_offset;
+    let whole_weeks = time_since_week_epoch.floor_to(Duration::WEEK);
+    let time_of_week = time_since_week_epoch - whole_weeks;
+    let weeks =
+        u32::try_from(whole_weeks / Duration::WEEK).expect("week number must fit in a `u32`");
+    (weeks, time_of_week)
+}
+
+/// Inverse of `to_week_and_time_of_week`: returns the time elapsed since `scale_epoch` for the
+/// given week number, counted from `week_epoch`, and time-of-week.
+fn from_week_and_time_of_week(
+    week: u32,
+    time_of_week: Duration,
+    scale_epoch: Date,
+    week_epoch: Date,
+) -> Duration {
+    let epoch_offset: Duration = scale_epoch.elapsed_calendar_days_since(week_epoch).into();
+    Duration::WEEK * week + time_of_week - epoch_offset
+}
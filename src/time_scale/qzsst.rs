@@ -2,7 +2,7 @@
 //! Quasi-Zenith Satellite System constellation.
 
 use crate::{
-    Date, Duration, FromTimeScale, IntoTimeScale, Month, TerrestrialTime, TimePoint,
+    Date, Duration, FromTimeScale, Gpst, IntoTimeScale, Month, TerrestrialTime, TimePoint,
     UniformDateTimeScale,
     time_scale::{AbsoluteTimeScale, TimeScale},
 };
@@ -54,6 +54,32 @@ impl TerrestrialTime for Qzsst {
     const TAI_OFFSET: Duration = Duration::seconds(-19);
 }
 
+impl QzssTime {
+    /// Returns the QZSS week number and time-of-week of this time point. QZSS weeks are aligned
+    /// with GPS weeks: they start at Sunday 00:00:00, and are counted since the GPS epoch of
+    /// 1980-01-06, not since the QZSST epoch. The full week number is returned, without rollover.
+    ///
+    /// # Panics
+    /// Panics if this time point lies before the GPS epoch, or if the week number does not fit in
+    /// a `u32`.
+    #[must_use]
+    pub fn to_week_tow(&self) -> (u32, Duration) {
+        super::to_week_and_time_of_week(self.time_since_epoch(), Qzsst::EPOCH, Gpst::EPOCH)
+    }
+
+    /// Constructs a QZSS time point from a full (not rolled-over) week number, aligned with GPS
+    /// weeks, and a time-of-week. The time-of-week is not required to lie within a single week.
+    #[must_use]
+    pub fn from_week_tow(week: u32, time_of_week: Duration) -> Self {
+        Self::from_time_since_epoch(super::from_week_and_time_of_week(
+            week,
+            time_of_week,
+            Qzsst::EPOCH,
+            Gpst::EPOCH,
+        ))
+    }
+}
+
 #[allow(clippy::doc_markdown, reason = "False positive on McClain")]
 /// Compares with a known timestamp as obtained from Vallado and McClain's "Fundamentals of
 /// Astrodynamics". Note that that timestamp is given for GPS time: QZSS time is always aligned
@@ -65,3 +91,13 @@ fn known_timestamps() {
     let qzsst = QzssTime::from_historic_datetime(2004, Month::May, 14, 16, 43, 13).unwrap();
     assert_eq!(tai, qzsst.into_tai());
 }
+
+/// Verifies that QZSS week numbers and time-of-week agree with those of GPS.
+#[test]
+fn week_and_time_of_week() {
+    let new_year = QzssTime::from_historic_datetime(2020, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(new_year.to_week_tow(), (2086, Duration::days(3)));
+    assert_eq!(new_year.to_week_tow(), new_year.into_gpst().to_week_tow());
+    assert_eq!(QzssTime::from_week_tow(2086, Duration::days(3)), new_year);
+    assert_eq!(QzssTime::epoch().to_week_tow(), (1024, Duration::ZERO));
+}
@@ -1,9 +1,7 @@
 //! Implementation of International Atomic Time (TAI).
 
-use num_traits::ConstZero;
-
 use crate::{
-    Date, Duration, FromTimeScale, IntoTimeScale, Month, TimePoint,
+    Date, Duration, FromTimeScale, IntoTimeScale, Month, Second, TimePoint,
     time_scale::{AbsoluteTimeScale, TerrestrialTime, TimeScale, datetime::UniformDateTimeScale},
 };
 
@@ -50,6 +48,27 @@ impl<Scale: ?Sized> TimePoint<Scale> {
     }
 }
 
+impl TaiTime {
+    /// The Unix epoch, 1970-01-01T00:00:00 UTC, expressed in TAI. Since this library applies the
+    /// 9 leap seconds of its table before 1972 to all earlier UTC date-times, this corresponds with
+    /// 1970-01-01T00:00:09 TAI.
+    pub const EPOCH_UNIX: Self = Self::from_time_since_epoch(Duration::seconds(378_691_209));
+
+    /// The J2000 epoch, defined as 2000-01-01T12:00:00 TT, expressed in TAI: this corresponds with
+    /// 2000-01-01T11:59:27.816 TAI.
+    pub const J2000: Self = Self::from_time_since_epoch(Duration::milliseconds(1_325_419_167_816));
+
+    /// Constructs a `TaiTime` from the number of SI seconds elapsed since the J2000 epoch, as is
+    /// common in astrodynamics. The result is exact up to the precision of the `f64` itself.
+    ///
+    /// # Panics
+    /// Panics if `seconds` is not finite, or if the resulting time point is not representable.
+    #[must_use]
+    pub fn from_j2000_seconds(seconds: f64) -> Self {
+        Self::J2000 + Duration::from_float::<f64, Second>(seconds)
+    }
+}
+
 impl TerrestrialTime for Tai {
     const TAI_OFFSET: Duration = Duration::ZERO;
 }
@@ -154,3 +173,39 @@ fn date_decomposition() {
     gregorian_datetime_roundtrip(1643, Month::January, 4, 1, 1, 33);
     gregorian_datetime_roundtrip(1996, Month::January, 1, 3, 0, 0);
 }
+
+/// Verifies the TAI epoch constants against their definitions in other time scales, and that
+/// seconds since J2000 are counted from the correct instant.
+#[test]
+fn epoch_constants() {
+    use crate::TtTime;
+    assert_eq!(
+        TaiTime::J2000.into_tt(),
+        TtTime::from_historic_datetime(2000, Month::January, 1, 12, 0, 0).unwrap()
+    );
+    assert_eq!(
+        TaiTime::EPOCH_UNIX,
+        crate::UtcTime::from_unix_timestamp(0).into_tai()
+    );
+    assert_eq!(
+        TaiTime::EPOCH_UNIX,
+        TaiTime::from_historic_datetime(1970, Month::January, 1, 0, 0, 9).unwrap()
+    );
+    assert_eq!(TaiTime::from_j2000_seconds(0.0), TaiTime::J2000);
+    assert_eq!(
+        TaiTime::from_j2000_seconds(-43_200.5),
+        TaiTime::J2000 - Duration::hours(12) - Duration::milliseconds(500)
+    );
+}
+
+/// Verifies that the J2000 epoch is printed as the expected date-time in TT and TAI.
+#[cfg(feature = "std")]
+#[test]
+fn j2000_formatting() {
+    assert_eq!(
+        TaiTime::J2000.into_tt().to_string(),
+        "2000-01-01T12:00:00 TT"
+    );
+    assert_eq!(TaiTime::J2000.to_string(), "2000-01-01T11:59:27.816 TAI");
+    assert_eq!(TaiTime::EPOCH_UNIX.to_string(), "1970-01-01T00:00:09 TAI");
+}
@@ -132,6 +132,22 @@ fn check_roundtrip() {
     }
 }
 
+/// Verifies that conversion to and from TCG/TT is near-identity over several centuries, where
+/// the accumulated rate difference makes rounding errors possible.
+#[test]
+fn check_roundtrip_within_tolerance() {
+    use rand::prelude::*;
+    let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(45);
+    let centuries = Duration::days(36_525 * 5).count();
+    for _ in 0..10_000 {
+        let attoseconds_since_epoch = rng.random_range(-centuries..centuries);
+        let time_since_epoch = Duration::attoseconds(attoseconds_since_epoch);
+        let tt = TtTime::from_time_since_epoch(time_since_epoch);
+        let tcg: TcgTime = TcgTime::from_tt(tt);
+        assert!(tcg.into_tt().approx_eq(tt, Duration::ATTOSECOND));
+    }
+}
+
 #[cfg(kani)]
 mod proof_harness {
     use super::*;
@@ -2,7 +2,8 @@
 //! a clock at rest in a coordinate frame co-moving with the barycentre of the Solar system.
 
 use crate::{
-    Date, Duration, FromTimeScale, IntoTimeScale, Month, Tcb, TcbTime, TimePoint, TtTime,
+    Date, Duration, FromTimeScale, IntoTimeScale, Month, Second, Tcb, TcbTime, TerrestrialTime,
+    TimePoint, TtTime,
     time_scale::{AbsoluteTimeScale, TimeScale, datetime::UniformDateTimeScale},
 };
 
@@ -79,32 +80,128 @@ impl FromTimeScale<Tdb> for TcbTime {
     }
 }
 
+impl<Scale> FromTimeScale<Scale> for TdbTime
+where
+    Scale: TerrestrialTime,
+{
+    /// Converts from a terrestrial time scale via TT, using `TtTime::approximate_tdb`. Hence, the
+    /// result is accurate only to 50 microseconds from 1980 to 2100.
+    fn from_time_scale(time_point: TimePoint<Scale>) -> Self {
+        time_point.into_tt().approximate_tdb()
+    }
+}
+
+impl<Scale> FromTimeScale<Tdb> for TimePoint<Scale>
+where
+    Scale: TerrestrialTime,
+{
+    /// Converts into a terrestrial time scale via TT, using `TdbTime::approximate_tt`. Hence, the
+    /// result is accurate only to 50 microseconds from 1980 to 2100.
+    fn from_time_scale(time_point: TdbTime) -> Self {
+        time_point.approximate_tt().into_time_scale()
+    }
+}
+
+/// Model used to compute `TDB - TT` from a TT instant
+///
+/// TDB differs from TT by a periodic term of at most about 1.7 milliseconds, mostly due to the
+/// eccentricity of the Earth's orbit. Different series expansions of this difference trade off
+/// accuracy against computational cost.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TdbModel {
+    /// The single-term IAU SOFA estimate `TDB - TT = 0.001657 * sin(g)`, where `g` is an estimate
+    /// of the Earth's mean anomaly. Accurate to 50 microseconds from 1980 to 2100.
+    ///
+    /// See "SOFA Time Scale and Calendar Tools", 2023 May 31, version for the C programming
+    /// language. Section 4.3.4 "TDB minus TT".
+    #[default]
+    SofaSimple,
+    /// The leading terms of the series of Fairhead and Bretagnon (1990), including the dominant
+    /// mixed (Poisson) term. Accurate to about 10 microseconds from 1600 to 2200, excluding the
+    /// topocentric terms, which depend on the observer location and remain below 2 microseconds.
+    ///
+    /// See Kaplan, "The IAU Resolutions on Astronomical Reference Systems, Time Scales, and Earth
+    /// Rotation Models", USNO Circular 179, 2005. Equation 2.6.
+    FairheadBretagnon1990,
+}
+
 impl TtTime {
     /// Approximates Barycentric Dynamical Time (BDT) from TT using a simplified expression
     /// following the IAU SOFA estimate `TDB = TT + 0.001657 * sin(g)` where `g` is an estimate of
     /// the Earth's mean anomaly. The resulting estimate is accurate to 50 microseconds from 1980
-    /// to 2100.
+    /// to 2100. Equivalent to `tdb_with_model(TdbModel::SofaSimple)`.
     ///
     /// See "SOFA Time Scale and Calendar Tools", 2023 May 31, version for the C programming
     /// language. Section 4.3.4 "TDB minus TT".
-    #[allow(clippy::cast_precision_loss, reason = "Intended")]
-    #[allow(clippy::cast_possible_truncation, reason = "Intended")]
-    #[allow(clippy::missing_panics_doc, reason = "Infallible")]
     #[must_use]
     pub fn approximate_tdb(self) -> TdbTime {
-        let j2000: Self = Self::from_historic_datetime(2000, Month::January, 1, 12, 0, 0).unwrap();
-        let mean_anomaly_per_attosecond = 0.017_202 / (24. * 60. * 60.);
-        let attoseconds_since_j2000 = (self - j2000).count();
-        let mean_anomaly = 6.24 + mean_anomaly_per_attosecond * (attoseconds_since_j2000 as f64);
-        let tdb_tt_offset = 0.001_657 * mean_anomaly.sin();
-        let tdb_tt_attoseconds = tdb_tt_offset * 1e18;
-        let tdb_tt_attoseconds = tdb_tt_attoseconds.round() as i128;
-        let count = self.count() + tdb_tt_attoseconds;
-        let time_since_epoch = Duration::attoseconds(count);
-        TdbTime::from_time_since_epoch(time_since_epoch)
+        self.tdb_with_model(TdbModel::SofaSimple)
+    }
+
+    /// Approximates Barycentric Dynamical Time (TDB) from TT, using the given model for the
+    /// difference `TDB - TT`. See `TdbModel` for the accuracy of each model.
+    #[must_use]
+    pub fn tdb_with_model(self, model: TdbModel) -> TdbTime {
+        TdbTime::from_time_since_epoch(self.time_since_epoch() + tdb_tt_offset(self, model))
+    }
+}
+
+impl TdbTime {
+    /// Approximates Terrestrial Time (TT) from TDB by inverting the expression used in
+    /// `TtTime::approximate_tdb`. Since `TDB - TT` changes by less than a nanosecond per second,
+    /// the inverse is found by fixed-point iteration starting from `TT = TDB`: each iteration
+    /// reduces the error by a factor of over a billion, so two iterations suffice to recover the
+    /// original TT down to rounding errors.
+    ///
+    /// Hence, `approximate_tt` and `approximate_tdb` are each others inverse, and the result is as
+    /// accurate as the underlying approximation: to 50 microseconds from 1980 to 2100.
+    #[must_use]
+    pub fn approximate_tt(self) -> TtTime {
+        let tdb_since_epoch = self.time_since_epoch();
+        let mut tt = TtTime::from_time_since_epoch(tdb_since_epoch);
+        for _ in 0..2 {
+            tt = TtTime::from_time_since_epoch(
+                tdb_since_epoch - tdb_tt_offset(tt, TdbModel::SofaSimple),
+            );
+        }
+        tt
     }
 }
 
+/// Estimates `TDB - TT` at the given TT instant using the given model.
+fn tdb_tt_offset(tt: TtTime, model: TdbModel) -> Duration {
+    let j2000 = TtTime::from_historic_datetime(2000, Month::January, 1, 12, 0, 0).unwrap();
+    let seconds_since_j2000 = (tt - j2000).as_float::<f64, Second>();
+    let tdb_tt_offset = match model {
+        TdbModel::SofaSimple => {
+            let mean_anomaly_per_second = 0.017_202 / (24. * 60. * 60.);
+            let mean_anomaly = 6.24 + mean_anomaly_per_second * seconds_since_j2000;
+            0.001_657 * mean_anomaly.sin()
+        }
+        TdbModel::FairheadBretagnon1990 => {
+            // Amplitude (s), frequency (rad per Julian century), and phase (rad) of each term.
+            const TERMS: [(f64, f64, f64); 6] = [
+                (0.001_657, 628.307_6, 6.240_1),
+                (0.000_022, 575.338_5, 4.297_0),
+                (0.000_014, 1_256.615_2, 6.196_9),
+                (0.000_005, 606.977_7, 4.021_2),
+                (0.000_005, 52.969_1, 0.444_4),
+                (0.000_002, 21.329_9, 5.543_1),
+            ];
+            let centuries = seconds_since_j2000 / (36_525. * 24. * 60. * 60.);
+            let periodic: f64 = TERMS
+                .iter()
+                .map(|(amplitude, frequency, phase)| {
+                    amplitude * frequency.mul_add(centuries, *phase).sin()
+                })
+                .sum();
+            let mixed = 0.000_010 * centuries * 628.307_6f64.mul_add(centuries, 4.249_0).sin();
+            periodic + mixed
+        }
+    };
+    Duration::from_seconds_f64(tdb_tt_offset).unwrap()
+}
+
 #[cfg(kani)]
 mod proof_harness {
     use super::*;
@@ -177,6 +274,34 @@ fn known_tdb_to_tcb_conversion() {
     assert!(difference < Duration::microseconds(1));
 }
 
+/// Compares the approximation of TDB against the SOFA reference value for 2006-01-15 21:25:42.684
+/// TT, for which TDB is 21:25:42.684373.
+#[test]
+fn approximate_tdb_reference() {
+    let tt = TtTime::from_fine_historic_datetime(
+        2006,
+        Month::January,
+        15,
+        21,
+        25,
+        42,
+        Duration::milliseconds(684),
+    )
+    .unwrap();
+    let tdb = TdbTime::from_fine_historic_datetime(
+        2006,
+        Month::January,
+        15,
+        21,
+        25,
+        42,
+        Duration::microseconds(684_373),
+    )
+    .unwrap();
+    let difference = (tt.approximate_tdb() - tdb).abs();
+    assert!(difference < Duration::microseconds(50));
+}
+
 /// Checks that roundtrip conversion to/from TCB/TDB is near-identity. Bar rounding errors, the
 /// transformations should be each others inverse.
 #[test]
@@ -193,3 +318,95 @@ fn roundtrip_tdb_tcb_conversion() {
         assert!(difference < Duration::attoseconds(10));
     }
 }
+
+/// Checks that approximating TT from TDB inverts the approximation of TDB from TT, for random
+/// instants between 1980 and 2100.
+#[test]
+fn roundtrip_tt_tdb_approximation() {
+    use rand::prelude::*;
+    let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(45);
+    let start = TtTime::from_historic_datetime(1980, Month::January, 1, 0, 0, 0).unwrap();
+    let end = TtTime::from_historic_datetime(2100, Month::January, 1, 0, 0, 0).unwrap();
+    for _ in 0..100 {
+        let attoseconds = rng.random_range(0..(end - start).count());
+        let tt = start + Duration::attoseconds(attoseconds);
+        let tdb = tt.approximate_tdb();
+        assert!(
+            (tdb - TdbTime::from_time_since_epoch(tt.time_since_epoch())).abs()
+                < Duration::milliseconds(2)
+        );
+        let difference = (tdb.approximate_tt() - tt).abs();
+        assert!(difference < Duration::microseconds(50));
+    }
+}
+
+/// Checks that terrestrial time scales may be converted into TDB and back, and that this agrees
+/// with the approximation via TT.
+#[test]
+fn roundtrip_tai_tdb_conversion() {
+    use crate::{TaiTime, UtcTime};
+    let tai = TaiTime::from_fine_historic_datetime(
+        2006,
+        Month::January,
+        15,
+        21,
+        24,
+        37,
+        Duration::microseconds(500_000),
+    )
+    .unwrap();
+    let tdb = tai.into_tdb();
+    assert_eq!(tdb, tai.into_tt().approximate_tdb());
+    assert!(
+        (tdb - TdbTime::from_time_since_epoch(tai.into_tt().time_since_epoch())).abs()
+            < Duration::milliseconds(2)
+    );
+    let difference = (TaiTime::from_tdb(tdb) - tai).abs();
+    assert!(difference < Duration::microseconds(50));
+
+    let utc = UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    let difference = (utc.into_tdb().into_time_scale() - utc).abs();
+    assert!(difference < Duration::microseconds(50));
+}
+
+/// Compares both TDB models against the SOFA reference value for 2006-01-15 21:25:42.684 TT,
+/// for which TDB is 21:25:42.684373, and checks that both models remain close in 2050.
+#[test]
+fn tdb_models() {
+    let tt = TtTime::from_fine_historic_datetime(
+        2006,
+        Month::January,
+        15,
+        21,
+        25,
+        42,
+        Duration::milliseconds(684),
+    )
+    .unwrap();
+    let tdb = TdbTime::from_fine_historic_datetime(
+        2006,
+        Month::January,
+        15,
+        21,
+        25,
+        42,
+        Duration::microseconds(684_373),
+    )
+    .unwrap();
+    assert_eq!(
+        tt.approximate_tdb(),
+        tt.tdb_with_model(TdbModel::SofaSimple)
+    );
+    let simple_error = (tt.tdb_with_model(TdbModel::SofaSimple) - tdb).abs();
+    let series_error = (tt.tdb_with_model(TdbModel::FairheadBretagnon1990) - tdb).abs();
+    assert!(simple_error < Duration::microseconds(50));
+    assert!(series_error < Duration::microseconds(5));
+    assert!(series_error < simple_error);
+
+    let tt = TtTime::from_historic_datetime(2050, Month::January, 1, 0, 0, 0).unwrap();
+    let divergence = (tt.tdb_with_model(TdbModel::SofaSimple)
+        - tt.tdb_with_model(TdbModel::FairheadBretagnon1990))
+    .abs();
+    assert!(divergence > Duration::ZERO);
+    assert!(divergence < Duration::microseconds(50));
+}
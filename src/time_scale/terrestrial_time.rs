@@ -37,3 +37,127 @@ where
         Self::from_time_since_epoch(time_since_epoch)
     }
 }
+
+impl<ScaleFrom> TimePoint<ScaleFrom>
+where
+    ScaleFrom: TerrestrialTime,
+{
+    /// Converts this time point into another terrestrial time scale. Unlike `into_time_scale()`,
+    /// which panics on overflow, returns `None` if the result is not representable: this may
+    /// happen for time points near the extremes of the representable range, when the target scale
+    /// has an earlier epoch.
+    #[must_use]
+    pub fn try_into_time_scale<ScaleInto>(self) -> Option<TimePoint<ScaleInto>>
+    where
+        ScaleInto: TerrestrialTime,
+    {
+        let epoch_offset: Duration = ScaleFrom::EPOCH
+            .elapsed_calendar_days_since(ScaleInto::EPOCH)
+            .into();
+        let scale_offset = ScaleFrom::TAI_OFFSET.checked_sub(ScaleInto::TAI_OFFSET)?;
+        // The total offset is combined first, such that intermediate results cannot overflow when
+        // the final result is representable.
+        let offset = epoch_offset.checked_sub(scale_offset)?;
+        self.time_since_epoch()
+            .checked_add(offset)
+            .map(TimePoint::from_time_since_epoch)
+    }
+}
+
+/// Converts a time point into another terrestrial time scale and back, verifying that this
+/// results in the original time point.
+#[cfg(test)]
+fn check_roundtrip<ScaleFrom, ScaleInto>(time_point: TimePoint<ScaleFrom>)
+where
+    ScaleFrom: TerrestrialTime,
+    ScaleInto: TerrestrialTime,
+{
+    use crate::IntoTimeScale;
+    let converted: TimePoint<ScaleInto> = time_point.into_time_scale();
+    let roundtrip: TimePoint<ScaleFrom> = converted.into_time_scale();
+    assert_eq!(time_point, roundtrip);
+}
+
+/// Round-trips a time point through all terrestrial time scales supported by this library.
+#[cfg(test)]
+fn check_roundtrip_all<Scale: TerrestrialTime>(time_point: TimePoint<Scale>) {
+    use crate::{Bdt, Glonasst, Gpst, Gst, Qzsst, Tai, Tt, Utc};
+    check_roundtrip::<Scale, Tai>(time_point);
+    check_roundtrip::<Scale, Utc>(time_point);
+    check_roundtrip::<Scale, Tt>(time_point);
+    check_roundtrip::<Scale, Gpst>(time_point);
+    check_roundtrip::<Scale, Gst>(time_point);
+    check_roundtrip::<Scale, Bdt>(time_point);
+    check_roundtrip::<Scale, Qzsst>(time_point);
+    check_roundtrip::<Scale, Glonasst>(time_point);
+}
+
+/// Verifies that a fixed instant round-trips through every pair of terrestrial time scales, and
+/// that the named conversion functions agree with the generic `into_time_scale()`.
+#[test]
+fn roundtrip_all_scale_pairs() {
+    use crate::{IntoTimeScale, Month, UtcTime};
+    let utc = UtcTime::from_fine_historic_datetime(
+        2016,
+        Month::December,
+        31,
+        23,
+        59,
+        60,
+        Duration::milliseconds(500),
+    )
+    .unwrap();
+
+    check_roundtrip_all(utc.into_tai());
+    check_roundtrip_all(utc);
+    check_roundtrip_all(utc.into_tt());
+    check_roundtrip_all(utc.into_gpst());
+    check_roundtrip_all(utc.into_gst());
+    check_roundtrip_all(utc.into_bdt());
+    check_roundtrip_all(utc.into_qzsst());
+    check_roundtrip_all(utc.into_glonasst());
+
+    let tai = utc.into_tai();
+    assert_eq!(tai, utc.into_time_scale());
+    assert_eq!(utc.into_tt(), tai.into_time_scale());
+    assert_eq!(utc.into_gpst(), tai.into_time_scale());
+    assert_eq!(utc.into_gst(), tai.into_time_scale());
+    assert_eq!(utc.into_bdt(), tai.into_time_scale());
+    assert_eq!(utc.into_qzsst(), tai.into_time_scale());
+    assert_eq!(utc.into_glonasst(), tai.into_time_scale());
+    assert_eq!(crate::GpsTime::from_utc(utc), utc.into_gpst());
+    assert_eq!(crate::GlonassTime::from_utc(utc), utc.into_glonasst());
+    assert_eq!(UtcTime::from_gst(utc.into_gst()), utc);
+    assert_eq!(UtcTime::from_bdt(utc.into_bdt()), utc);
+    assert_eq!(UtcTime::from_qzsst(utc.into_qzsst()), utc);
+    assert_eq!(UtcTime::from_tt(utc.into_tt()), utc);
+}
+
+/// Verifies that checked conversions between terrestrial time scales agree with the unchecked
+/// conversions, and report overflow near the extremes of the representable range.
+#[test]
+fn checked_conversion_near_extremes() {
+    use crate::{GpsTime, Gpst, IntoTimeScale, Month, Tai, TaiTime, UtcTime};
+    use num_traits::Bounded;
+
+    let utc = UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert_eq!(
+        utc.try_into_time_scale::<Gpst>(),
+        Some(utc.into_time_scale())
+    );
+    assert_eq!(utc.try_into_time_scale::<Tai>(), Some(utc.into_tai()));
+
+    // The TAI epoch lies before the GPS epoch, so the latest GPS time point cannot be expressed in
+    // TAI, while the latest TAI time point is representable in GPS time.
+    assert_eq!(GpsTime::max_value().try_into_time_scale::<Tai>(), None);
+    let gpst = TaiTime::max_value().try_into_time_scale::<Gpst>().unwrap();
+    assert_eq!(gpst, TaiTime::max_value().into_time_scale());
+    assert_eq!(
+        gpst.try_into_time_scale::<Tai>(),
+        Some(TaiTime::max_value())
+    );
+
+    // Conversely, the earliest TAI time point cannot be expressed in GPS time.
+    assert_eq!(TaiTime::min_value().try_into_time_scale::<Gpst>(), None);
+    assert!(GpsTime::min_value().try_into_time_scale::<Tai>().is_some());
+}
@@ -74,8 +74,6 @@ fn known_timestamps() {
 
 #[test]
 fn date_decomposition() {
-    use num_traits::ConstZero;
-
     let time = TtTime::from_historic_datetime(2004, Month::May, 14, 16, 44, 4).unwrap();
     let (date, hour, minute, second, subseconds) = time.into_fine_historic_datetime();
     assert_eq!(date.year(), 2004);
@@ -1,12 +1,14 @@
 //! Implementation of Coordinated Universal Time (UTC).
 
-use num_traits::ConstZero;
-
 use crate::{
-    Date, Days, Duration, FromDateTime, FromTimeScale, IntoDateTime, IntoTimeScale,
-    LeapSecondProvider, Month, Second, StaticLeapSecondProvider, TerrestrialTime, TimePoint,
+    Date, Days, Duration, FromDateTime, FromTimeScale, IntoDateTime, IntoFineDateTime,
+    IntoTimeScale, LeapSecondProvider, Month, Nano, Second, StaticLeapSecondProvider,
+    TerrestrialTime, TimePoint,
     errors::{InvalidTimeOfDay, InvalidUtcDateTime},
-    time_scale::{AbsoluteTimeScale, TimeScale},
+    time_scale::{
+        AbsoluteTimeScale, TimeScale,
+        leap_seconds::{LeapSecond, leap_second_on_date},
+    },
     units::{SecondsPerDay, SecondsPerHour, SecondsPerMinute},
 };
 
@@ -36,6 +38,8 @@ impl TimeScale for Utc {
     const NAME: &'static str = "Coordinated Universal Time";
 
     const ABBREVIATION: &'static str = "UTC";
+
+    const HAS_LEAP_SECONDS: bool = true;
 }
 
 impl AbsoluteTimeScale for Utc {
@@ -49,6 +53,26 @@ impl AbsoluteTimeScale for Utc {
     };
 }
 
+impl Utc {
+    /// Returns the time elapsed from the civil UTC date-time `start` to `end`, each given as a
+    /// `(date, hour, minute, second)` tuple. Any leap seconds inserted or removed between both
+    /// date-times are accounted for: from 2015-06-30T23:59:59 to 2015-07-01T00:00:00, for example,
+    /// two seconds elapsed. The result is negative if `end` lies before `start`.
+    ///
+    /// # Errors
+    /// Will raise an error if either date-time does not exist in UTC.
+    pub fn elapsed_seconds_between(
+        start: (Date, u8, u8, u8),
+        end: (Date, u8, u8, u8),
+    ) -> Result<Duration, InvalidUtcDateTime> {
+        let (date, hour, minute, second) = start;
+        let start = UtcTime::from_datetime(date, hour, minute, second)?;
+        let (date, hour, minute, second) = end;
+        let end = UtcTime::from_datetime(date, hour, minute, second)?;
+        Ok(end - start)
+    }
+}
+
 impl<Scale: ?Sized> TimePoint<Scale> {
     #[must_use]
     pub fn from_utc(time_point: UtcTime) -> Self
@@ -67,6 +91,78 @@ impl<Scale: ?Sized> TimePoint<Scale> {
     }
 }
 
+impl UtcTime {
+    /// Constructs a `UtcTime` from a POSIX (Unix) timestamp, expressed in seconds since
+    /// 1970-01-01T00:00:00 UTC. Unix time ignores leap seconds: every day is exactly 86400 seconds
+    /// long. This function re-introduces the leap seconds accumulated up to the given date.
+    ///
+    /// # Panics
+    /// Will panic if the number of days since the Unix epoch does not fit inside an `i32`.
+    #[must_use]
+    pub fn from_unix_timestamp(seconds: i64) -> Self {
+        Self::from_unix_time(Duration::seconds(seconds.into()))
+    }
+
+    /// Constructs a `UtcTime` from a POSIX (Unix) timestamp, expressed in nanoseconds since
+    /// 1970-01-01T00:00:00 UTC. Leap seconds are handled as in `from_unix_timestamp`.
+    ///
+    /// # Panics
+    /// Will panic if the number of days since the Unix epoch does not fit inside an `i32`.
+    #[must_use]
+    pub fn from_unix_timestamp_nanos(nanoseconds: i128) -> Self {
+        Self::from_unix_time(Duration::nanoseconds(nanoseconds))
+    }
+
+    /// Returns the POSIX (Unix) timestamp of this time point, in whole seconds since
+    /// 1970-01-01T00:00:00 UTC, rounded towards negative infinity. Since Unix time cannot
+    /// represent leap seconds, a leap second 23:59:60 maps to the same timestamp as 00:00:00 of
+    /// the following day.
+    ///
+    /// # Panics
+    /// Will panic if the resulting timestamp does not fit inside an `i64`.
+    #[must_use]
+    pub fn unix_timestamp(&self) -> i64 {
+        let seconds = self.unix_time().floor::<Second>() / Duration::seconds(1);
+        seconds.try_into().unwrap_or_else(|_| {
+            panic!("Unix timestamp cannot be represented as `i64` number of seconds")
+        })
+    }
+
+    /// Returns the POSIX (Unix) timestamp of this time point, in nanoseconds since
+    /// 1970-01-01T00:00:00 UTC, rounded towards negative infinity. Leap seconds are handled as in
+    /// `unix_timestamp`.
+    #[must_use]
+    pub fn unix_timestamp_nanos(&self) -> i128 {
+        self.unix_time().floor::<Nano>() / Duration::nanoseconds(1)
+    }
+
+    /// Constructs a `UtcTime` from the non-leap-second-aware time elapsed since the Unix epoch.
+    fn from_unix_time(time_since_unix_epoch: Duration) -> Self {
+        let days = time_since_unix_epoch.floor::<SecondsPerDay>();
+        let time_of_day = time_since_unix_epoch - days;
+        let days = Days::new(
+            (days / Duration::days(1))
+                .try_into()
+                .unwrap_or_else(|_| panic!("Days since Unix epoch cannot be expressed as `i32`")),
+        );
+        let date = Date::from_time_since_epoch(days);
+        let Ok(midnight) = Self::from_datetime(date, 0, 0, 0) else {
+            unreachable!()
+        };
+        midnight + time_of_day
+    }
+
+    /// Returns the time elapsed since the Unix epoch, ignoring leap seconds.
+    fn unix_time(self) -> Duration {
+        let (date, hour, minute, second, subseconds) = self.into_fine_datetime();
+        let days: Duration = date.time_since_epoch().into();
+        days + Duration::hours(hour.into())
+            + Duration::minutes(minute.into())
+            + Duration::seconds(second.into())
+            + subseconds
+    }
+}
+
 impl TerrestrialTime for Utc {
     /// Perhaps confusingly, we define UTC as coinciding with TAI. This is entirely possible
     /// because we handle leap seconds at the date-time boundary: after converting UTC into its
@@ -78,6 +174,28 @@ impl FromDateTime for UtcTime {
     type Error = InvalidUtcDateTime;
 
     fn from_datetime(date: Date, hour: u8, minute: u8, second: u8) -> Result<Self, Self::Error> {
+        Self::from_leap_second_datetime(date, hour, minute, second, &StaticLeapSecondProvider {})
+    }
+}
+
+impl IntoDateTime for UtcTime {
+    fn into_datetime(self) -> (Date, u8, u8, u8) {
+        self.into_leap_second_datetime(&StaticLeapSecondProvider {})
+    }
+}
+
+// UTC does not implement the leap second date-time traits: the blanket date-time implementations
+// for those traits would then be considered to overlap with those of uniform date-time scales.
+// Instead, the provider-based conversions are exposed within the crate as inherent functions.
+impl UtcTime {
+    /// Creates a UTC time point from a date-time, using the given leap second provider.
+    pub(crate) fn from_leap_second_datetime(
+        date: Date,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        leap_second_provider: &impl LeapSecondProvider,
+    ) -> Result<Self, InvalidUtcDateTime> {
         if hour > 23 || minute > 59 || second > 60 {
             return Err(InvalidUtcDateTime::InvalidTimeOfDay(InvalidTimeOfDay {
                 hour,
@@ -86,8 +204,8 @@ impl FromDateTime for UtcTime {
             }));
         }
 
-        let (is_leap_second, leap_seconds) = StaticLeapSecondProvider {}.leap_seconds_on_date(date);
-        if second == 60 && !is_leap_second {
+        let (leap_second, leap_seconds) = leap_second_on_date(leap_second_provider, date);
+        if second == 60 && leap_second != LeapSecond::Inserted {
             return Err(InvalidUtcDateTime::NonLeapSecondDateTime {
                 date,
                 hour,
@@ -96,6 +214,15 @@ impl FromDateTime for UtcTime {
             });
         }
 
+        if (hour, minute, second) == (23, 59, 59) && leap_second == LeapSecond::Deleted {
+            return Err(InvalidUtcDateTime::DeletedSecond {
+                date,
+                hour,
+                minute,
+                second,
+            });
+        }
+
         let days_since_scale_epoch = {
             let days_since_1970 = date.time_since_epoch();
             let epoch_days_since_1970 = Utc::EPOCH.time_since_epoch();
@@ -112,14 +239,16 @@ impl FromDateTime for UtcTime {
             + days_since_scale_epoch.into();
         Ok(Self::from_time_since_epoch(time_since_epoch))
     }
-}
 
-impl IntoDateTime for UtcTime {
-    fn into_datetime(self) -> (Date, u8, u8, u8) {
+    /// Maps a UTC time point to its date-time, using the given leap second provider.
+    pub(crate) fn into_leap_second_datetime(
+        self,
+        leap_second_provider: &impl LeapSecondProvider,
+    ) -> (Date, u8, u8, u8) {
         // Step-by-step factoring of the time since epoch into days, hours, minutes, and seconds.
         let seconds_since_scale_epoch = self.time_since_epoch();
 
-        let (is_leap_second, leap_seconds) = StaticLeapSecondProvider {}.leap_seconds_at_time(self);
+        let (is_leap_second, leap_seconds) = leap_second_provider.leap_seconds_at_time(self);
 
         let seconds_since_scale_epoch =
             seconds_since_scale_epoch - Duration::seconds(leap_seconds.into());
@@ -148,7 +277,7 @@ impl IntoDateTime for UtcTime {
             date,
             hour.try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in hour value that cannot be expressed as `u8`")),
             minute.try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in minute value that cannot be expressed as `u8`")),
-            (second / Duration::seconds(1)).try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in second value that cannot be expressed as `u8`")),
+            second.whole_seconds().try_into().unwrap_or_else(|_| panic!("Call of `datetime_from_time_point` results in second value that cannot be expressed as `u8`")),
         )
         }
     }
@@ -272,3 +401,185 @@ fn datetime_roundtrip_near_leap_seconds() {
         }
     }
 }
+
+/// Verifies that the time elapsed between civil UTC date-times accounts for leap seconds.
+#[test]
+fn elapsed_seconds_between_datetimes() {
+    use crate::Month::*;
+    let june_30 = Date::from_historic_date(2015, June, 30).unwrap();
+    let july_1 = Date::from_historic_date(2015, July, 1).unwrap();
+    assert_eq!(
+        Utc::elapsed_seconds_between((june_30, 23, 59, 59), (july_1, 0, 0, 0)),
+        Ok(Duration::seconds(2))
+    );
+    assert_eq!(
+        Utc::elapsed_seconds_between((july_1, 0, 0, 0), (june_30, 23, 59, 59)),
+        Ok(Duration::seconds(-2))
+    );
+    assert_eq!(
+        Utc::elapsed_seconds_between((june_30, 23, 59, 60), (july_1, 0, 0, 0)),
+        Ok(Duration::seconds(1))
+    );
+    assert_eq!(
+        Utc::elapsed_seconds_between((june_30, 0, 0, 0), (july_1, 0, 0, 0)),
+        Ok(Duration::seconds(86_401))
+    );
+
+    // Without a leap second, a day lasts exactly 86400 seconds.
+    let july_2 = Date::from_historic_date(2015, July, 2).unwrap();
+    assert_eq!(
+        Utc::elapsed_seconds_between((july_1, 0, 0, 0), (july_2, 0, 0, 0)),
+        Ok(Duration::seconds(86_400))
+    );
+    assert!(Utc::elapsed_seconds_between((july_1, 23, 59, 60), (july_2, 0, 0, 0)).is_err());
+}
+
+/// Verifies conversion to and from Unix timestamps for some known values, including the Unix epoch
+/// itself (which lies before the UTC epoch) and times around the 2016 leap second.
+#[test]
+fn unix_timestamps() {
+    use crate::Month::*;
+    let unix_epoch = UtcTime::from_historic_datetime(1970, January, 1, 0, 0, 0).unwrap();
+    assert_eq!(UtcTime::from_unix_timestamp(0), unix_epoch);
+    assert_eq!(
+        unix_epoch.time_since_epoch(),
+        Duration::seconds(-63_072_000 + 9)
+    );
+    assert_eq!(unix_epoch.unix_timestamp(), 0);
+
+    let before = UtcTime::from_historic_datetime(2016, December, 31, 23, 59, 59).unwrap();
+    let leap_second = UtcTime::from_historic_datetime(2016, December, 31, 23, 59, 60).unwrap();
+    let after = UtcTime::from_historic_datetime(2017, January, 1, 0, 0, 0).unwrap();
+    assert_eq!(UtcTime::from_unix_timestamp(1_483_228_799), before);
+    assert_eq!(UtcTime::from_unix_timestamp(1_483_228_800), after);
+    assert_eq!(before.unix_timestamp(), 1_483_228_799);
+    assert_eq!(leap_second.unix_timestamp(), 1_483_228_800);
+    assert_eq!(after.unix_timestamp(), 1_483_228_800);
+
+    let time = UtcTime::from_fine_historic_datetime(
+        2025,
+        August,
+        3,
+        20,
+        25,
+        42,
+        Duration::nanoseconds(123_456_789),
+    )
+    .unwrap();
+    let nanoseconds = 1_754_252_742_123_456_789;
+    assert_eq!(UtcTime::from_unix_timestamp_nanos(nanoseconds), time);
+    assert_eq!(time.unix_timestamp_nanos(), nanoseconds);
+    assert_eq!(time.unix_timestamp(), 1_754_252_742);
+
+    let before_unix_epoch = UtcTime::from_unix_timestamp_nanos(-1);
+    assert_eq!(before_unix_epoch.unix_timestamp(), -1);
+    assert_eq!(before_unix_epoch.unix_timestamp_nanos(), -1);
+}
+
+/// Leap second provider that follows the static leap second table, but additionally deletes a
+/// (synthetic) negative leap second at the end of a given date, after the end of the table.
+#[cfg(test)]
+struct NegativeLeapSecondProvider {
+    deleted_on: Date,
+}
+
+#[cfg(test)]
+impl LeapSecondProvider for NegativeLeapSecondProvider {
+    fn leap_seconds_on_date(&self, utc_date: Date) -> (bool, i32) {
+        match utc_date.cmp(&self.deleted_on) {
+            core::cmp::Ordering::Greater => (false, 36),
+            core::cmp::Ordering::Equal => (true, 37),
+            core::cmp::Ordering::Less => {
+                crate::StaticLeapSecondProvider {}.leap_seconds_on_date(utc_date)
+            }
+        }
+    }
+
+    fn leap_seconds_at_time(&self, utc_time: UtcTime) -> (bool, i32) {
+        let days_since_scale_epoch =
+            self.deleted_on.time_since_epoch() - Utc::EPOCH.time_since_epoch() + Days::new(1);
+        let deletion: Duration = Duration::from(days_since_scale_epoch) + Duration::seconds(36);
+        if utc_time.time_since_epoch() >= deletion {
+            (false, 36)
+        } else {
+            crate::StaticLeapSecondProvider {}.leap_seconds_at_time(utc_time)
+        }
+    }
+}
+
+/// Verifies that a negative leap second is handled properly when using a leap second provider
+/// that announces one: the deleted 23:59:59 shall be rejected, and the seconds directly before and
+/// after the deletion shall be one second apart.
+#[test]
+fn negative_leap_second() {
+    use crate::{FromLeapSecondDateTime, GlonassTime};
+    let date = Date::from_historic_date(2030, Month::June, 30).unwrap();
+    let next_date = Date::from_historic_date(2030, Month::July, 1).unwrap();
+    let provider = NegativeLeapSecondProvider { deleted_on: date };
+
+    let before = UtcTime::from_leap_second_datetime(date, 23, 59, 58, &provider).unwrap();
+    let after = UtcTime::from_leap_second_datetime(next_date, 0, 0, 0, &provider).unwrap();
+    assert_eq!(after - before, Duration::seconds(1));
+    assert_eq!(
+        UtcTime::from_leap_second_datetime(date, 23, 59, 59, &provider),
+        Err(InvalidUtcDateTime::DeletedSecond {
+            date,
+            hour: 23,
+            minute: 59,
+            second: 59
+        })
+    );
+    assert_eq!(
+        UtcTime::from_leap_second_datetime(date, 23, 59, 60, &provider),
+        Err(InvalidUtcDateTime::NonLeapSecondDateTime {
+            date,
+            hour: 23,
+            minute: 59,
+            second: 60
+        })
+    );
+    assert_eq!(
+        before.into_leap_second_datetime(&provider),
+        (date, 23, 59, 58)
+    );
+    assert_eq!(
+        after.into_leap_second_datetime(&provider),
+        (next_date, 0, 0, 0)
+    );
+
+    // Other days are unaffected by the deletion.
+    assert!(UtcTime::from_leap_second_datetime(next_date, 23, 59, 59, &provider).is_ok());
+
+    // GLONASST is three hours ahead of UTC, so the deleted second falls on 02:59:59.
+    assert_eq!(
+        <GlonassTime as FromLeapSecondDateTime>::from_datetime(next_date, 2, 59, 59, &provider),
+        Err(crate::errors::InvalidGlonassDateTime::DeletedSecond {
+            date: next_date,
+            hour: 2,
+            minute: 59,
+            second: 59
+        })
+    );
+    let before =
+        <GlonassTime as FromLeapSecondDateTime>::from_datetime(next_date, 2, 59, 58, &provider)
+            .unwrap();
+    let after =
+        <GlonassTime as FromLeapSecondDateTime>::from_datetime(next_date, 3, 0, 0, &provider)
+            .unwrap();
+    assert_eq!(after - before, Duration::seconds(1));
+}
+
+/// Verifies that the time scale metadata distinguishes scales with leap seconds from continuous
+/// scales.
+#[test]
+fn time_scale_metadata() {
+    use crate::{Glonasst, Gpst, Tai, TimeScale};
+    fn flags<Scale: TimeScale>() -> (bool, bool) {
+        (Scale::HAS_LEAP_SECONDS, Scale::IS_CONTINUOUS)
+    }
+
+    assert_eq!(flags::<Utc>(), (true, false));
+    assert_eq!(flags::<Glonasst>(), (true, false));
+    assert_eq!(flags::<Gpst>(), (false, true));
+    assert_eq!(flags::<Tai>(), (false, true));
+}